@@ -36,10 +36,13 @@ use crate::loading::{
 use awbrn_game::world::initialize_terrain_semantic_world;
 use bevy::prelude::*;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub struct AwbrnPlugin {
     map_resolver: Arc<dyn MapAssetPathResolver>,
     static_asset_resolver: Arc<dyn StaticAssetPathResolver>,
+    fixed_timestep: Option<Duration>,
+    loading_color: Option<Color>,
 }
 
 impl AwbrnPlugin {
@@ -47,6 +50,8 @@ impl AwbrnPlugin {
         Self {
             map_resolver,
             static_asset_resolver: Arc::new(DefaultStaticAssetPathResolver),
+            fixed_timestep: None,
+            loading_color: None,
         }
     }
 
@@ -57,6 +62,18 @@ impl AwbrnPlugin {
         self.static_asset_resolver = static_asset_resolver;
         self
     }
+
+    /// Step time by `step` every frame rather than by real elapsed time.
+    pub fn with_fixed_timestep(mut self, step: Duration) -> Self {
+        self.fixed_timestep = Some(step);
+        self
+    }
+
+    /// Cover the viewport with `color` until a map is in game.
+    pub fn with_loading_color(mut self, color: Color) -> Self {
+        self.loading_color = Some(color);
+        self
+    }
 }
 
 impl Default for AwbrnPlugin {
@@ -64,6 +81,8 @@ impl Default for AwbrnPlugin {
         Self {
             map_resolver: Arc::new(crate::loading::DefaultMapAssetPathResolver),
             static_asset_resolver: Arc::new(DefaultStaticAssetPathResolver),
+            fixed_timestep: None,
+            loading_color: None,
         }
     }
 }
@@ -83,6 +102,14 @@ impl Plugin for AwbrnPlugin {
             crate::modes::play::PlayPlugin,
         ));
 
+        if let Some(step) = self.fixed_timestep {
+            app.add_plugins(crate::core::FixedTimestepPlugin { step });
+        }
+
+        if let Some(color) = self.loading_color {
+            app.insert_resource(crate::features::LoadingSplashColor(color));
+        }
+
         // Cross-plugin OnEnter(Complete) scheduling
         app.add_systems(
             OnEnter(LoadingState::Complete),
@@ -111,11 +138,12 @@ impl Plugin for AwbrnPlugin {
 
 #[cfg(test)]
 pub(crate) mod test_helpers {
-    use crate::core::{RenderLayer, SpriteSize, on_map_position_insert};
+    use crate::core::{SpriteSize, on_map_position_insert};
     use crate::features::weather::CurrentWeather;
     use crate::modes::replay::commands::ReplayAdvanceLock;
     use crate::modes::replay::navigation::{
-        CourseArrowPiece, animate_course_arrows, animate_unit_paths, spawn_pending_course_arrows,
+        CourseArrowPiece, MoveTrail, animate_course_arrows, animate_unit_paths, fade_move_trails,
+        spawn_move_trail, spawn_pending_course_arrows,
     };
     use crate::projection::{project_terrain_render_state, project_unit_render_state};
     use crate::render::units::{handle_unit_spawn, sync_projected_unit_render_state};
@@ -162,26 +190,19 @@ pub(crate) mod test_helpers {
         app.insert_resource(ReplayState::default());
         app.add_observer(on_map_position_insert);
         app.world_mut()
-            .register_required_components_with::<Unit, SpriteSize>(|| SpriteSize {
-                width: 23.0,
-                height: 24.0,
-                z_index: RenderLayer::UNIT,
-            });
+            .register_required_components_with::<Unit, SpriteSize>(|| SpriteSize::UNIT);
         app.world_mut()
             .register_required_components::<Unit, Visibility>();
         app.world_mut()
             .register_required_components::<Unit, crate::render::UnitOverlayRegistry>();
         app.world_mut()
-            .register_required_components_with::<TerrainTile, SpriteSize>(|| SpriteSize {
-                width: 16.0,
-                height: 32.0,
-                z_index: RenderLayer::TERRAIN,
-            });
+            .register_required_components_with::<TerrainTile, SpriteSize>(|| SpriteSize::TERRAIN);
         app.world_mut()
             .register_required_components::<MapPosition, Transform>();
         app.add_observer(crate::modes::replay::fog::on_replay_fog_dirty);
         app.add_observer(handle_unit_spawn);
         app.add_observer(spawn_pending_course_arrows);
+        app.add_observer(spawn_move_trail);
         app.add_systems(
             Update,
             (
@@ -192,6 +213,7 @@ pub(crate) mod test_helpers {
                     .run_if(resource_changed::<CurrentWeather>),
                 sync_projected_unit_render_state.before(crate::render::animation::animate_units),
                 animate_course_arrows,
+                fade_move_trails,
                 animate_unit_paths,
             )
                 .chain(),
@@ -293,12 +315,9 @@ pub(crate) mod test_helpers {
                 let width = game_map.width().max(position.x + 1);
                 let height = game_map.height().max(position.y + 1);
                 let mut existing_tiles = Vec::new();
-                for y in 0..game_map.height() {
-                    for x in 0..game_map.width() {
-                        let position = Position::new(x, y);
-                        if let Some(terrain) = game_map.terrain_at(position) {
-                            existing_tiles.push((position, terrain));
-                        }
+                for position in game_map.positions() {
+                    if let Some(terrain) = game_map.terrain_at(position) {
+                        existing_tiles.push((position, terrain));
                     }
                 }
 
@@ -335,6 +354,14 @@ pub(crate) mod test_helpers {
             .collect()
     }
 
+    pub(crate) fn move_trails(app: &mut App) -> Vec<(MoveTrail, Transform)> {
+        let mut query = app.world_mut().query::<(&MoveTrail, &Transform)>();
+        query
+            .iter(app.world())
+            .map(|(trail, transform)| (*trail, *transform))
+            .collect()
+    }
+
     pub(crate) fn test_move_action() -> Action {
         test_move_action_for(
             CoreUnitId::new(173623341),
@@ -531,8 +558,12 @@ mod tests {
     use super::test_helpers::*;
     use crate::core::SpriteSize;
     use crate::core::coords::position_to_world_translation;
+    use crate::features::camera::{CameraFollow, follow_camera_target};
     use crate::modes::replay::commands::{ReplayAdvanceLock, ReplayTurnCommand};
-    use crate::modes::replay::navigation::{COURSE_ARROW_BASE_SCALE, CourseArrowSpriteKind};
+    use crate::modes::replay::navigation::{
+        COURSE_ARROW_BASE_SCALE, COURSE_ARROW_SPRITE_SIZE, CourseArrowSpriteKind,
+        MOVE_TRAIL_LIFETIME_MS, scaled_animation_duration,
+    };
     use awbrn_content::get_unit_animation_frames;
     use awbrn_game::MapPosition;
     use awbrn_game::world::{CaptureProgress, GameMap, GraphicalHp};
@@ -705,6 +736,115 @@ mod tests {
         assert!(course_arrows(&mut app).is_empty());
     }
 
+    #[test]
+    fn move_action_leaves_a_fading_trail() {
+        let mut app = replay_animation_test_app();
+        let unit_entity = spawn_test_unit(
+            &mut app,
+            Position::new(8, 33),
+            CoreUnitId::new(173623341),
+            PlayerFaction::GreenEarth,
+        );
+        app.update();
+
+        ReplayTurnCommand {
+            action: test_move_action(),
+        }
+        .apply(app.world_mut());
+        app.update();
+
+        let trails = move_trails(&mut app);
+        assert_eq!(
+            trails.len(),
+            2,
+            "one breadcrumb per tile before the destination"
+        );
+        assert!(trails.iter().all(|(trail, _)| trail.owner == unit_entity));
+
+        let game_map = app.world().resource::<GameMap>();
+        let expected = position_to_world_translation(
+            &COURSE_ARROW_SPRITE_SIZE,
+            Position::new(8, 33),
+            game_map,
+        );
+        assert!(trails.iter().any(|(_, transform)| {
+            (transform.translation.x - expected.x).abs() < 0.001
+                && (transform.translation.y - expected.y).abs() < 0.001
+        }));
+
+        app.world_mut()
+            .resource_mut::<Time<()>>()
+            .advance_by(scaled_animation_duration(MOVE_TRAIL_LIFETIME_MS));
+        app.update();
+
+        assert!(move_trails(&mut app).is_empty());
+    }
+
+    #[test]
+    fn camera_follow_pans_toward_move_destination() {
+        let mut app = replay_animation_test_app();
+        app.insert_resource(CameraFollow {
+            enabled: true,
+            target: None,
+        });
+        app.add_systems(Update, follow_camera_target);
+        app.world_mut().spawn(Window {
+            resolution: bevy::window::WindowResolution::new(320, 240),
+            ..default()
+        });
+        let camera = app
+            .world_mut()
+            .spawn((
+                Camera2d,
+                Projection::Orthographic(OrthographicProjection {
+                    scale: 0.5,
+                    ..OrthographicProjection::default_2d()
+                }),
+                Transform::default(),
+            ))
+            .id();
+        spawn_test_unit(
+            &mut app,
+            Position::new(8, 33),
+            CoreUnitId::new(173623341),
+            PlayerFaction::GreenEarth,
+        );
+        app.update();
+
+        ReplayTurnCommand {
+            action: test_move_action(),
+        }
+        .apply(app.world_mut());
+
+        let destination = position_to_world_translation(
+            &COURSE_ARROW_SPRITE_SIZE,
+            Position::new(7, 32),
+            app.world().resource::<GameMap>(),
+        )
+        .truncate();
+        assert_eq!(
+            app.world().resource::<CameraFollow>().target,
+            Some(destination)
+        );
+
+        let camera_position = |app: &App| {
+            app.world()
+                .get::<Transform>(camera)
+                .unwrap()
+                .translation
+                .truncate()
+        };
+        let start = camera_position(&app);
+        app.world_mut()
+            .resource_mut::<Time<()>>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+        let after = camera_position(&app);
+
+        assert!(after.distance(destination) < start.distance(destination));
+        assert!(after.x < start.x && after.y < start.y);
+    }
+
     #[test]
     fn animating_units_unhide_when_fog_is_disabled_mid_animation() {
         let mut app = replay_animation_test_app();
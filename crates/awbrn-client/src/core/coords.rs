@@ -232,7 +232,7 @@ fn tile_grid_top_left_world(map: &GameMap) -> WorldPos {
 /// Use this for sprites that exactly fill one tile (tile cursor, fog overlay).
 /// For differently-sized sprites, use [`map_position_to_world_translation`],
 /// which adds the alignment offset on top of the tile center.
-fn tile_center_world(pos: MapPosition, map: &GameMap) -> WorldPos {
+pub(crate) fn tile_center_world(pos: MapPosition, map: &GameMap) -> WorldPos {
     let tl = tile_grid_top_left_world(map);
     WorldPos::new(
         tl.x() + (pos.x() as f32 + 0.5) * TILE_SIZE,
@@ -275,22 +275,30 @@ pub fn map_position_to_world_translation(
     map_position: MapPosition,
     game_map: &GameMap,
 ) -> Vec3 {
-    let center = tile_center_world(map_position, game_map);
-
-    // Center the sprite within the tile cell. Offsets are computed in
-    // grid-local space (Y-down); the Y offset is negated for world space (Y-up).
-    let x_align = (TILE_SIZE - sprite_size.width) / 2.0;
-    let y_align = (TILE_SIZE - sprite_size.height) / 2.0;
-
+    let center = tile_center_world(map_position, game_map) + sprite_alignment_offset(sprite_size);
     let z_offset = map_position.y() as f32 * 0.001;
 
     Vec3::new(
-        center.x() + x_align,
-        center.y() - y_align,
+        center.x(),
+        center.y(),
         sprite_size.z_index as f32 + z_offset,
     )
 }
 
+/// World-space offset from a tile's center to the center of a sprite drawn
+/// on it.
+///
+/// Offsets are computed in grid-local space (Y-down) and negated on Y for
+/// world space (Y-up). Sprites wider than a tile shift left by half the
+/// overhang; sprites taller than a tile shift up by half the overhang, so the
+/// extra height extends above the cell. A 16×32 terrain sprite therefore sits
+/// 8px up, and a 23×24 unit sprite sits 3.5px left and 4px up.
+pub fn sprite_alignment_offset(sprite_size: &SpriteSize) -> Vec2 {
+    let x_align = (TILE_SIZE - sprite_size.width) / 2.0;
+    let y_align = (TILE_SIZE - sprite_size.height) / 2.0;
+    Vec2::new(x_align, -y_align)
+}
+
 /// Like [`map_position_to_world_translation`] but takes a raw [`Position`].
 ///
 /// Kept for call sites (e.g., fog overlay) that work with [`Position`] directly.
@@ -367,6 +375,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tile_sized_sprites_have_no_alignment_offset() {
+        assert_eq!(sprite_alignment_offset(&TILE_SPRITE), Vec2::ZERO);
+    }
+
+    #[test]
+    fn terrain_sprites_extend_above_their_tile() {
+        assert_eq!(
+            sprite_alignment_offset(&SpriteSize::TERRAIN),
+            Vec2::new(0.0, 8.0)
+        );
+    }
+
+    #[test]
+    fn tall_terrain_shares_its_base_with_flat_sprites_in_the_row() {
+        let game_map = map_3x2();
+
+        for pos in [MapPosition::new(0, 0), MapPosition::new(2, 1)] {
+            let tall = map_position_to_world_translation(&SpriteSize::TERRAIN, pos, &game_map);
+            let flat = map_position_to_world_translation(&TILE_SPRITE, pos, &game_map);
+
+            let tall_base = tall.y - SpriteSize::TERRAIN.height / 2.0;
+            let flat_base = flat.y - TILE_SPRITE.height / 2.0;
+            assert!((tall_base - flat_base).abs() < 0.001);
+            assert!((tall.x - flat.x).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn unit_sprites_overhang_left_and_up() {
+        assert_eq!(
+            sprite_alignment_offset(&SpriteSize::UNIT),
+            Vec2::new(-3.5, 4.0)
+        );
+    }
+
     #[test]
     fn world_outside_map_returns_none() {
         let game_map = map_3x2();
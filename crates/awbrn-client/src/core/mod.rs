@@ -3,6 +3,8 @@ pub mod coords;
 use awbrn_game::world::{GameMap, TerrainTile, Unit};
 use awbrn_game::{GameWorldPlugin, MapPosition};
 use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
 
 /// Color used for inactive units
 pub const INACTIVE_UNIT_COLOR: Color = Color::srgb(0.67, 0.67, 0.67);
@@ -15,19 +17,44 @@ impl RenderLayer {
     pub const BACKDROP: i8 = 0;
     pub const TERRAIN: i8 = 1;
     pub const FOG_OVERLAY: i8 = 2;
-    pub const MOVE_RANGE_OVERLAY: i8 = 3;
-    pub const UNIT: i8 = 4;
-    pub const COURSE_ARROW: i8 = 5;
+    /// Tile grid lines, drawn over fog so fogged tiles stay outlined.
+    pub const GRID_OVERLAY: i8 = 3;
+    pub const MOVE_RANGE_OVERLAY: i8 = 4;
+    pub const UNIT: i8 = 5;
+    pub const COURSE_ARROW: i8 = 6;
     pub const CURSOR: i8 = 10;
+    pub const LOADING_SPLASH: i8 = 20;
+
+    /// Local z of unit overlays (HP, capture, cargo badges) relative to their
+    /// parent unit. Less than a full layer so badges sit above the unit but
+    /// never tie with the course arrow layer.
+    pub const UNIT_OVERLAY_OFFSET: f32 = 0.5;
 }
 
-#[derive(Component, Copy, Clone)]
+#[derive(Component, Debug, Copy, Clone, PartialEq)]
 pub struct SpriteSize {
     pub width: f32,
     pub height: f32,
     pub z_index: i8,
 }
 
+impl SpriteSize {
+    /// Terrain sprites are one tile wide and two tiles tall so tall terrain
+    /// (mountains, buildings) can overhang the row above.
+    pub const TERRAIN: SpriteSize = SpriteSize {
+        width: 16.0,
+        height: 32.0,
+        z_index: RenderLayer::TERRAIN,
+    };
+
+    /// Unit sprites are 23×24 and overhang their tile to the left and above.
+    pub const UNIT: SpriteSize = SpriteSize {
+        width: 23.0,
+        height: 24.0,
+        z_index: RenderLayer::UNIT,
+    };
+}
+
 /// Observer that triggers when MapPosition is inserted
 pub(crate) fn on_map_position_insert(
     trigger: On<Insert, MapPosition>,
@@ -77,24 +104,30 @@ impl Plugin for CorePlugin {
 
         // Register visual required components for game types defined in awbrn-game
         app.world_mut()
-            .register_required_components_with::<Unit, SpriteSize>(|| SpriteSize {
-                width: 23.0,
-                height: 24.0,
-                z_index: RenderLayer::UNIT,
-            });
+            .register_required_components_with::<Unit, SpriteSize>(|| SpriteSize::UNIT);
         app.world_mut()
             .register_required_components::<Unit, Visibility>();
         app.world_mut()
-            .register_required_components_with::<TerrainTile, SpriteSize>(|| SpriteSize {
-                width: 16.0,
-                height: 32.0,
-                z_index: RenderLayer::TERRAIN,
-            });
+            .register_required_components_with::<TerrainTile, SpriteSize>(|| SpriteSize::TERRAIN);
         app.world_mut()
             .register_required_components::<MapPosition, Transform>();
     }
 }
 
+/// Advances `Time` by a constant step each frame instead of wall-clock time.
+///
+/// Replay advancement and animations then progress identically regardless of
+/// the real frame rate, which keeps autoplay and frame captures deterministic.
+pub struct FixedTimestepPlugin {
+    pub step: Duration,
+}
+
+impl Plugin for FixedTimestepPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(self.step));
+    }
+}
+
 #[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
 pub enum AppState {
     #[default]
@@ -123,6 +156,88 @@ pub enum LoadingState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::render::animation::{Animation, animate_units};
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimePlugin;
+
+    #[test]
+    fn fixed_timestep_advances_animations_deterministically() {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            FixedTimestepPlugin {
+                step: Duration::from_millis(50),
+            },
+        ))
+        .add_systems(Update, animate_units);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Animation {
+                    start_index: 10,
+                    frame_durations: [100, 100, 100, 100],
+                    current_frame: 0,
+                    frame_timer: Timer::new(Duration::from_millis(100), TimerMode::Once),
+                },
+                Sprite {
+                    texture_atlas: Some(TextureAtlas {
+                        layout: Handle::default(),
+                        index: 10,
+                    }),
+                    ..default()
+                },
+            ))
+            .id();
+
+        // The first frame only establishes the time baseline.
+        app.update();
+        for _ in 0..6 {
+            app.update();
+        }
+
+        let entity = app.world().entity(entity);
+        assert_eq!(entity.get::<Animation>().unwrap().current_frame, 3);
+        assert_eq!(
+            entity
+                .get::<Sprite>()
+                .unwrap()
+                .texture_atlas
+                .as_ref()
+                .unwrap()
+                .index,
+            13
+        );
+    }
+
+    #[test]
+    fn terrain_and_unit_entities_use_sprite_size_presets() {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, CorePlugin));
+
+        let terrain_entity = app
+            .world_mut()
+            .spawn((
+                MapPosition::new(0, 0),
+                TerrainTile {
+                    terrain: awbrn_types::GraphicalTerrain::Plain,
+                },
+            ))
+            .id();
+        let unit_entity = app
+            .world_mut()
+            .spawn((MapPosition::new(0, 0), Unit(awbrn_types::Unit::Infantry)))
+            .id();
+
+        assert_eq!(
+            app.world().entity(terrain_entity).get::<SpriteSize>(),
+            Some(&SpriteSize::TERRAIN)
+        );
+        assert_eq!(
+            app.world().entity(unit_entity).get::<SpriteSize>(),
+            Some(&SpriteSize::UNIT)
+        );
+    }
 
     /// Test MapPosition -> Transform observer including updates
     #[test]
@@ -134,17 +249,9 @@ mod tests {
 
         // Register visual required components needed for the observer
         app.world_mut()
-            .register_required_components_with::<Unit, SpriteSize>(|| SpriteSize {
-                width: 23.0,
-                height: 24.0,
-                z_index: RenderLayer::UNIT,
-            });
+            .register_required_components_with::<Unit, SpriteSize>(|| SpriteSize::UNIT);
         app.world_mut()
-            .register_required_components_with::<TerrainTile, SpriteSize>(|| SpriteSize {
-                width: 16.0,
-                height: 32.0,
-                z_index: RenderLayer::TERRAIN,
-            });
+            .register_required_components_with::<TerrainTile, SpriteSize>(|| SpriteSize::TERRAIN);
         app.world_mut()
             .register_required_components::<MapPosition, Transform>();
 
@@ -172,6 +279,8 @@ mod tests {
             .unwrap();
         let unit_transform = *app.world().entity(unit_entity).get::<Transform>().unwrap();
 
+        // Expected values are the tile center plus `coords::sprite_alignment_offset`:
+        // terrain sits 8px up, units sit 3.5px left and 4px up.
         assert!(
             terrain_transform
                 .translation
@@ -180,7 +289,7 @@ mod tests {
         assert!(
             unit_transform
                 .translation
-                .abs_diff_eq(Vec3::new(124.5, -36.0, 4.0), 0.1)
+                .abs_diff_eq(Vec3::new(124.5, -36.0, 5.0), 0.1)
         );
 
         app.world_mut()
@@ -207,7 +316,7 @@ mod tests {
         assert!(
             updated_unit_transform
                 .translation
-                .abs_diff_eq(Vec3::new(140.5, -20.0, 4.0), 0.1)
+                .abs_diff_eq(Vec3::new(140.5, -20.0, 5.0), 0.1)
         );
 
         assert_ne!(
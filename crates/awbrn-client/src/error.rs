@@ -0,0 +1,94 @@
+use crate::JsonAssetError;
+use awbrn_map::MapError;
+use awbw_replay::ReplayError;
+
+/// Any error raised while loading a map, replay, or asset, so front ends have
+/// a single type to present to users.
+#[derive(Debug)]
+pub enum AwbrnError {
+    Map(MapError),
+    Replay(ReplayError),
+    Asset(JsonAssetError),
+}
+
+impl From<MapError> for AwbrnError {
+    fn from(error: MapError) -> Self {
+        AwbrnError::Map(error)
+    }
+}
+
+impl From<ReplayError> for AwbrnError {
+    fn from(error: ReplayError) -> Self {
+        AwbrnError::Replay(error)
+    }
+}
+
+impl From<JsonAssetError> for AwbrnError {
+    fn from(error: JsonAssetError) -> Self {
+        AwbrnError::Asset(error)
+    }
+}
+
+impl std::error::Error for AwbrnError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AwbrnError::Map(e) => Some(e),
+            AwbrnError::Replay(e) => Some(e),
+            AwbrnError::Asset(e) => Some(e),
+        }
+    }
+}
+
+impl std::fmt::Display for AwbrnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AwbrnError::Map(e) => write!(f, "Unable to load map: {}", e),
+            AwbrnError::Replay(e) => write!(f, "Unable to load replay: {}", e),
+            AwbrnError::Asset(e) => write!(f, "Unable to load asset: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn map_errors_convert_and_display() {
+        let error = AwbrnError::from(MapError::EmptyMap);
+
+        assert!(matches!(error, AwbrnError::Map(MapError::EmptyMap)));
+        assert_eq!(
+            error.to_string(),
+            "Unable to load map: Map data is empty or contains no valid terrain"
+        );
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn replay_errors_convert_and_display() {
+        let replay_error = awbw_replay::ReplayParser::new()
+            .parse(b"not a replay")
+            .unwrap_err();
+        let expected = format!("Unable to load replay: {}", replay_error);
+        let error = AwbrnError::from(replay_error);
+
+        assert!(matches!(error, AwbrnError::Replay(_)));
+        assert_eq!(error.to_string(), expected);
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn asset_errors_convert_and_display() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "tiles.json missing");
+        let error = AwbrnError::from(JsonAssetError::from(io_error));
+
+        assert!(matches!(error, AwbrnError::Asset(JsonAssetError::Io(_))));
+        assert_eq!(
+            error.to_string(),
+            "Unable to load asset: IO error: tiles.json missing"
+        );
+        assert!(error.source().is_some());
+    }
+}
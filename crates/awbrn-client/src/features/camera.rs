@@ -1,8 +1,10 @@
-use crate::core::coords::{map_visual_top_world_y, map_visual_world_size};
+use crate::core::coords::{LogicalPx, WorldPos, map_visual_top_world_y, map_visual_world_size};
 use crate::features::event_bus::{EventSink, MapDimensions};
+use crate::features::input::selection_modifier_held;
 use crate::loading::ClientAssetLoader;
 use crate::render::UnitAtlasResource;
 use awbrn_game::world::GameMap;
+use bevy::ecs::system::SystemParam;
 use bevy::input::{
     ButtonState,
     mouse::{MouseButtonInput, MouseScrollUnit, MouseWheel},
@@ -16,6 +18,11 @@ use std::collections::BTreeMap;
 pub struct CameraScale(f32);
 
 impl CameraScale {
+    /// Create a camera scale clamped to the supported zoom range.
+    pub fn new(scale: f32) -> Self {
+        CameraScale(scale.clamp(MIN_CAMERA_SCALE, MAX_CAMERA_SCALE))
+    }
+
     pub fn scale(&self) -> f32 {
         self.0
     }
@@ -39,7 +46,49 @@ impl Default for CameraScale {
     }
 }
 
+/// Color drawn around the map, e.g. when the viewport is larger than the map.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct MapBackgroundColor(pub Color);
+
+impl Default for MapBackgroundColor {
+    fn default() -> Self {
+        MapBackgroundColor(ClearColor::default().0)
+    }
+}
+
+/// Pans the camera toward the tile of the most recent replay action.
+///
+/// Follow mode disengages as soon as the user pans manually and stays off
+/// until toggled again.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct CameraFollow {
+    pub enabled: bool,
+    /// World position the camera is easing toward, if any.
+    pub target: Option<Vec2>,
+}
+
+impl CameraFollow {
+    /// Ease toward `target` if follow mode is enabled.
+    pub fn focus(&mut self, target: Vec2) {
+        if self.enabled {
+            self.target = Some(target);
+        }
+    }
+
+    fn disengage(&mut self) {
+        if self.enabled {
+            info!("Camera follow disengaged by manual pan");
+        }
+        self.enabled = false;
+        self.target = None;
+    }
+}
+
 const DEFAULT_CAMERA_SCALE: f32 = 2.0;
+/// Exponential decay rate, per second, of the camera's distance to the
+/// follow target.
+const CAMERA_FOLLOW_RATE: f32 = 6.0;
+const CAMERA_FOLLOW_ARRIVAL_DISTANCE: f32 = 0.5;
 const KEYBOARD_ZOOM_FACTOR: f32 = 1.25;
 const MAX_CAMERA_SCALE: f32 = 4.0;
 const MIN_CAMERA_SCALE: f32 = 0.2;
@@ -62,9 +111,17 @@ struct MousePanState {
     dragging: bool,
 }
 
-fn setup_camera(mut commands: Commands, camera_scale: Res<CameraScale>) {
+fn setup_camera(
+    mut commands: Commands,
+    camera_scale: Res<CameraScale>,
+    background: Res<MapBackgroundColor>,
+) {
     commands.spawn((
         Camera2d,
+        Camera {
+            clear_color: ClearColorConfig::Custom(background.0),
+            ..default()
+        },
         Projection::Orthographic(OrthographicProjection {
             scaling_mode: bevy::camera::ScalingMode::WindowSize,
             scale: 1.0 / camera_scale.scale(),
@@ -74,11 +131,30 @@ fn setup_camera(mut commands: Commands, camera_scale: Res<CameraScale>) {
     ));
 }
 
+/// Logs when unit animations index past the generated unit spritesheet grid,
+/// e.g. new units or factions without regenerated assets. Sprites would
+/// otherwise silently render from the wrong cells. Panics in debug builds.
+fn check_unit_atlas_bounds() -> bool {
+    let max_index = awbrn_content::max_unit_sprite_index();
+    let cells = awbrn_content::UNIT_SPRITESHEET_COLUMNS * awbrn_content::UNIT_SPRITESHEET_ROWS;
+    if max_index < cells {
+        return true;
+    }
+
+    error!(
+        "Unit animations reach sprite index {max_index} but the generated unit spritesheet has {cells} cells; regenerate the unit assets"
+    );
+    debug_assert!(max_index < cells, "unit spritesheet layout mismatch");
+    false
+}
+
 fn setup_unit_atlas(
     mut commands: Commands,
     asset_loader: ClientAssetLoader,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
+    check_unit_atlas_bounds();
+
     let texture = asset_loader.load_unit_texture();
     let layout = TextureAtlasLayout::from_grid(
         UVec2::new(
@@ -126,6 +202,28 @@ fn minimum_camera_scale(game_map: &GameMap, window: &Window) -> f32 {
     fit_scale.clamp(MIN_CAMERA_SCALE, DEFAULT_CAMERA_SCALE)
 }
 
+/// World-space point under `position` on the canvas.
+///
+/// Uses the camera's transform and zoom directly rather than Bevy's cached
+/// viewport, so it is valid before the first frame renders. `position` is in
+/// CSS pixels; the window's scale factor is already folded into its logical
+/// size.
+pub fn canvas_to_world(
+    camera_transform: &Transform,
+    projection: &Projection,
+    window: &Window,
+    position: LogicalPx,
+) -> WorldPos {
+    let world_units_per_viewport_pixel =
+        projection_world_units_per_viewport_pixel(projection).unwrap_or(1.0);
+    WorldPos::from_bevy(viewport_to_world(
+        camera_transform.translation.truncate(),
+        world_units_per_viewport_pixel,
+        window,
+        position.to_vec2(),
+    ))
+}
+
 fn viewport_to_world(
     camera_translation: Vec2,
     world_units_per_viewport_pixel: f32,
@@ -357,6 +455,7 @@ fn handle_touch_camera(
     mut camera_scale: ResMut<CameraScale>,
     mut touch_reader: MessageReader<TouchInput>,
     mut touch_state: ResMut<TouchCameraState>,
+    mut follow: ResMut<CameraFollow>,
     mut query: Query<(&mut Projection, &mut Transform), With<Camera>>,
 ) {
     let Ok(window) = windows.single() else {
@@ -415,6 +514,9 @@ fn handle_touch_camera(
             let world_delta = viewport_delta_to_world_delta(viewport_delta, projection_scale);
             transform.translation -= world_delta.extend(0.0);
             clamp_camera_translation(&mut transform, window, game_map.as_ref(), projection_scale);
+            if viewport_delta != Vec2::ZERO {
+                follow.disengage();
+            }
         }
         2 => {
             let contacts = touch_state.contacts.values().copied().collect::<Vec<_>>();
@@ -472,23 +574,32 @@ fn handle_touch_camera(
     }
 }
 
+#[derive(SystemParam)]
+struct MousePanInput<'w, 's> {
+    button_reader: MessageReader<'w, 's, MouseButtonInput>,
+    cursor_reader: MessageReader<'w, 's, CursorMoved>,
+    keyboard_input: Res<'w, ButtonInput<KeyCode>>,
+}
+
 fn handle_mouse_pan(
     windows: Query<&Window>,
     game_map: Res<GameMap>,
     mut pan_state: ResMut<MousePanState>,
-    mut button_reader: MessageReader<MouseButtonInput>,
-    mut cursor_reader: MessageReader<CursorMoved>,
+    mut input: MousePanInput,
+    mut follow: ResMut<CameraFollow>,
     mut query: Query<(&mut Projection, &mut Transform), With<Camera>>,
 ) {
-    for event in button_reader.read() {
+    for event in input.button_reader.read() {
         if event.button == MouseButton::Left {
-            pan_state.dragging = event.state == ButtonState::Pressed;
+            // Shift-drags select tiles instead of panning
+            pan_state.dragging = event.state == ButtonState::Pressed
+                && !selection_modifier_held(&input.keyboard_input);
         }
     }
 
     if !pan_state.dragging {
         // Consume pending cursor events so they don't accumulate while not dragging
-        for _ in cursor_reader.read() {}
+        for _ in input.cursor_reader.read() {}
         return;
     }
 
@@ -502,15 +613,64 @@ fn handle_mouse_pan(
         return;
     };
 
-    for cursor in cursor_reader.read() {
+    for cursor in input.cursor_reader.read() {
         if let Some(delta) = cursor.delta {
             let world_delta = viewport_delta_to_world_delta(delta, projection_scale);
             transform.translation -= world_delta.extend(0.0);
             clamp_camera_translation(&mut transform, window, game_map.as_ref(), projection_scale);
+            follow.disengage();
         }
     }
 }
 
+pub(crate) fn handle_camera_follow_toggle(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut follow: ResMut<CameraFollow>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyF) {
+        follow.enabled = !follow.enabled;
+        follow.target = None;
+        info!("Camera follow enabled: {}", follow.enabled);
+    }
+}
+
+/// Eases the camera toward the [`CameraFollow`] target, clearing the target
+/// once the camera arrives or can't get any closer because of the map bounds.
+pub(crate) fn follow_camera_target(
+    time: Res<Time>,
+    windows: Query<&Window>,
+    game_map: Res<GameMap>,
+    mut follow: ResMut<CameraFollow>,
+    mut query: Query<(&Projection, &mut Transform), With<Camera>>,
+) {
+    let Some(target) = follow.target.filter(|_| follow.enabled) else {
+        return;
+    };
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((projection, mut transform)) = query.single_mut() else {
+        return;
+    };
+    let Some(projection_scale) = projection_world_units_per_viewport_pixel(projection) else {
+        return;
+    };
+
+    let before = transform.translation.truncate();
+    let t = 1.0 - (-CAMERA_FOLLOW_RATE * time.delta_secs()).exp();
+    let eased = before.lerp(target, t);
+    transform.translation.x = eased.x;
+    transform.translation.y = eased.y;
+    clamp_camera_translation(&mut transform, window, game_map.as_ref(), projection_scale);
+
+    let after = transform.translation.truncate();
+    if after.distance(target) <= CAMERA_FOLLOW_ARRIVAL_DISTANCE
+        || (t > 0.0 && after.abs_diff_eq(before, f32::EPSILON))
+    {
+        follow.target = None;
+    }
+}
+
 fn snap_camera_to_device_pixels(
     windows: Query<&Window>,
     mut query: Query<(&Projection, &mut Transform), With<Camera>>,
@@ -538,22 +698,42 @@ fn emit_map_dimensions_on_scale_change(
 
 pub struct CameraPlugin;
 
+fn apply_map_background_color(
+    background: Res<MapBackgroundColor>,
+    mut cameras: Query<&mut Camera, With<Camera2d>>,
+) {
+    for mut camera in &mut cameras {
+        camera.clear_color = ClearColorConfig::Custom(background.0);
+    }
+}
+
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CameraScale>()
+            .init_resource::<MapBackgroundColor>()
             .init_resource::<TouchCameraState>()
             .init_resource::<MousePanState>()
+            .init_resource::<CameraFollow>()
             .add_systems(Startup, (setup_camera, setup_unit_atlas))
+            .add_systems(
+                Update,
+                apply_map_background_color.run_if(resource_changed::<MapBackgroundColor>),
+            )
             .add_systems(
                 Update,
                 (
                     handle_touch_camera,
                     handle_camera_scaling,
                     handle_mouse_pan,
+                    (handle_camera_follow_toggle, follow_camera_target)
+                        .chain()
+                        .after(handle_touch_camera)
+                        .after(handle_mouse_pan),
                     snap_camera_to_device_pixels
                         .after(handle_touch_camera)
                         .after(handle_camera_scaling)
-                        .after(handle_mouse_pan),
+                        .after(handle_mouse_pan)
+                        .after(follow_camera_target),
                 )
                     .run_if(in_state(crate::core::AppState::InGame)),
             )
@@ -585,6 +765,13 @@ mod tests {
         game_map
     }
 
+    #[test]
+    fn unit_animations_fit_within_unit_atlas() {
+        let cells = awbrn_content::UNIT_SPRITESHEET_COLUMNS * awbrn_content::UNIT_SPRITESHEET_ROWS;
+        assert!(awbrn_content::max_unit_sprite_index() < cells);
+        assert!(check_unit_atlas_bounds());
+    }
+
     fn test_window(width: u32, height: u32) -> Window {
         Window {
             resolution: WindowResolution::new(width, height),
@@ -615,6 +802,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn setting_map_background_color_updates_camera_clear_color() {
+        let mut app = App::new();
+        app.init_resource::<MapBackgroundColor>().add_systems(
+            Update,
+            apply_map_background_color.run_if(resource_changed::<MapBackgroundColor>),
+        );
+        let camera = app.world_mut().spawn(Camera2d).id();
+
+        let color = Color::srgb_u8(10, 20, 30);
+        app.insert_resource(MapBackgroundColor(color));
+        app.update();
+
+        assert!(matches!(
+            app.world().entity(camera).get::<Camera>().unwrap().clear_color,
+            ClearColorConfig::Custom(c) if c == color
+        ));
+    }
+
     #[test]
     fn zoom_keeps_anchor_world_position_stable() {
         let game_map = test_map(40, 40);
@@ -2,7 +2,8 @@ use crate::features::camera::{CameraScale, compute_map_dimensions};
 use awbrn_game::world::GameMap;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::io::Write;
+use std::sync::{Arc, Mutex, PoisonError};
 
 /// A resource that receives events of type T.
 ///
@@ -23,6 +24,79 @@ impl<T: Send + Sync + 'static> EventSink<T> {
     }
 }
 
+/// Records every event emitted through its sinks as JSON lines.
+///
+/// Intended for debugging and regression tests: install a sink per event type
+/// with [`EventRecorder::sink`], advance [`EventRecorder::set_step`] as replay
+/// actions are applied, and diff the resulting log against a golden file.
+#[derive(Clone, Default)]
+pub struct EventRecorder {
+    inner: Arc<Mutex<EventRecorderState>>,
+}
+
+#[derive(Default)]
+struct EventRecorderState {
+    step: usize,
+    lines: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct RecordedEvent<'a, T> {
+    step: usize,
+    event: &'a str,
+    payload: &'a T,
+}
+
+impl EventRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the step index attached to subsequently recorded events.
+    pub fn set_step(&self, step: usize) {
+        self.state().step = step;
+    }
+
+    /// Creates an [`EventSink`] that records payloads under the given event name.
+    pub fn sink<T>(&self, event: &'static str) -> EventSink<T>
+    where
+        T: Serialize + Send + Sync + 'static,
+    {
+        let recorder = self.clone();
+        EventSink::new(move |payload: T| recorder.record(event, &payload))
+    }
+
+    pub fn record<T: Serialize>(&self, event: &str, payload: &T) {
+        let mut state = self.state();
+        let line = serde_json::to_string(&RecordedEvent {
+            step: state.step,
+            event,
+            payload,
+        })
+        .expect("event payloads serialize to JSON");
+        state.lines.push(line);
+    }
+
+    /// Returns the recorded events, one JSON object per line.
+    pub fn to_json_lines(&self) -> String {
+        let state = self.state();
+        let mut out = String::new();
+        for line in &state.lines {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn write_json_lines<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(self.to_json_lines().as_bytes())
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, EventRecorderState> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
 #[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
@@ -65,6 +139,36 @@ pub struct TileSelected {
     pub terrain_type: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct TileHovered {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// Inclusive tile bounds of the current rectangular selection, or `None`
+/// when the selection was cleared.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionChanged {
+    pub bounds: Option<SelectionBounds>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionBounds {
+    pub min_x: usize,
+    pub min_y: usize,
+    pub max_x: usize,
+    pub max_y: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
 #[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
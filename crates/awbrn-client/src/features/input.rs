@@ -1,13 +1,15 @@
-use crate::core::coords::{LogicalPx, TILE_SIZE, map_position_to_world_translation};
+use crate::core::coords::{LogicalPx, TILE_SIZE, WorldPos, map_position_to_world_translation};
 use crate::core::{RenderLayer, SpriteSize};
-use crate::features::event_bus::{EventSink, TileSelected};
+use crate::features::event_bus::{
+    EventSink, SelectionBounds, SelectionChanged, TileHovered, TileSelected,
+};
 use crate::render::UiAtlas;
 use awbrn_game::MapPosition;
 use awbrn_game::world::{BoardIndex, GameMap, TerrainTile};
 use awbrn_map::Position;
 use bevy::input::touch::{TouchInput, TouchPhase};
 use bevy::prelude::*;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Component to mark the currently selected tile
 #[derive(Component)]
@@ -17,6 +19,58 @@ pub struct SelectedTile;
 #[derive(Component)]
 pub struct TileCursor;
 
+/// Map coordinate currently under the pointer, if any.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HoveredTile(pub Option<Position>);
+
+/// Tiles in the current rectangular selection, made by shift-dragging
+/// across the map.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection(pub BTreeSet<Position>);
+
+impl Selection {
+    /// Select every tile in the rectangle spanned by two corners.
+    pub fn rectangle(a: Position, b: Position) -> Self {
+        let xs = a.x.min(b.x)..=a.x.max(b.x);
+        let ys = a.y.min(b.y)..=a.y.max(b.y);
+        Selection(
+            ys.flat_map(|y| xs.clone().map(move |x| Position::new(x, y)))
+                .collect(),
+        )
+    }
+
+    /// Inclusive bounds of the selected tiles.
+    pub fn bounds(&self) -> Option<SelectionBounds> {
+        let first = self.0.first()?;
+        Some(self.0.iter().fold(
+            SelectionBounds {
+                min_x: first.x,
+                min_y: first.y,
+                max_x: first.x,
+                max_y: first.y,
+            },
+            |bounds, position| SelectionBounds {
+                min_x: bounds.min_x.min(position.x),
+                min_y: bounds.min_y.min(position.y),
+                max_x: bounds.max_x.max(position.x),
+                max_y: bounds.max_y.max(position.y),
+            },
+        ))
+    }
+}
+
+/// Corner where the in-progress selection drag started.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct SelectionDrag {
+    anchor: Option<Position>,
+}
+
+/// Marker for the highlight sprite drawn over each selected tile.
+#[derive(Component)]
+pub struct SelectionHighlight;
+
+const SELECTION_HIGHLIGHT_COLOR: Color = Color::srgba(0.3, 0.6, 1.0, 0.35);
+
 #[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TileClicked {
     pub position: Position,
@@ -56,36 +110,41 @@ pub(crate) fn spawn_tile_cursor(mut commands: Commands, ui_atlas: UiAtlas) {
     commands.spawn(tile_cursor_bundle(ui_atlas));
 }
 
+/// Records the tile under `world_pos` into [`HoveredTile`], only touching
+/// the resource when the coordinate actually changes.
+pub(crate) fn record_hovered_tile(
+    world_pos: Option<WorldPos>,
+    game_map: &GameMap,
+    hovered_tile: &mut impl DetectChangesMut<Inner = HoveredTile>,
+) -> Option<MapPosition> {
+    let map_position = world_pos.and_then(|world_pos| world_pos.to_map_position(game_map));
+    hovered_tile.set_if_neq(HoveredTile(
+        map_position.map(|map_position| map_position.position()),
+    ));
+    map_position
+}
+
 pub(crate) fn update_tile_cursor(
     windows: Query<&Window>,
     camera_q: Query<(&Camera, &GlobalTransform)>,
     game_map: Res<GameMap>,
+    mut hovered_tile: ResMut<HoveredTile>,
     mut cursor_q: Query<(&mut Transform, &mut Visibility), With<TileCursor>>,
 ) {
-    let Ok((mut transform, mut visibility)) = cursor_q.single_mut() else {
-        return;
-    };
+    let world_pos = windows
+        .single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .zip(camera_q.single().ok())
+        .and_then(|(cursor_pos, (camera, camera_transform))| {
+            LogicalPx::from_window_position(cursor_pos).to_world(camera, camera_transform)
+        });
+    let map_position = record_hovered_tile(world_pos, game_map.as_ref(), &mut hovered_tile);
 
-    let Ok(window) = windows.single() else {
-        *visibility = Visibility::Hidden;
-        return;
-    };
-
-    let Ok((camera, camera_transform)) = camera_q.single() else {
-        *visibility = Visibility::Hidden;
-        return;
-    };
-    let Some(cursor_pos) = window.cursor_position() else {
-        *visibility = Visibility::Hidden;
-        return;
-    };
-    let Some(world_pos) =
-        LogicalPx::from_window_position(cursor_pos).to_world(camera, camera_transform)
-    else {
-        *visibility = Visibility::Hidden;
+    let Ok((mut transform, mut visibility)) = cursor_q.single_mut() else {
         return;
     };
-    let Some(map_position) = world_pos.to_map_position(game_map.as_ref()) else {
+    let Some(map_position) = map_position else {
         *visibility = Visibility::Hidden;
         return;
     };
@@ -98,6 +157,81 @@ pub(crate) fn update_tile_cursor(
     *visibility = Visibility::Visible;
 }
 
+/// Whether the modifier that turns a left-drag into a selection (instead of
+/// a camera pan) is held.
+pub(crate) fn selection_modifier_held(keyboard_input: &ButtonInput<KeyCode>) -> bool {
+    keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+}
+
+/// Grows the [`Selection`] rectangle from where a shift-drag started to the
+/// hovered tile. Escape, or a click without the selection modifier, clears
+/// the selection.
+pub(crate) fn update_drag_selection(
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    hovered_tile: Res<HoveredTile>,
+    mut drag: ResMut<SelectionDrag>,
+    mut selection: ResMut<Selection>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        drag.anchor = None;
+        selection.set_if_neq(Selection::default());
+    }
+
+    if mouse_button_input.just_pressed(MouseButton::Left) {
+        drag.anchor = hovered_tile
+            .0
+            .filter(|_| selection_modifier_held(&keyboard_input));
+        if drag.anchor.is_none() {
+            selection.set_if_neq(Selection::default());
+        }
+    }
+
+    if let Some(anchor) = drag.anchor
+        && let Some(hovered) = hovered_tile.0
+    {
+        selection.set_if_neq(Selection::rectangle(anchor, hovered));
+    }
+
+    if !mouse_button_input.pressed(MouseButton::Left) {
+        drag.anchor = None;
+    }
+}
+
+/// Redraws one highlight per selected tile whenever the selection changes.
+pub(crate) fn sync_selection_highlights(
+    mut commands: Commands,
+    selection: Res<Selection>,
+    game_map: Res<GameMap>,
+    highlights: Query<Entity, With<SelectionHighlight>>,
+) {
+    for entity in &highlights {
+        commands.entity(entity).despawn();
+    }
+
+    for &position in &selection.0 {
+        let translation = map_position_to_world_translation(
+            &TILE_CORE_SPRITE_SIZE,
+            MapPosition::from(position),
+            game_map.as_ref(),
+        );
+        commands.spawn((
+            SelectionHighlight,
+            Sprite::from_color(SELECTION_HIGHLIGHT_COLOR, Vec2::splat(TILE_SIZE)),
+            Transform::from_translation(translation - Vec3::Z * 0.5),
+        ));
+    }
+}
+
+pub(crate) fn emit_selection_changed(
+    selection: Res<Selection>,
+    sink: Res<EventSink<SelectionChanged>>,
+) {
+    sink.emit(SelectionChanged {
+        bounds: selection.bounds(),
+    });
+}
+
 pub(crate) fn detect_map_clicks(
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window>,
@@ -254,22 +388,48 @@ pub(crate) fn on_tile_selected(
     sink.emit(TileSelected {
         x: pos.x,
         y: pos.y,
-        terrain_type: format!("{:?}", tile.terrain),
+        terrain_type: tile.terrain.as_terrain().to_string(),
     });
 }
 
+pub(crate) fn emit_tile_hovered(hovered_tile: Res<HoveredTile>, sink: Res<EventSink<TileHovered>>) {
+    if let Some(position) = hovered_tile.0 {
+        sink.emit(TileHovered {
+            x: position.x,
+            y: position.y,
+        });
+    }
+}
+
 pub struct InputPlugin;
 
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TouchTapState>();
+        app.init_resource::<HoveredTile>();
+        app.init_resource::<Selection>();
+        app.init_resource::<SelectionDrag>();
         app.add_message::<TileClicked>();
         app.add_observer(on_tile_selected);
         app.add_systems(
             Update,
             (
                 (detect_map_clicks, detect_touch_taps).chain(),
-                update_tile_cursor,
+                (
+                    update_tile_cursor,
+                    emit_tile_hovered.run_if(
+                        resource_changed::<HoveredTile>
+                            .and(resource_exists::<EventSink<TileHovered>>),
+                    ),
+                    update_drag_selection,
+                    sync_selection_highlights.run_if(resource_changed::<Selection>),
+                    emit_selection_changed.run_if(
+                        resource_changed::<Selection>
+                            .and(not(resource_added::<Selection>))
+                            .and(resource_exists::<EventSink<SelectionChanged>>),
+                    ),
+                )
+                    .chain(),
             )
                 .run_if(in_state(crate::core::AppState::InGame)),
         );
@@ -283,3 +443,179 @@ impl Plugin for InputPlugin {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_bus::EventRecorder;
+    use awbrn_types::GraphicalTerrain;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn hover_at(world: &mut World, world_pos: Option<WorldPos>) -> Option<Position> {
+        world
+            .run_system_once(
+                move |game_map: Res<GameMap>, mut hovered_tile: ResMut<HoveredTile>| {
+                    record_hovered_tile(world_pos, &game_map, &mut hovered_tile);
+                },
+            )
+            .unwrap();
+        world.resource::<HoveredTile>().0
+    }
+
+    #[test]
+    fn shift_drag_selects_the_spanned_rectangle() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<HoveredTile>()
+            .init_resource::<Selection>()
+            .init_resource::<SelectionDrag>()
+            .add_systems(Update, update_drag_selection);
+        let recorder = EventRecorder::new();
+        app.insert_resource(recorder.sink::<SelectionChanged>("SelectionChanged"));
+        app.add_systems(
+            Update,
+            emit_selection_changed
+                .after(update_drag_selection)
+                .run_if(resource_changed::<Selection>.and(not(resource_added::<Selection>))),
+        );
+
+        let step = |app: &mut App, hovered: Position, press: Option<bool>| {
+            app.world_mut().resource_mut::<HoveredTile>().0 = Some(hovered);
+            {
+                let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+                mouse.clear();
+                match press {
+                    Some(true) => mouse.press(MouseButton::Left),
+                    Some(false) => mouse.release(MouseButton::Left),
+                    None => {}
+                }
+            }
+            app.update();
+        };
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ShiftLeft);
+        step(&mut app, Position::new(1, 1), Some(true));
+        step(&mut app, Position::new(2, 1), None);
+        step(&mut app, Position::new(3, 2), None);
+        step(&mut app, Position::new(3, 2), Some(false));
+        // Hovering after release leaves the selection alone.
+        step(&mut app, Position::new(5, 5), None);
+
+        let expected = Selection::rectangle(Position::new(1, 1), Position::new(3, 2));
+        assert_eq!(*app.world().resource::<Selection>(), expected);
+        assert_eq!(
+            expected.0.iter().copied().collect::<Vec<_>>(),
+            [(1, 1), (1, 2), (2, 1), (2, 2), (3, 1), (3, 2)].map(|(x, y)| Position::new(x, y))
+        );
+
+        let lines = recorder.to_json_lines();
+        let last: serde_json::Value = serde_json::from_str(lines.lines().last().unwrap()).unwrap();
+        assert_eq!(last["event"], "SelectionChanged");
+        assert_eq!(
+            last["payload"]["bounds"],
+            serde_json::json!({"minX": 1, "minY": 1, "maxX": 3, "maxY": 2})
+        );
+    }
+
+    #[test]
+    fn escape_and_plain_clicks_clear_the_selection() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(HoveredTile(Some(Position::new(1, 1))))
+            .init_resource::<Selection>()
+            .init_resource::<SelectionDrag>()
+            .add_systems(Update, update_drag_selection);
+        let recorder = EventRecorder::new();
+        app.insert_resource(recorder.sink::<SelectionChanged>("SelectionChanged"));
+        app.add_systems(
+            Update,
+            emit_selection_changed
+                .after(update_drag_selection)
+                .run_if(resource_changed::<Selection>.and(not(resource_added::<Selection>))),
+        );
+        app.update();
+
+        let selected = Selection::rectangle(Position::new(0, 0), Position::new(1, 1));
+        let last_bounds = |recorder: &EventRecorder| {
+            let lines = recorder.to_json_lines();
+            let last: serde_json::Value =
+                serde_json::from_str(lines.lines().last().unwrap()).unwrap();
+            last["payload"]["bounds"].clone()
+        };
+
+        *app.world_mut().resource_mut::<Selection>() = selected.clone();
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        app.update();
+        assert!(app.world().resource::<Selection>().0.is_empty());
+        assert_eq!(last_bounds(&recorder), serde_json::Value::Null);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        *app.world_mut().resource_mut::<Selection>() = selected;
+        app.update();
+        assert_ne!(last_bounds(&recorder), serde_json::Value::Null);
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        assert!(app.world().resource::<Selection>().0.is_empty());
+        assert_eq!(last_bounds(&recorder), serde_json::Value::Null);
+    }
+
+    #[test]
+    fn plain_drag_does_not_select() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(HoveredTile(Some(Position::new(1, 1))))
+            .init_resource::<Selection>()
+            .init_resource::<SelectionDrag>()
+            .add_systems(Update, update_drag_selection);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        app.world_mut().resource_mut::<HoveredTile>().0 = Some(Position::new(4, 4));
+        app.update();
+
+        assert!(app.world().resource::<Selection>().0.is_empty());
+    }
+
+    #[test]
+    fn hovering_a_world_position_records_its_tile() {
+        let mut world = World::new();
+        world.init_resource::<GameMap>();
+        world.init_resource::<HoveredTile>();
+        world
+            .resource_mut::<GameMap>()
+            .set(awbrn_map::AwbrnMap::new(3, 2, GraphicalTerrain::Plain));
+
+        let tile_center = map_position_to_world_translation(
+            &TILE_CORE_SPRITE_SIZE,
+            MapPosition::new(2, 1),
+            world.resource::<GameMap>(),
+        );
+        assert_eq!(
+            hover_at(
+                &mut world,
+                Some(WorldPos::from_bevy(tile_center.truncate()))
+            ),
+            Some(Position::new(2, 1))
+        );
+
+        assert_eq!(
+            hover_at(&mut world, Some(WorldPos::new(1000.0, 1000.0))),
+            None
+        );
+        assert_eq!(hover_at(&mut world, None), None);
+    }
+}
@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+
+use crate::core::{AppState, RenderLayer};
+
+/// Solid color covering the viewport until a map is in game, so the first
+/// frames don't flash the camera's clear color. Front ends opt in by
+/// inserting this resource.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct LoadingSplashColor(pub Color);
+
+/// Marker for the splash shown while no map is in game.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct LoadingSplash;
+
+/// Large enough to cover the viewport at any camera zoom and position.
+const LOADING_SPLASH_SIZE: f32 = 1_000_000.0;
+
+fn spawn_loading_splash(
+    mut commands: Commands,
+    color: Res<LoadingSplashColor>,
+    existing: Query<(), With<LoadingSplash>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        LoadingSplash,
+        Sprite::from_color(color.0, Vec2::splat(LOADING_SPLASH_SIZE)),
+        Transform::from_xyz(0.0, 0.0, f32::from(RenderLayer::LOADING_SPLASH)),
+    ));
+}
+
+fn despawn_loading_splash(mut commands: Commands, splashes: Query<Entity, With<LoadingSplash>>) {
+    for entity in &splashes {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub struct LoadingSplashPlugin;
+
+impl Plugin for LoadingSplashPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Startup,
+            spawn_loading_splash.run_if(resource_exists::<LoadingSplashColor>),
+        )
+        .add_systems(
+            OnEnter(AppState::Loading),
+            spawn_loading_splash.run_if(resource_exists::<LoadingSplashColor>),
+        )
+        .add_systems(OnEnter(AppState::InGame), despawn_loading_splash);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    fn splash_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<(), With<LoadingSplash>>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn splash_shown_while_loading_and_removed_in_game() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<AppState>()
+            .insert_resource(LoadingSplashColor(Color::BLACK))
+            .add_plugins(LoadingSplashPlugin);
+
+        app.update();
+        assert_eq!(splash_count(&mut app), 1);
+
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Loading);
+        app.update();
+        assert_eq!(splash_count(&mut app), 1);
+
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::InGame);
+        app.update();
+        assert_eq!(splash_count(&mut app), 0);
+
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Loading);
+        app.update();
+        assert_eq!(splash_count(&mut app), 1);
+    }
+
+    #[test]
+    fn no_splash_without_color() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<AppState>()
+            .add_plugins(LoadingSplashPlugin);
+
+        app.update();
+        assert_eq!(splash_count(&mut app), 0);
+    }
+}
@@ -2,17 +2,22 @@ pub mod camera;
 pub mod event_bus;
 pub mod fog;
 pub mod input;
+pub mod loading_splash;
+pub mod pause;
 pub mod player_display;
 pub mod player_roster;
 pub mod weather;
 
 pub use awbrn_game::world::{CurrentWeather, FogActive, FogOfWarMap, FriendlyFactions};
-pub use camera::CameraScale;
+pub use camera::{CameraFollow, CameraScale, MapBackgroundColor};
 pub use event_bus::{
-    EventSink, MapDimensions, NewDay, PlayerRosterEntry, PlayerRosterSnapshot, PlayerRosterStats,
-    ReplayLoaded, ReplayLoadedPlayer, TileSelected, UnitBuilt, UnitMoved,
+    EventRecorder, EventSink, MapDimensions, NewDay, PlayerRosterEntry, PlayerRosterSnapshot,
+    PlayerRosterStats, ReplayLoaded, ReplayLoadedPlayer, SelectionBounds, SelectionChanged,
+    TileHovered, TileSelected, UnitBuilt, UnitMoved,
 };
-pub use input::{SelectedTile, TileCursor};
+pub use input::{HoveredTile, SelectedTile, Selection, TileCursor};
+pub use loading_splash::{LoadingSplash, LoadingSplashColor};
+pub use pause::AnimationsPaused;
 
 use bevy::prelude::*;
 
@@ -24,6 +29,8 @@ impl Plugin for FeaturesPlugin {
             weather::WeatherPlugin,
             camera::CameraPlugin,
             input::InputPlugin,
+            loading_splash::LoadingSplashPlugin,
+            pause::PausePlugin,
             fog::FogPlugin,
             player_display::PlayerDisplayPlugin,
         ));
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use bevy::window::WindowFocused;
+
+/// Why game time is paused. Animations and replay playback advance off
+/// virtual time, so pausing it freezes both and lets a background window or
+/// tab stop burning CPU.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnimationsPaused {
+    /// Paused explicitly by the user. Focus changes leave this alone.
+    pub by_user: bool,
+
+    /// Paused because the window or tab lost focus.
+    pub unfocused: bool,
+}
+
+impl AnimationsPaused {
+    pub fn is_paused(&self) -> bool {
+        self.by_user || self.unfocused
+    }
+}
+
+pub(crate) fn track_window_focus(
+    mut messages: MessageReader<WindowFocused>,
+    mut paused: ResMut<AnimationsPaused>,
+) {
+    let Some(focus) = messages.read().last() else {
+        return;
+    };
+
+    let unfocused = !focus.focused;
+    if paused.unfocused != unfocused {
+        paused.unfocused = unfocused;
+        info!("Window focus changed, unfocused: {}", unfocused);
+    }
+}
+
+pub(crate) fn apply_animations_paused(
+    paused: Res<AnimationsPaused>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if paused.is_paused() {
+        time.pause();
+    } else {
+        time.unpause();
+    }
+}
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<WindowFocused>()
+            .init_resource::<AnimationsPaused>()
+            .add_systems(
+                Update,
+                (
+                    track_window_focus,
+                    apply_animations_paused.run_if(resource_changed::<AnimationsPaused>),
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimePlugin;
+
+    fn set_focused(app: &mut App, window: Entity, focused: bool) {
+        app.world_mut()
+            .write_message(WindowFocused { window, focused });
+        app.update();
+    }
+
+    fn is_paused(app: &App) -> bool {
+        app.world().resource::<Time<Virtual>>().is_paused()
+    }
+
+    #[test]
+    fn focus_loss_pauses_and_focus_gain_resumes() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, PausePlugin));
+        let window = app.world_mut().spawn_empty().id();
+        app.update();
+        assert!(!is_paused(&app));
+
+        set_focused(&mut app, window, false);
+        assert!(is_paused(&app));
+
+        set_focused(&mut app, window, true);
+        assert!(!is_paused(&app));
+    }
+
+    #[test]
+    fn focus_gain_keeps_an_explicit_pause() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, PausePlugin));
+        let window = app.world_mut().spawn_empty().id();
+        app.world_mut().resource_mut::<AnimationsPaused>().by_user = true;
+        app.update();
+        assert!(is_paused(&app));
+
+        set_focused(&mut app, window, false);
+        set_focused(&mut app, window, true);
+        assert!(is_paused(&app));
+
+        app.world_mut().resource_mut::<AnimationsPaused>().by_user = false;
+        app.update();
+        assert!(!is_paused(&app));
+    }
+}
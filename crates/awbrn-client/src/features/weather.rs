@@ -3,6 +3,22 @@ pub use awbrn_game::world::CurrentWeather;
 use awbrn_types::Weather;
 use bevy::prelude::*;
 
+/// Request from a front end to switch the current weather.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetWeather(pub Weather);
+
+pub(crate) fn apply_set_weather(
+    mut messages: MessageReader<SetWeather>,
+    mut current_weather: ResMut<CurrentWeather>,
+) {
+    if let Some(SetWeather(weather)) = messages.read().last().copied()
+        && current_weather.weather() != weather
+    {
+        current_weather.set(weather);
+        info!("Weather set to: {:?}", weather);
+    }
+}
+
 pub(crate) fn handle_weather_toggle(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut current_weather: ResMut<CurrentWeather>,
@@ -23,9 +39,14 @@ pub struct WeatherPlugin;
 
 impl Plugin for WeatherPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CurrentWeather>().add_systems(
-            Update,
-            handle_weather_toggle.run_if(in_state(crate::core::AppState::InGame)),
-        );
+        app.add_message::<SetWeather>()
+            .init_resource::<CurrentWeather>()
+            .add_systems(
+                Update,
+                (
+                    apply_set_weather,
+                    handle_weather_toggle.run_if(in_state(crate::core::AppState::InGame)),
+                ),
+            );
     }
 }
@@ -1,20 +1,26 @@
 mod awbrn_plugin;
 pub mod core;
+mod error;
 pub mod features;
 mod json_plugin;
 pub mod loading;
 pub mod modes;
 pub mod projection;
 pub mod render;
+#[cfg(feature = "testing")]
+pub mod test_support;
 mod ui_atlas;
 
 pub use awbrn_plugin::AwbrnPlugin;
+pub use error::AwbrnError;
 pub use features::event_bus::{
-    EventSink, MapDimensions, NewDay, PlayerRosterEntry, PlayerRosterSnapshot, PlayerRosterStats,
-    ReplayLoaded, ReplayLoadedPlayer, TileSelected, UnitBuilt, UnitMoved,
+    EventRecorder, EventSink, MapDimensions, NewDay, PlayerRosterEntry, PlayerRosterSnapshot,
+    PlayerRosterStats, ReplayLoaded, ReplayLoadedPlayer, SelectionBounds, SelectionChanged,
+    TileHovered, TileSelected, UnitBuilt, UnitMoved,
 };
 pub use json_plugin::*;
 pub use loading::{
-    MapAssetPathResolver, PendingGameStart, PendingMatchMap, ReplayToLoad, StaticAssetPathResolver,
+    MapAssetPathResolver, PendingGameStart, PendingMatchMap, PrefixMapAssetPathResolver,
+    ReplayToLoad, StaticAssetPathResolver,
 };
 pub use ui_atlas::*;
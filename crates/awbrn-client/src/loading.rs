@@ -1,7 +1,7 @@
-use crate::UiAtlasAsset;
 use crate::core::{AppState, GameMode, LoadingState};
 use crate::features::event_bus::{EventSink, ReplayLoaded, ReplayLoadedPlayer};
 use crate::render::UiAtlasResource;
+use crate::{AwbrnError, UiAtlasAsset};
 use awbrn_content::co_portrait_by_awbw_id;
 use awbrn_game::world::GameMap;
 use awbrn_map::{AwbrnMap, AwbwMap, AwbwMapData, Position};
@@ -26,6 +26,39 @@ impl MapAssetPathResolver for DefaultMapAssetPathResolver {
     }
 }
 
+/// Resolves map ids to `{base}/{id}.{ext}`, for serving maps from a CDN or a
+/// subdirectory (e.g. `https://cdn.example.com/maps` with `json.gz`). A base
+/// ending in a query parameter (`...?maps_id=`) takes the id directly, and an
+/// empty extension is left off.
+pub struct PrefixMapAssetPathResolver {
+    base: String,
+    ext: String,
+}
+
+impl PrefixMapAssetPathResolver {
+    pub fn new(base: impl Into<String>, ext: impl Into<String>) -> Self {
+        Self {
+            base: base.into(),
+            ext: ext.into(),
+        }
+    }
+}
+
+impl MapAssetPathResolver for PrefixMapAssetPathResolver {
+    fn resolve_path(&self, map_id: u32) -> String {
+        let mut path = if self.base.ends_with('=') {
+            format!("{}{}", self.base, map_id)
+        } else {
+            format!("{}/{}", self.base.trim_end_matches('/'), map_id)
+        };
+        if !self.ext.is_empty() {
+            path.push('.');
+            path.push_str(&self.ext);
+        }
+        path
+    }
+}
+
 /// Trait for resolving static asset paths from logical asset keys.
 pub trait StaticAssetPathResolver: Send + Sync + 'static {
     fn resolve_path(&self, logical_path: &str) -> String;
@@ -155,7 +188,7 @@ pub(crate) fn detect_replay_to_load(
     let replay = match parser.parse(&replay_to_load.0) {
         Ok(replay) => replay,
         Err(e) => {
-            error!("Failed to parse replay: {:?}", e);
+            error!("{}", AwbrnError::from(e));
             return;
         }
     };
@@ -219,7 +252,7 @@ pub(crate) fn detect_pending_match_map(
     let awbw_map = match AwbwMap::try_from(&pending_map.0) {
         Ok(map) => map,
         Err(error) => {
-            error!("Failed to parse match map data: {:?}", error);
+            error!("{}", AwbrnError::from(error));
             commands.remove_resource::<PendingMatchMap>();
             return;
         }
@@ -384,6 +417,39 @@ pub(crate) fn setup_ui_atlas(
     info!("UI atlas resource initialized");
 }
 
+/// Report a `ui.png` that doesn't match `ui_atlas.json`. Only the atlas data is
+/// checked in, so a texture generated before the data last changed draws the
+/// wrong sprites until both are regenerated with `cargo xtask-assets ui`.
+pub(crate) fn check_ui_texture_matches_atlas(
+    pending_ui: Res<PendingUiAtlas>,
+    images: Res<Assets<Image>>,
+    ui_atlas_assets: Res<Assets<UiAtlasAsset>>,
+    mut checked: Local<bool>,
+) {
+    if *checked {
+        return;
+    }
+
+    let (Some(image), Some(ui_atlas)) = (
+        images.get(&pending_ui.texture),
+        ui_atlas_assets.get(&pending_ui.atlas),
+    ) else {
+        return;
+    };
+
+    *checked = true;
+    let expected = UVec2::new(ui_atlas.size.width, ui_atlas.size.height);
+    if image.size() != expected {
+        error!(
+            "UI texture is {}x{} but the UI atlas data expects {}x{}; regenerate both with `cargo xtask-assets ui`",
+            image.width(),
+            image.height(),
+            expected.x,
+            expected.y
+        );
+    }
+}
+
 pub(crate) fn transition_to_in_game(mut next_app_state: ResMut<NextState<AppState>>) {
     next_app_state.set(AppState::InGame);
 }
@@ -427,6 +493,7 @@ impl Plugin for LoadingPlugin {
                     detect_pending_match_map.run_if(resource_exists::<PendingMatchMap>),
                     emit_pending_replay_loaded_event
                         .run_if(resource_exists::<PendingReplayLoadedEvent>),
+                    check_ui_texture_matches_atlas.run_if(resource_exists::<PendingUiAtlas>),
                 ),
             )
             .add_systems(
@@ -457,6 +524,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn prefix_map_asset_resolver_formats_base_id_and_extension() {
+        let resolver = PrefixMapAssetPathResolver::new("https://cdn.example.com/maps", "json.gz");
+
+        assert_eq!(
+            resolver.resolve_path(162795),
+            "https://cdn.example.com/maps/162795.json.gz"
+        );
+    }
+
+    #[test]
+    fn prefix_map_asset_resolver_ignores_trailing_slash() {
+        let resolver = PrefixMapAssetPathResolver::new("maps/", "json");
+
+        assert_eq!(resolver.resolve_path(7), "maps/7.json");
+    }
+
+    #[test]
+    fn prefix_map_asset_resolver_appends_id_to_query_parameter() {
+        let resolver = PrefixMapAssetPathResolver::new(
+            "https://awbw.amarriner.com/api/map/map_info.php?maps_id=",
+            "",
+        );
+
+        assert_eq!(
+            resolver.resolve_path(162795),
+            "https://awbw.amarriner.com/api/map/map_info.php?maps_id=162795"
+        );
+    }
+
     #[test]
     fn default_static_asset_resolver_returns_logical_path() {
         let resolver = DefaultStaticAssetPathResolver;
@@ -548,8 +645,8 @@ mod tests {
 
     #[test]
     fn replay_loaded_event_resolves_cos_and_sorts_players() {
-        let replay = AwbwReplay {
-            games: vec![AwbwGame {
+        let replay = AwbwReplay::new(
+            vec![AwbwGame {
                 id: awbrn_types::AwbwGameId::new(7),
                 name: "Test Replay".to_string(),
                 password: None,
@@ -604,8 +701,8 @@ mod tests {
                 timers_increment: 0,
                 timers_max_turn: 0,
             }],
-            turns: Vec::new(),
-        };
+            Vec::new(),
+        );
 
         let replay_loaded = replay_loaded_event(&replay).expect("replay should emit roster event");
         assert_eq!(replay_loaded.game_id, 7);
@@ -626,8 +723,8 @@ mod tests {
 
     #[test]
     fn replay_loaded_event_uses_null_for_unknown_cos() {
-        let replay = AwbwReplay {
-            games: vec![AwbwGame {
+        let replay = AwbwReplay::new(
+            vec![AwbwGame {
                 id: awbrn_types::AwbwGameId::new(9),
                 name: "Unknown CO Replay".to_string(),
                 password: None,
@@ -672,8 +769,8 @@ mod tests {
                 timers_increment: 0,
                 timers_max_turn: 0,
             }],
-            turns: Vec::new(),
-        };
+            Vec::new(),
+        );
 
         let replay_loaded = replay_loaded_event(&replay).expect("replay should emit roster event");
         assert_eq!(replay_loaded.players.len(), 1);
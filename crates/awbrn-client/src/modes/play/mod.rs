@@ -5,10 +5,11 @@ use crate::core::{AppState, GameMode, RenderLayer, SpriteSize};
 use crate::features::input::TileClicked;
 use awbrn_game::MapPosition;
 use awbrn_game::world::{
-    BoardIndex, CarriedBy, Faction, FriendlyFactions, Fuel, GameMap, Unit, UnitActive,
+    BoardIndex, CarriedBy, CurrentWeather, Faction, FriendlyFactions, Fuel, GameMap, Unit,
+    UnitActive,
 };
-use awbrn_map::{MovementMap, PathFinder, Position, TerrainCosts};
-use awbrn_types::{GraphicalTerrain, MovementCost, MovementTerrain, UnitMovement};
+use awbrn_map::{MovementMap, MovementTypeCosts, PathFinder, Position};
+use awbrn_types::{GraphicalTerrain, MovementTerrain};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
@@ -63,6 +64,13 @@ pub(crate) struct PlaySelectionState<'w> {
     phase: ResMut<'w, PlayUiPhase>,
 }
 
+/// The board a unit's move range is computed against
+#[derive(SystemParam)]
+pub(crate) struct MoveRangeBoard<'w> {
+    game_map: Res<'w, GameMap>,
+    weather: Res<'w, CurrentWeather>,
+}
+
 #[derive(Clone)]
 struct ClientMovementMap {
     width: usize,
@@ -103,16 +111,6 @@ impl MovementMap for ClientMovementMap {
     }
 }
 
-struct UnitMovementCosts {
-    movement_type: UnitMovement,
-}
-
-impl TerrainCosts for UnitMovementCosts {
-    fn cost(&self, terrain: MovementTerrain) -> Option<u8> {
-        MovementCost::from_terrain(&terrain).cost(self.movement_type)
-    }
-}
-
 type UnitSelectionQueryItem<'a> = (
     &'a Unit,
     &'a Faction,
@@ -148,21 +146,18 @@ fn movement_budget(unit: awbrn_types::Unit, fuel: Option<&Fuel>) -> u8 {
 fn collect_terrain(game_map: &GameMap) -> Vec<MovementTerrain> {
     let mut terrain = Vec::with_capacity(game_map.width() * game_map.height());
 
-    for y in 0..game_map.height() {
-        for x in 0..game_map.width() {
-            let position = Position::new(x, y);
-            let graphical = game_map
-                .terrain_at(position)
-                .unwrap_or(GraphicalTerrain::Plain);
-            terrain.push(MovementTerrain::from(graphical));
-        }
+    for position in game_map.positions() {
+        let graphical = game_map
+            .terrain_at(position)
+            .unwrap_or(GraphicalTerrain::Plain);
+        terrain.push(MovementTerrain::from(graphical));
     }
 
     terrain
 }
 
 fn compute_move_range(
-    game_map: &GameMap,
+    board: &MoveRangeBoard<'_>,
     moving_entity: Entity,
     origin: Position,
     unit: awbrn_types::Unit,
@@ -170,6 +165,7 @@ fn compute_move_range(
     friendly_factions: &FriendlyFactions,
     occupancy: &Query<OccupancyQueryItem<'_>, With<Unit>>,
 ) -> HashMap<Position, u8> {
+    let game_map = &*board.game_map;
     let width = game_map.width();
     let height = game_map.height();
     let mut blocked = vec![false; width * height];
@@ -198,9 +194,7 @@ fn compute_move_range(
         terrain: collect_terrain(game_map),
         blocked,
     };
-    let costs = UnitMovementCosts {
-        movement_type: unit.movement_type(),
-    };
+    let costs = MovementTypeCosts::new(unit.movement_type()).with_weather(board.weather.weather());
     let mut pathfinder = PathFinder::new(map);
     pathfinder
         .reachable(origin, movement_budget(unit, fuel), costs)
@@ -231,7 +225,7 @@ fn select_unit(
 
 fn confirm_selected_destination(
     destination: Position,
-    game_map: &GameMap,
+    board: &MoveRangeBoard<'_>,
     friendly_factions: &FriendlyFactions,
     units: &Query<UnitSelectionQueryItem<'_>, With<Unit>>,
     occupancy: &Query<OccupancyQueryItem<'_>, With<Unit>>,
@@ -255,7 +249,7 @@ fn confirm_selected_destination(
     }
 
     let range = compute_move_range(
-        game_map,
+        board,
         selected_unit.entity,
         selected_unit.origin,
         unit.0,
@@ -281,7 +275,7 @@ fn confirm_selected_destination(
 
 pub(crate) fn handle_play_tile_clicks(
     board_index: Res<BoardIndex>,
-    game_map: Res<GameMap>,
+    board: MoveRangeBoard<'_>,
     friendly_factions: Res<FriendlyFactions>,
     mut click_reader: MessageReader<TileClicked>,
     units: Query<UnitSelectionQueryItem<'_>, With<Unit>>,
@@ -296,7 +290,7 @@ pub(crate) fn handle_play_tile_clicks(
         if selection.move_range.tiles.contains_key(&position) {
             confirm_selected_destination(
                 position,
-                &game_map,
+                &board,
                 &friendly_factions,
                 &units,
                 &occupancy,
@@ -323,7 +317,7 @@ pub(crate) fn handle_play_tile_clicks(
 
     let origin = map_position.position();
     let range = compute_move_range(
-        &game_map,
+        &board,
         unit_entity,
         origin,
         unit.0,
@@ -440,7 +434,7 @@ impl Plugin for PlayPlugin {
 mod tests {
     use super::*;
     use awbrn_game::world::initialize_terrain_semantic_world;
-    use awbrn_types::PlayerFaction;
+    use awbrn_types::{PlayerFaction, Weather};
 
     fn play_test_app() -> App {
         let mut app = App::new();
@@ -450,6 +444,7 @@ mod tests {
         app.add_message::<TileClicked>();
         app.init_resource::<BoardIndex>();
         app.init_resource::<GameMap>();
+        app.init_resource::<CurrentWeather>();
         app.init_resource::<FriendlyFactions>();
         app.init_resource::<ButtonInput<KeyCode>>();
         app.add_plugins(PlayPlugin);
@@ -600,6 +595,35 @@ mod tests {
         assert!(!range.contains_key(&Position::new(6, 3)));
     }
 
+    #[test]
+    fn snow_limits_move_range() {
+        let mut app = play_test_app();
+        set_plain_map(&mut app, 7, 7);
+        app.world_mut()
+            .resource_mut::<FriendlyFactions>()
+            .0
+            .insert(PlayerFaction::OrangeStar);
+        app.world_mut()
+            .resource_mut::<CurrentWeather>()
+            .set(Weather::Snow);
+
+        spawn_unit(
+            &mut app,
+            Position::new(3, 3),
+            awbrn_types::Unit::Tank,
+            PlayerFaction::OrangeStar,
+            true,
+            None,
+        );
+
+        click_tile(&mut app, Position::new(3, 3));
+
+        // Treads pay 2 per plains tile in snow, halving the tank's reach
+        let range = &app.world().resource::<MoveRange>().tiles;
+        assert_eq!(range.get(&Position::new(3, 0)), Some(&6));
+        assert!(!range.contains_key(&Position::new(0, 0)));
+    }
+
     #[test]
     fn enemy_occupied_tiles_block_and_friendly_tiles_only_block_stopping() {
         let mut app = play_test_app();
@@ -2,19 +2,90 @@ use awbw_replay::AwbwReplay;
 use bevy::prelude::*;
 
 use crate::features::player_display::PlayerDisplayFactionOverrides;
-use crate::features::player_roster::{emit_player_roster_updated, player_roster_seed_from_replay};
+use crate::features::player_roster::{
+    PlayerFunds, PlayerUnitCosts, emit_player_roster_updated, player_roster_seed_from_replay,
+};
 use crate::loading::LoadedReplay;
 use crate::modes::replay::commands::ReplayAdvanceLock;
-use awbrn_game::replay::initialize_replay_semantic_world;
+use awbrn_game::MapPosition;
+use awbrn_game::replay::{
+    PlaybackDiagnostics, PowerMovementBoosts, PowerVisionBoosts, ReplayPlayerRegistry,
+    ReplayTerrainKnowledge, initialize_replay_semantic_world,
+};
+use awbrn_game::snapshot::{GameSnapshot, capture_game_snapshot, restore_game_snapshot};
+use awbrn_game::world::{CurrentWeather, GameMap, TerrainTile};
+
+/// The replay world before its first action, restored when seeking backwards.
+#[derive(Resource)]
+pub(crate) struct ReplayStartSnapshot {
+    snapshot: GameSnapshot,
+    weather: Option<CurrentWeather>,
+    funds: Option<PlayerFunds>,
+    unit_costs: Option<PlayerUnitCosts>,
+    diagnostics: Option<PlaybackDiagnostics>,
+}
+
+impl ReplayStartSnapshot {
+    fn capture(world: &mut World) -> Option<Self> {
+        let snapshot = capture_game_snapshot(world)
+            .inspect_err(|err| warn!("Unable to capture the replay start: {err}"))
+            .ok()?;
+
+        Some(Self {
+            snapshot,
+            weather: world.get_resource::<CurrentWeather>().copied(),
+            funds: world.get_resource::<PlayerFunds>().cloned(),
+            unit_costs: world.get_resource::<PlayerUnitCosts>().cloned(),
+            diagnostics: world.get_resource::<PlaybackDiagnostics>().cloned(),
+        })
+    }
+
+    /// Puts the units, terrain, and replay resources back to how they stood
+    /// before the first action.
+    pub(crate) fn restore(&self, world: &mut World) {
+        if let Err(err) = restore_game_snapshot(world, &self.snapshot) {
+            warn!("Unable to rewind the replay: {err}");
+            return;
+        }
+
+        // Captures rewrite the map, so rebuild it from the restored tiles
+        let tiles: Vec<_> = world
+            .query::<(&MapPosition, &TerrainTile)>()
+            .iter(world)
+            .map(|(map_pos, tile)| (map_pos.position(), tile.terrain))
+            .collect();
+        let mut game_map = world.resource_mut::<GameMap>();
+        for (position, terrain) in tiles {
+            game_map.set_terrain(position, terrain);
+        }
+
+        let knowledge = ReplayTerrainKnowledge::from_map_and_registry(
+            world.resource::<GameMap>(),
+            world.resource::<ReplayPlayerRegistry>(),
+        );
+        world.insert_resource(knowledge);
+
+        if let Some(weather) = self.weather {
+            world.insert_resource(weather);
+        }
+        if let Some(funds) = &self.funds {
+            world.insert_resource(funds.clone());
+        }
+        if let Some(unit_costs) = &self.unit_costs {
+            world.insert_resource(unit_costs.clone());
+        }
+        world.insert_resource(PowerVisionBoosts::default());
+        world.insert_resource(PowerMovementBoosts::default());
+        // Keep what the parser reported up front, such as unrecognized units
+        world.insert_resource(self.diagnostics.clone().unwrap_or_default());
+    }
+}
 
 pub fn initialize_replay_semantic_world_for_client(world: &mut World) {
     let replay = world
         .get_resource::<LoadedReplay>()
         .map(|r| r.0.clone())
-        .unwrap_or_else(|| AwbwReplay {
-            games: Vec::new(),
-            turns: Vec::new(),
-        });
+        .unwrap_or_else(|| AwbwReplay::new(Vec::new(), Vec::new()));
 
     initialize_replay_semantic_world(&replay, world);
 
@@ -32,6 +103,10 @@ pub fn initialize_replay_semantic_world_for_client(world: &mut World) {
 
     world.insert_resource(ReplayAdvanceLock::default());
     emit_player_roster_updated(world);
+
+    if let Some(start) = ReplayStartSnapshot::capture(world) {
+        world.insert_resource(start);
+    }
 }
 
 #[cfg(test)]
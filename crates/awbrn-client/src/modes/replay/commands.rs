@@ -4,23 +4,29 @@
 //! immediate mutations that are visible to subsequent queries within the same
 //! command execution.
 
-use awbw_replay::turn_models::{Action, MoveAction};
+use awbw_replay::turn_models::{Action, MoveAction, UnitMap};
 use bevy::{log, prelude::*};
 
-use crate::features::event_bus::{EventSink, NewDay as ExternalNewDay};
+use crate::core::coords::tile_center_world;
+use crate::features::camera::CameraFollow;
+use crate::features::event_bus::{EventSink, NewDay as ExternalNewDay, UnitBuilt};
 use crate::features::player_roster::{
     PlayerFunds, PlayerRosterConfig, PlayerUnitCosts, emit_player_roster_updated,
     player_ids_for_team,
 };
+use crate::loading::LoadedReplay;
+use crate::modes::replay::bootstrap::ReplayStartSnapshot;
+use crate::modes::replay::diff::{TurnBaseline, emit_turn_diff};
 use crate::modes::replay::navigation::{
     PendingCourseArrows, path_positions, replay_move_view, replay_path_tiles,
 };
-use crate::render::animation::UnitPathAnimation;
+use crate::render::animation::{SpawnAnim, UnitPathAnimation};
+use awbrn_game::MapPosition;
 use awbrn_game::replay::{
     AwbwUnitId, NewDay, ReplayState, apply_move_state,
-    apply_non_move_action as game_apply_non_move_action,
+    apply_non_move_action as game_apply_non_move_action, warn_playback,
 };
-use awbrn_game::world::{CarriedBy, Faction, StrongIdMap, Unit};
+use awbrn_game::world::{CarriedBy, Faction, GameMap, StrongIdMap, Unit};
 
 #[derive(Resource, Debug, Default)]
 pub struct ReplayAdvanceLock {
@@ -84,6 +90,10 @@ impl Command for ReplayFollowupCommand {
             world.trigger(super::fog::ReplayFogDirty);
         }
         emit_player_roster_updated(world);
+
+        if let Some(baseline) = world.remove_resource::<TurnBaseline>() {
+            emit_turn_diff(&baseline, world);
+        }
     }
 }
 
@@ -94,24 +104,208 @@ pub struct ReplayTurnCommand {
 
 impl Command for ReplayTurnCommand {
     fn apply(self, world: &mut World) {
+        let baseline = TurnBaseline::capture_if_observed(world);
+
         if let Some(mov) = self.action.move_action()
             && Self::apply_move(mov, &self.action, world)
         {
-            // Move started a path animation — fog recompute happens in
-            // ReplayFollowupCommand after animation completes.
+            // Move started a path animation — fog recompute and the turn diff
+            // happen in ReplayFollowupCommand after animation completes.
+            if let Some(baseline) = baseline {
+                world.insert_resource(baseline);
+            }
+            focus_camera_on_action(&self.action, world);
             return;
         }
 
         apply_non_move_action(&self.action, world);
+        focus_camera_on_action(&self.action, world);
         update_player_roster_funds(&self.action, world);
         update_player_roster_unit_costs(&self.action, world);
         world.trigger(super::fog::ReplayFogDirty);
         emit_player_roster_updated(world);
+        if let Some(baseline) = baseline {
+            emit_turn_diff(&baseline, world);
+        }
+    }
+}
+
+/// Applies every remaining action of `day` in one pass, as if each path
+/// animation finished instantly. Stops once the day rolls over, so the
+/// closing `End` action triggers a single [`NewDay`].
+pub struct ReplayApplyDayCommand {
+    pub day: u32,
+}
+
+impl Command for ReplayApplyDayCommand {
+    fn apply(self, world: &mut World) {
+        finish_pending_animation(world);
+
+        let current_day = world.resource::<ReplayState>().day;
+        if current_day != self.day {
+            log::warn!(
+                "Cannot apply day {} while the replay is on day {}",
+                self.day,
+                current_day
+            );
+            return;
+        }
+
+        while world.resource::<ReplayState>().day == self.day && apply_next_action_instantly(world)
+        {
+        }
+    }
+}
+
+/// Applies actions up to and including `target_index`, finishing every path
+/// animation instantly so a seek lands on its target in one step.
+pub struct ReplaySeekCommand {
+    pub target_index: usize,
+}
+
+impl Command for ReplaySeekCommand {
+    fn apply(self, world: &mut World) {
+        finish_pending_animation(world);
+
+        while world.resource::<ReplayState>().next_action_index as usize <= self.target_index
+            && apply_next_action_instantly(world)
+        {}
+    }
+}
+
+/// Resets the replay to before its first action, so a following
+/// [`ReplaySeekCommand`] can land on an earlier action.
+pub struct ReplayRewindCommand;
+
+impl Command for ReplayRewindCommand {
+    fn apply(self, world: &mut World) {
+        if !world.contains_resource::<ReplayStartSnapshot>() {
+            log::warn!("Cannot rewind a replay that wasn't captured at its start");
+            return;
+        }
+
+        finish_pending_animation(world);
+        world.remove_resource::<TurnBaseline>();
+        world.resource_scope(|world, start: Mut<ReplayStartSnapshot>| start.restore(world));
+    }
+}
+
+/// Apply the next replay action and finish any path animation it started.
+/// Returns `false` once the replay has no actions left.
+fn apply_next_action_instantly(world: &mut World) -> bool {
+    let next_action_index = world.resource::<ReplayState>().next_action_index as usize;
+    let Some(action) = world
+        .get_resource::<LoadedReplay>()
+        .and_then(|replay| replay.0.turns.get(next_action_index))
+        .cloned()
+    else {
+        return false;
+    };
+
+    world.resource_mut::<ReplayState>().next_action_index += 1;
+    ReplayTurnCommand { action }.apply(world);
+    finish_pending_animation(world);
+    true
+}
+
+/// Skip to the end of the path animation holding the replay, applying its
+/// deferred follow-up now instead of when the animation completes.
+fn finish_pending_animation(world: &mut World) {
+    let Some(entity) = world.resource::<ReplayAdvanceLock>().active_entity() else {
+        return;
+    };
+
+    if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+        // Leave the component so the next frame snaps the unit to the end of
+        // its path and restores its idle visuals.
+        if let Some(mut path_animation) = entity_mut.get_mut::<UnitPathAnimation>() {
+            path_animation.elapsed = path_animation.total_duration;
+        }
+        entity_mut.remove::<PendingCourseArrows>();
+    }
+
+    let followup = world
+        .resource_mut::<ReplayAdvanceLock>()
+        .release_for(entity);
+    if let Some(followup) = followup {
+        ReplayFollowupCommand {
+            action: followup.action,
+            recompute_fog: followup.recompute_fog,
+        }
+        .apply(world);
     }
 }
 
 pub(crate) fn apply_non_move_action(action: &Action, world: &mut World) {
     game_apply_non_move_action(action, world);
+
+    if let Action::Build { new_unit, .. } = action {
+        insert_spawn_animations(new_unit, world);
+        emit_unit_built(new_unit, world);
+    }
+}
+
+/// Point [`CameraFollow`] at the tile of the unit acting in `action`.
+fn focus_camera_on_action(action: &Action, world: &mut World) {
+    if !world
+        .get_resource::<CameraFollow>()
+        .is_some_and(|follow| follow.enabled)
+    {
+        return;
+    }
+
+    let Some(entity) = action.primary_unit_id().and_then(|unit_id| {
+        world
+            .resource::<StrongIdMap<AwbwUnitId>>()
+            .get(&AwbwUnitId(unit_id))
+    }) else {
+        return;
+    };
+    let Some(&map_position) = world.get::<MapPosition>(entity) else {
+        return;
+    };
+
+    let target = tile_center_world(map_position, world.resource::<GameMap>()).to_vec2();
+    world.resource_mut::<CameraFollow>().focus(target);
+}
+
+/// Forward built units to a registered [`UnitBuilt`] sink.
+fn emit_unit_built(new_unit: &UnitMap, world: &World) {
+    let Some(sink) = world.get_resource::<EventSink<UnitBuilt>>() else {
+        return;
+    };
+
+    let Some(unit) = new_unit.values().find_map(|unit| unit.get_value()) else {
+        return;
+    };
+
+    let (Some(x), Some(y)) = (unit.units_x, unit.units_y) else {
+        return;
+    };
+
+    sink.emit(UnitBuilt {
+        unit_id: unit.units_id.as_u32(),
+        unit_type: unit.units_name.name().to_string(),
+        x: x as usize,
+        y: y as usize,
+        player_id: unit.units_players_id,
+    });
+}
+
+/// Give units that were just built a pop-in animation.
+fn insert_spawn_animations(new_unit: &UnitMap, world: &mut World) {
+    let entities: Vec<Entity> = {
+        let units = world.resource::<StrongIdMap<AwbwUnitId>>();
+        new_unit
+            .values()
+            .filter_map(|unit| unit.get_value())
+            .filter_map(|unit| units.get(&AwbwUnitId(unit.units_id)))
+            .collect()
+    };
+
+    for entity in entities {
+        world.entity_mut(entity).insert(SpawnAnim::default());
+    }
 }
 
 fn update_player_roster_funds(action: &Action, world: &mut World) {
@@ -284,7 +478,10 @@ fn targeted_players(
 impl ReplayTurnCommand {
     fn apply_move(move_action: &MoveAction, action: &Action, world: &mut World) -> bool {
         let Some((targeted_player, unit)) = replay_move_view(move_action) else {
-            log::warn!("Move action missing visible targeted player unit data");
+            warn_playback(
+                world,
+                "Move action missing visible targeted player unit data",
+            );
             return false;
         };
 
@@ -297,9 +494,12 @@ impl ReplayTurnCommand {
         };
 
         let Some(entity) = entity else {
-            log::warn!(
-                "Unit with ID {} not found in unit storage",
-                unit.units_id.as_u32()
+            warn_playback(
+                world,
+                format!(
+                    "Unit with ID {} not found in unit storage",
+                    unit.units_id.as_u32()
+                ),
             );
             return false;
         };
@@ -420,6 +620,7 @@ pub(crate) fn on_new_day(trigger: On<NewDay>, sink: If<Res<EventSink<ExternalNew
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modes::replay::diff::{ReplayTurnApplied, TurnDiff, UnitLocation, UnitRelocated};
     use crate::projection::{ProjectedTerrainRenderState, project_terrain_render_state};
     use crate::render::TerrainAtlasResource;
     use crate::render::map::{
@@ -439,9 +640,10 @@ mod tests {
     use awbw_replay::Hidden;
     use awbw_replay::Masked;
     use awbw_replay::turn_models::{
-        Action, BuildingInfo, CaptureAction, JoinAction, MoveAction, PathTile, SupplyAction,
-        TargetedPlayer, UnitProperty,
+        Action, BuildingInfo, CaptureAction, JoinAction, LoadAction, MoveAction, PathTile,
+        SupplyAction, TargetedPlayer, UnitProperty,
     };
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn one_step_paths_use_expected_single_segment_duration() {
@@ -623,6 +825,170 @@ mod tests {
         );
     }
 
+    #[test]
+    fn move_turn_diff_lists_moved_unit_from_and_to() {
+        let diffs = Arc::new(Mutex::new(Vec::<TurnDiff>::new()));
+        let mut app = replay_turn_test_app();
+        let sink_diffs = diffs.clone();
+        app.insert_resource(EventSink::new(move |event: ReplayTurnApplied| {
+            sink_diffs.lock().unwrap().push(event.diff);
+        }));
+        let unit_entity = spawn_test_unit(&mut app, Position::new(1, 2), CoreUnitId::new(1));
+
+        ReplayTurnCommand {
+            action: Action::Move(test_player_targeted_move_action(
+                CoreUnitId::new(1),
+                2,
+                2,
+                &[(1, 2), (2, 2)],
+                1,
+            )),
+        }
+        .apply(app.world_mut());
+
+        // The diff waits for the path animation to finish.
+        assert!(diffs.lock().unwrap().is_empty());
+
+        let followup = app
+            .world_mut()
+            .resource_mut::<ReplayAdvanceLock>()
+            .release_for(unit_entity)
+            .expect("move should hold the advance lock");
+        ReplayFollowupCommand {
+            action: followup.action,
+            recompute_fog: followup.recompute_fog,
+        }
+        .apply(app.world_mut());
+
+        let diffs = diffs.lock().unwrap();
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(
+            diffs[0],
+            TurnDiff {
+                moved: vec![UnitRelocated {
+                    unit_id: CoreUnitId::new(1),
+                    from: UnitLocation::Tile(Position::new(1, 2)),
+                    to: UnitLocation::Tile(Position::new(2, 2)),
+                }],
+                ..TurnDiff::default()
+            }
+        );
+    }
+
+    #[test]
+    fn load_and_unload_turn_diffs_list_relocations() {
+        let diffs = Arc::new(Mutex::new(Vec::<TurnDiff>::new()));
+        let mut app = replay_turn_test_app();
+        let sink_diffs = diffs.clone();
+        app.insert_resource(EventSink::new(move |event: ReplayTurnApplied| {
+            sink_diffs.lock().unwrap().push(event.diff);
+        }));
+        spawn_test_unit(&mut app, Position::new(2, 2), CoreUnitId::new(1));
+        spawn_test_unit(&mut app, Position::new(2, 3), CoreUnitId::new(2));
+
+        ReplayTurnCommand {
+            action: Action::Load {
+                move_action: None,
+                load_action: LoadAction {
+                    loaded: [(TargetedPlayer::Global, Hidden::Visible(CoreUnitId::new(2)))].into(),
+                    transport: [(TargetedPlayer::Global, Hidden::Visible(CoreUnitId::new(1)))]
+                        .into(),
+                },
+            },
+        }
+        .apply(app.world_mut());
+
+        ReplayTurnCommand {
+            action: Action::Unload {
+                unit: [(
+                    TargetedPlayer::Global,
+                    Hidden::Visible(test_unit_property(CoreUnitId::new(2), 3, 2)),
+                )]
+                .into(),
+                transport_id: CoreUnitId::new(1),
+                discovered: Default::default(),
+            },
+        }
+        .apply(app.world_mut());
+
+        let carried = UnitLocation::Carried {
+            transport_id: CoreUnitId::new(1),
+        };
+        let diffs = diffs.lock().unwrap();
+        assert_eq!(
+            *diffs,
+            vec![
+                TurnDiff {
+                    moved: vec![UnitRelocated {
+                        unit_id: CoreUnitId::new(2),
+                        from: UnitLocation::Tile(Position::new(2, 3)),
+                        to: carried,
+                    }],
+                    ..TurnDiff::default()
+                },
+                TurnDiff {
+                    moved: vec![UnitRelocated {
+                        unit_id: CoreUnitId::new(2),
+                        from: carried,
+                        to: UnitLocation::Tile(Position::new(3, 2)),
+                    }],
+                    ..TurnDiff::default()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn seek_command_skips_path_animations_up_to_target() {
+        let mut app = replay_turn_test_app();
+        let unit_entity = spawn_test_unit(&mut app, Position::new(1, 2), CoreUnitId::new(1));
+        let moves = [(2, 2), (3, 2), (4, 2)].map(|(x, y)| {
+            Action::Move(test_player_targeted_move_action(
+                CoreUnitId::new(1),
+                x,
+                y,
+                &[(x - 1, y), (x, y)],
+                1,
+            ))
+        });
+        app.insert_resource(LoadedReplay(awbw_replay::AwbwReplay::new(
+            Vec::new(),
+            moves.to_vec(),
+        )));
+
+        ReplaySeekCommand { target_index: 1 }.apply(app.world_mut());
+
+        assert_eq!(app.world().resource::<ReplayState>().next_action_index, 2);
+        assert!(!app.world().resource::<ReplayAdvanceLock>().is_active());
+        assert_eq!(
+            app.world()
+                .entity(unit_entity)
+                .get::<MapPosition>()
+                .unwrap()
+                .position(),
+            Position::new(3, 2)
+        );
+    }
+
+    #[test]
+    fn move_skips_turn_baseline_without_diff_sink() {
+        let mut app = replay_turn_test_app();
+        spawn_test_unit(&mut app, Position::new(1, 2), CoreUnitId::new(1));
+
+        ReplayTurnCommand {
+            action: Action::Move(test_player_targeted_move_action(
+                CoreUnitId::new(1),
+                2,
+                2,
+                &[(1, 2), (2, 2)],
+                1,
+            )),
+        }
+        .apply(app.world_mut());
+
+        assert!(!app.world().contains_resource::<TurnBaseline>());
+    }
+
     #[test]
     fn player_targeted_paths_still_request_animation_lock() {
         assert!(
@@ -1241,6 +1607,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn built_units_get_spawn_animation_but_deployed_units_do_not() {
+        let mut app = replay_turn_test_app();
+        let deployed_entity = spawn_test_unit(&mut app, Position::new(1, 1), CoreUnitId::new(1));
+
+        ReplayTurnCommand {
+            action: Action::Build {
+                new_unit: [(
+                    TargetedPlayer::Global,
+                    Hidden::Visible(test_unit_property(CoreUnitId::new(2), 3, 3)),
+                )]
+                .into(),
+                discovered: Default::default(),
+            },
+        }
+        .apply(app.world_mut());
+
+        let built_entity = app
+            .world()
+            .resource::<StrongIdMap<AwbwUnitId>>()
+            .get(&AwbwUnitId(CoreUnitId::new(2)))
+            .expect("built unit should be registered");
+
+        assert!(app.world().entity(built_entity).contains::<SpawnAnim>());
+        assert!(!app.world().entity(deployed_entity).contains::<SpawnAnim>());
+    }
+
+    #[test]
+    fn build_emits_unit_built_with_display_name() {
+        let mut app = replay_turn_test_app();
+        let recorder = crate::features::event_bus::EventRecorder::new();
+        app.insert_resource(recorder.sink::<UnitBuilt>("UnitBuilt"));
+
+        ReplayTurnCommand {
+            action: Action::Build {
+                new_unit: [(
+                    TargetedPlayer::Global,
+                    Hidden::Visible(test_unit_property_with_resources(
+                        CoreUnitId::new(2),
+                        3,
+                        4,
+                        awbrn_types::Unit::AntiAir,
+                        60,
+                        9,
+                    )),
+                )]
+                .into(),
+                discovered: Default::default(),
+            },
+        }
+        .apply(app.world_mut());
+
+        let line = recorder.to_json_lines();
+        let event: serde_json::Value = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(event["event"], "UnitBuilt");
+        assert_eq!(event["payload"]["unitType"], "Anti-Air");
+        assert_eq!(event["payload"]["unitId"], 2);
+        assert_eq!(event["payload"]["x"], 3);
+        assert_eq!(event["payload"]["y"], 4);
+    }
+
     fn replay_turn_test_app() -> App {
         let mut app = App::new();
         app.insert_resource(BoardIndex::new(40, 40));
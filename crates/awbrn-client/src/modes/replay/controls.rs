@@ -230,6 +230,65 @@ mod tests {
         assert_eq!(app.world().resource::<ReplayState>().next_action_index, 1);
     }
 
+    #[test]
+    fn replay_key_step_applies_capture_action() {
+        use awbrn_game::MapPosition;
+        use awbrn_game::world::{
+            CaptureProgress, GameMap, GraphicalHp, TerrainTile, Unit, UnitActive,
+        };
+        use awbrn_map::{AwbrnMap, Position};
+        use awbrn_types::{GraphicalTerrain, Property};
+        use awbw_replay::turn_models::{BuildingInfo, CaptureAction};
+
+        let mut app = replay_controls_test_app_with_actions(vec![Action::Capt {
+            move_action: None,
+            capture_action: CaptureAction {
+                building_info: BuildingInfo {
+                    buildings_capture: 10,
+                    buildings_id: 99,
+                    buildings_x: 2,
+                    buildings_y: 2,
+                    buildings_team: None,
+                },
+                vision: Default::default(),
+                income: None,
+            },
+        }]);
+
+        let city = GraphicalTerrain::Property(Property::City(awbrn_types::Faction::Neutral));
+        let mut map = AwbrnMap::new(3, 3, GraphicalTerrain::Plain);
+        map.set_terrain(Position::new(2, 2), city);
+        app.insert_resource(awbrn_game::world::BoardIndex::new(3, 3));
+        app.init_resource::<GameMap>();
+        app.world_mut().resource_mut::<GameMap>().set(map);
+        app.world_mut()
+            .spawn((MapPosition::new(2, 2), TerrainTile { terrain: city }));
+        let unit = app
+            .world_mut()
+            .spawn((
+                MapPosition::new(2, 2),
+                Unit(awbrn_types::Unit::Infantry),
+                awbrn_game::world::Faction(awbrn_types::PlayerFaction::OrangeStar),
+                AwbwUnitId(awbrn_types::AwbwUnitId::new(1)),
+                GraphicalHp(10),
+                UnitActive,
+            ))
+            .id();
+
+        send_key_event(&mut app, KeyCode::ArrowRight, ButtonState::Pressed, false);
+        app.update();
+
+        assert_eq!(app.world().resource::<ReplayState>().next_action_index, 1);
+        assert_eq!(
+            app.world()
+                .entity(unit)
+                .get::<CaptureProgress>()
+                .map(|progress| progress.value()),
+            Some(10)
+        );
+        assert!(!app.world().entity(unit).contains::<UnitActive>());
+    }
+
     fn replay_controls_test_app(action_count: usize) -> App {
         replay_controls_test_app_with_actions(vec![test_replay_action(); action_count])
     }
@@ -241,10 +300,7 @@ mod tests {
         app.insert_resource(ReplayState::default());
         app.insert_resource(ReplayAdvanceLock::default());
         app.insert_resource(StrongIdMap::<AwbwUnitId>::default());
-        app.insert_resource(LoadedReplay(AwbwReplay {
-            games: Vec::new(),
-            turns: actions,
-        }));
+        app.insert_resource(LoadedReplay(AwbwReplay::new(Vec::new(), actions)));
         app.init_resource::<crate::features::fog::FogOfWarMap>();
         app.init_resource::<crate::features::fog::FogActive>();
         app.init_resource::<crate::features::fog::FriendlyFactions>();
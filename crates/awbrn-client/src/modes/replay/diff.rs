@@ -0,0 +1,247 @@
+//! Structured summaries of what a single replay step changed.
+//!
+//! A [`TurnBaseline`] is captured before a replay action is applied and
+//! compared against the world once the action (including any follow-up
+//! deferred behind a path animation) has fully settled. The resulting
+//! [`TurnDiff`] is emitted as a [`ReplayTurnApplied`] event. Baselines are
+//! only captured while an [`EventSink<ReplayTurnApplied>`] is registered.
+
+use std::collections::{BTreeMap, HashMap};
+
+use awbrn_game::MapPosition;
+use awbrn_game::replay::AwbwUnitId;
+use awbrn_game::world::{CarriedBy, GameMap, GraphicalHp};
+use awbrn_map::Position;
+use awbrn_types::{AwbwGamePlayerId, Faction, GraphicalTerrain};
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::features::event_bus::EventSink;
+use crate::features::player_roster::PlayerFunds;
+
+/// Everything a replay step changed, in a stable order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TurnDiff {
+    pub moved: Vec<UnitRelocated>,
+    pub spawned: Vec<UnitSpawned>,
+    pub destroyed: Vec<awbrn_types::AwbwUnitId>,
+    pub hp_changed: Vec<UnitHpChanged>,
+    pub captured: Vec<TileCaptured>,
+    pub funds: Vec<FundsDelta>,
+}
+
+impl TurnDiff {
+    pub fn is_empty(&self) -> bool {
+        self == &TurnDiff::default()
+    }
+}
+
+/// Where a unit sits: on a map tile or inside a transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum UnitLocation {
+    Tile(Position),
+    Carried {
+        transport_id: awbrn_types::AwbwUnitId,
+    },
+}
+
+/// A unit that moved between tiles, or was loaded into or unloaded from a
+/// transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitRelocated {
+    pub unit_id: awbrn_types::AwbwUnitId,
+    pub from: UnitLocation,
+    pub to: UnitLocation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitSpawned {
+    pub unit_id: awbrn_types::AwbwUnitId,
+    /// `None` when the unit's location is unknown.
+    pub location: Option<UnitLocation>,
+}
+
+/// A surviving unit whose displayed hit points changed, from combat or a
+/// repair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitHpChanged {
+    pub unit_id: awbrn_types::AwbwUnitId,
+    pub from: u8,
+    pub to: u8,
+}
+
+/// A property tile whose owner changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TileCaptured {
+    pub position: Position,
+    pub owner: Faction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundsDelta {
+    pub player_id: AwbwGamePlayerId,
+    pub delta: i64,
+}
+
+/// Event emitted once a replay step has fully applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayTurnApplied {
+    pub diff: TurnDiff,
+}
+
+/// World state captured before a replay step, used to compute its [`TurnDiff`].
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TurnBaseline {
+    units: BTreeMap<awbrn_types::AwbwUnitId, Option<UnitLocation>>,
+    hp: BTreeMap<awbrn_types::AwbwUnitId, u8>,
+    owners: HashMap<Position, Faction>,
+    funds: HashMap<AwbwGamePlayerId, u32>,
+}
+
+impl TurnBaseline {
+    /// Capture a baseline only when a [`ReplayTurnApplied`] sink is registered.
+    pub fn capture_if_observed(world: &mut World) -> Option<Self> {
+        world
+            .contains_resource::<EventSink<ReplayTurnApplied>>()
+            .then(|| Self::capture(world))
+    }
+
+    pub fn capture(world: &mut World) -> Self {
+        let units = {
+            let mut query =
+                world.query::<(&AwbwUnitId, Option<&MapPosition>, Option<&CarriedBy>)>();
+            let world = &*world;
+            query
+                .iter(world)
+                .map(|(unit_id, position, carried_by)| {
+                    let location = match (position, carried_by) {
+                        (Some(position), _) => Some(UnitLocation::Tile(position.position())),
+                        (None, Some(carried_by)) => {
+                            world.get::<AwbwUnitId>(carried_by.0).map(|transport| {
+                                UnitLocation::Carried {
+                                    transport_id: transport.0,
+                                }
+                            })
+                        }
+                        (None, None) => None,
+                    };
+                    (unit_id.0, location)
+                })
+                .collect()
+        };
+
+        let hp = world
+            .query::<(&AwbwUnitId, &GraphicalHp)>()
+            .iter(world)
+            .map(|(unit_id, hp)| (unit_id.0, hp.value()))
+            .collect();
+
+        let owners = world
+            .get_resource::<GameMap>()
+            .map(|game_map| {
+                game_map
+                    .positions()
+                    .filter_map(|position| match game_map.terrain_at(position)? {
+                        GraphicalTerrain::Property(property) => {
+                            Some((position, property.faction()))
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let funds = world
+            .get_resource::<PlayerFunds>()
+            .map(|funds| funds.0.clone())
+            .unwrap_or_default();
+
+        Self {
+            units,
+            hp,
+            owners,
+            funds,
+        }
+    }
+
+    /// Compare this baseline against the current world.
+    pub fn diff(&self, world: &mut World) -> TurnDiff {
+        let current = TurnBaseline::capture(world);
+        let mut diff = TurnDiff::default();
+
+        for (&unit_id, &location) in &current.units {
+            match self.units.get(&unit_id) {
+                None => diff.spawned.push(UnitSpawned { unit_id, location }),
+                Some(&Some(from)) => {
+                    if let Some(to) = location
+                        && from != to
+                    {
+                        diff.moved.push(UnitRelocated { unit_id, from, to });
+                    }
+                }
+                Some(None) => {}
+            }
+        }
+
+        diff.destroyed = self
+            .units
+            .keys()
+            .filter(|unit_id| !current.units.contains_key(unit_id))
+            .copied()
+            .collect();
+
+        diff.hp_changed = current
+            .hp
+            .iter()
+            .filter_map(|(&unit_id, &to)| {
+                let from = *self.hp.get(&unit_id)?;
+                (from != to).then_some(UnitHpChanged { unit_id, from, to })
+            })
+            .collect();
+
+        diff.captured = current
+            .owners
+            .iter()
+            .filter(|(position, owner)| {
+                self.owners
+                    .get(position)
+                    .is_some_and(|previous| previous != *owner)
+            })
+            .map(|(&position, &owner)| TileCaptured { position, owner })
+            .collect();
+        diff.captured.sort_by_key(|capture| capture.position);
+
+        let mut player_ids: Vec<_> = self.funds.keys().chain(current.funds.keys()).collect();
+        player_ids.sort();
+        player_ids.dedup();
+        diff.funds = player_ids
+            .into_iter()
+            .filter_map(|&player_id| {
+                let before = i64::from(self.funds.get(&player_id).copied().unwrap_or_default());
+                let after = i64::from(current.funds.get(&player_id).copied().unwrap_or_default());
+                (before != after).then_some(FundsDelta {
+                    player_id,
+                    delta: after - before,
+                })
+            })
+            .collect();
+
+        diff
+    }
+}
+
+/// Diff the world against `baseline` and emit [`ReplayTurnApplied`].
+pub(crate) fn emit_turn_diff(baseline: &TurnBaseline, world: &mut World) {
+    let diff = baseline.diff(world);
+    if let Some(sink) = world.get_resource::<EventSink<ReplayTurnApplied>>() {
+        sink.emit(ReplayTurnApplied { diff });
+    }
+}
@@ -1,9 +1,13 @@
 pub mod bootstrap;
 pub mod commands;
 pub(crate) mod controls;
+pub mod diff;
 pub mod fog;
 pub mod navigation;
+pub mod seek;
 pub(crate) mod state;
+#[cfg(feature = "replay-validation")]
+pub mod validation;
 
 use crate::core::{AppState, GameMode};
 use awbrn_game::replay::{
@@ -17,6 +21,7 @@ pub struct ReplayPlugin;
 impl Plugin for ReplayPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<commands::ReplayAdvanceLock>()
+            .add_message::<seek::ReplaySeek>()
             .add_plugins(navigation::NavigationPlugin)
             .add_observer(fog::on_replay_fog_dirty)
             .add_observer(commands::on_carried_by_add)
@@ -24,7 +29,8 @@ impl Plugin for ReplayPlugin {
             .add_observer(commands::on_new_day)
             .add_systems(
                 Update,
-                controls::handle_replay_controls
+                (controls::handle_replay_controls, seek::handle_replay_seek)
+                    .chain()
                     .run_if(in_state(GameMode::Replay).and(in_state(AppState::InGame))),
             )
             .add_systems(
@@ -39,5 +45,14 @@ impl Plugin for ReplayPlugin {
                     .run_if(resource_changed::<CurrentWeather>)
                     .run_if(in_state(GameMode::Replay).and(in_state(AppState::InGame))),
             );
+
+        #[cfg(feature = "replay-validation")]
+        app.add_systems(
+            Update,
+            validation::log_replay_discrepancies
+                .after(seek::handle_replay_seek)
+                .run_if(resource_changed::<awbrn_game::replay::ReplayState>)
+                .run_if(in_state(GameMode::Replay).and(in_state(AppState::InGame))),
+        );
     }
 }
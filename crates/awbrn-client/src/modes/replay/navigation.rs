@@ -32,6 +32,15 @@ pub(crate) const COURSE_ARROW_SPRITE_SIZE: SpriteSize = SpriteSize {
     z_index: RenderLayer::COURSE_ARROW,
 };
 
+pub(crate) const MOVE_TRAIL_LIFETIME_MS: u64 = 1800;
+const MOVE_TRAIL_ALPHA: f32 = 0.6;
+const MOVE_TRAIL_DOT_SIZE: f32 = 4.0;
+const MOVE_TRAIL_SPRITE_SIZE: SpriteSize = SpriteSize {
+    width: 16.0,
+    height: 16.0,
+    z_index: RenderLayer::MOVE_RANGE_OVERLAY,
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReplayPathTile {
     pub position: Position,
@@ -44,6 +53,15 @@ pub struct PendingCourseArrows {
     pub path: Vec<ReplayPathTile>,
 }
 
+/// Breadcrumb left on a tile the last moved unit passed through. Fades out
+/// over `lifetime` and is replaced by the next move's trail.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MoveTrail {
+    pub owner: Entity,
+    pub elapsed: Duration,
+    pub lifetime: Duration,
+}
+
 pub fn scaled_animation_duration(base_ms: u64) -> Duration {
     let speed = REPLAY_PATH_ANIMATION_SPEED_FACTOR.max(f32::EPSILON);
     if (speed - 1.0).abs() < f32::EPSILON {
@@ -370,6 +388,61 @@ pub(crate) fn animate_course_arrows(
     }
 }
 
+pub(crate) fn spawn_move_trail(
+    trigger: On<Insert, PendingCourseArrows>,
+    mut commands: Commands,
+    game_map: Res<GameMap>,
+    query: Query<&PendingCourseArrows>,
+    existing_trails: Query<Entity, With<MoveTrail>>,
+) {
+    let owner = trigger.entity;
+    let Ok(pending) = query.get(owner) else {
+        return;
+    };
+
+    for entity in &existing_trails {
+        commands.entity(entity).despawn();
+    }
+
+    // The destination tile is occupied by the unit itself.
+    let traversed = pending.path.split_last().map_or(&[][..], |(_, rest)| rest);
+    for tile in traversed.iter().filter(|tile| tile.unit_visible) {
+        commands.spawn((
+            Sprite::from_color(
+                Color::srgba(1.0, 1.0, 1.0, MOVE_TRAIL_ALPHA),
+                Vec2::splat(MOVE_TRAIL_DOT_SIZE),
+            ),
+            Transform::from_translation(position_to_world_translation(
+                &MOVE_TRAIL_SPRITE_SIZE,
+                tile.position,
+                game_map.as_ref(),
+            )),
+            MoveTrail {
+                owner,
+                elapsed: Duration::ZERO,
+                lifetime: scaled_animation_duration(MOVE_TRAIL_LIFETIME_MS),
+            },
+        ));
+    }
+}
+
+pub(crate) fn fade_move_trails(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut MoveTrail, &mut Sprite)>,
+) {
+    for (entity, mut trail, mut sprite) in &mut query {
+        trail.elapsed += time.delta();
+        if trail.elapsed >= trail.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let remaining = 1.0 - trail.elapsed.as_secs_f32() / trail.lifetime.as_secs_f32();
+        sprite.color.set_alpha(MOVE_TRAIL_ALPHA * remaining);
+    }
+}
+
 type UnitPathAnimationQuery<'w, 's> = Query<
     'w,
     's,
@@ -545,14 +618,17 @@ pub struct NavigationPlugin;
 
 impl Plugin for NavigationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(spawn_pending_course_arrows).add_systems(
-            Update,
-            (
-                animate_course_arrows,
-                animate_unit_paths.before(crate::render::animation::animate_units),
-            )
-                .run_if(in_state(crate::core::AppState::InGame)),
-        );
+        app.add_observer(spawn_pending_course_arrows)
+            .add_observer(spawn_move_trail)
+            .add_systems(
+                Update,
+                (
+                    animate_course_arrows,
+                    fade_move_trails,
+                    animate_unit_paths.before(crate::render::animation::animate_units),
+                )
+                    .run_if(in_state(crate::core::AppState::InGame)),
+            );
     }
 }
 
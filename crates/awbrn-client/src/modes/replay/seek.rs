@@ -0,0 +1,176 @@
+use crate::loading::LoadedReplay;
+use crate::modes::replay::commands::{ReplayRewindCommand, ReplaySeekCommand};
+use awbrn_game::replay::ReplayState;
+use awbw_replay::turn_models::ActionKind;
+use bevy::prelude::*;
+
+/// Request to jump through the replay to another action of the given kind,
+/// such as the next power activation or capture.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplaySeek {
+    Next(ActionKind),
+    Prev(ActionKind),
+}
+
+/// Plays the replay forward to the sought action, skipping the path
+/// animations along the way. Seeking backwards first rewinds the replay to its
+/// start.
+pub(crate) fn handle_replay_seek(
+    mut commands: Commands,
+    mut seeks: MessageReader<ReplaySeek>,
+    replay_state: Res<ReplayState>,
+    loaded_replay: Res<LoadedReplay>,
+) {
+    let next_index = replay_state.next_action_index as usize;
+    for seek in seeks.read() {
+        match *seek {
+            ReplaySeek::Next(kind) => match loaded_replay.0.next_action_of_kind(kind, next_index) {
+                Some(target_index) => commands.queue(ReplaySeekCommand { target_index }),
+                None => info!("No {kind:?} action left in the replay"),
+            },
+            ReplaySeek::Prev(kind) => {
+                // The most recently applied action is the current one
+                let current = next_index.saturating_sub(1);
+                match loaded_replay.0.prev_action_of_kind(kind, current) {
+                    Some(target_index) => {
+                        commands.queue(ReplayRewindCommand);
+                        commands.queue(ReplaySeekCommand { target_index });
+                    }
+                    None => info!("No earlier {kind:?} action in the replay"),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use awbrn_game::replay::AwbwUnitId;
+    use awbrn_game::world::StrongIdMap;
+    use awbrn_map::AwbrnMap;
+    use awbrn_types::AwbwGamePlayerId;
+    use awbrn_types::GraphicalTerrain;
+    use awbw_replay::AwbwReplay;
+    use awbw_replay::turn_models::{Action, BuildingInfo, CaptureAction, PowerAction};
+
+    #[test]
+    fn seek_next_capture_lands_on_first_capture() {
+        let mut app = seek_test_app(vec![
+            test_power_action(),
+            test_power_action(),
+            test_capture_action(),
+            test_power_action(),
+            test_capture_action(),
+        ]);
+
+        app.world_mut()
+            .write_message(ReplaySeek::Next(ActionKind::Capture));
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<ReplayState>().next_action_index,
+            3,
+            "the first capture at index 2 should be the last applied action"
+        );
+    }
+
+    #[test]
+    fn seek_prev_capture_lands_on_earlier_capture() {
+        let mut app = seek_test_app(vec![
+            test_capture_action(),
+            test_power_action(),
+            test_capture_action(),
+            test_power_action(),
+            test_power_action(),
+        ]);
+        ReplaySeekCommand { target_index: 4 }.apply(app.world_mut());
+        assert_eq!(app.world().resource::<ReplayState>().next_action_index, 5);
+
+        app.world_mut()
+            .write_message(ReplaySeek::Prev(ActionKind::Capture));
+        app.update();
+        assert_eq!(
+            app.world().resource::<ReplayState>().next_action_index,
+            3,
+            "the capture at index 2 should be the last applied action"
+        );
+
+        app.world_mut()
+            .write_message(ReplaySeek::Prev(ActionKind::Capture));
+        app.update();
+        assert_eq!(app.world().resource::<ReplayState>().next_action_index, 1);
+    }
+
+    #[test]
+    fn seek_without_match_stays_put() {
+        let mut app = seek_test_app(vec![test_power_action(), test_power_action()]);
+
+        app.world_mut()
+            .write_message(ReplaySeek::Next(ActionKind::Capture));
+        app.update();
+
+        assert_eq!(app.world().resource::<ReplayState>().next_action_index, 0);
+    }
+
+    fn seek_test_app(actions: Vec<Action>) -> App {
+        let mut app = App::new();
+        app.add_message::<ReplaySeek>();
+        app.add_systems(Update, handle_replay_seek);
+        app.insert_resource(ReplayState::default());
+        app.insert_resource(crate::modes::replay::commands::ReplayAdvanceLock::default());
+        app.insert_resource(StrongIdMap::<AwbwUnitId>::default());
+        app.insert_resource(awbrn_game::world::BoardIndex::new(3, 3));
+        app.init_resource::<awbrn_game::world::GameMap>();
+        app.insert_resource(LoadedReplay(AwbwReplay::new(Vec::new(), actions)));
+        app.init_resource::<crate::features::fog::FogOfWarMap>();
+        app.init_resource::<crate::features::fog::FogActive>();
+        app.init_resource::<crate::features::fog::FriendlyFactions>();
+        app.init_resource::<crate::modes::replay::fog::ReplayTerrainKnowledge>();
+        app.init_resource::<crate::modes::replay::fog::ReplayViewpoint>();
+        app.init_resource::<crate::modes::replay::fog::ReplayPlayerRegistry>();
+        app.init_resource::<awbrn_game::replay::PowerVisionBoosts>();
+        app.add_observer(crate::modes::replay::fog::on_replay_fog_dirty);
+        app.world_mut()
+            .resource_mut::<awbrn_game::world::GameMap>()
+            .set(AwbrnMap::new(3, 3, GraphicalTerrain::Plain));
+        crate::modes::replay::bootstrap::initialize_replay_semantic_world_for_client(
+            app.world_mut(),
+        );
+        app
+    }
+
+    fn test_power_action() -> Action {
+        Action::Power(PowerAction {
+            player_id: AwbwGamePlayerId::new(1),
+            co_name: "Test CO".to_string(),
+            co_power: "N".to_string(),
+            power_name: "Test Power".to_string(),
+            players_cop: 0,
+            global: None,
+            hp_change: None,
+            unit_replace: None,
+            unit_add: None,
+            player_replace: None,
+            missile_coords: None,
+            weather: None,
+        })
+    }
+
+    fn test_capture_action() -> Action {
+        Action::Capt {
+            move_action: None,
+            capture_action: CaptureAction {
+                building_info: BuildingInfo {
+                    buildings_capture: 10,
+                    buildings_id: 99,
+                    buildings_x: 2,
+                    buildings_y: 2,
+                    buildings_team: None,
+                },
+                vision: Default::default(),
+                income: None,
+            },
+        }
+    }
+}
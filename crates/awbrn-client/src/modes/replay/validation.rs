@@ -0,0 +1,181 @@
+//! Debug harness comparing replay playback against AWBW's own game state.
+//!
+//! A replay archive embeds a full game snapshot for the start of every turn.
+//! Once an action that passes the turn has been applied, the reconstructed
+//! units and property owners should match the snapshot for the turn that just
+//! began, so any difference points at an action the engine applied
+//! incorrectly.
+
+use std::collections::BTreeMap;
+
+use awbrn_game::MapPosition;
+use awbrn_game::replay::{AwbwUnitId, ReplayState};
+use awbrn_game::world::{Faction, GameMap, Unit};
+use awbrn_map::Position;
+use awbrn_types::{GraphicalTerrain, PlayerFaction};
+use awbw_replay::game_models::AwbwGame;
+use bevy::prelude::*;
+
+use crate::loading::LoadedReplay;
+use crate::modes::replay::commands::ReplayAdvanceLock;
+
+/// A difference between the reconstructed world and the embedded snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayDiscrepancy {
+    /// The snapshot has a unit the world doesn't.
+    MissingUnit { unit_id: awbrn_types::AwbwUnitId },
+    /// The world has a unit the snapshot doesn't.
+    UnexpectedUnit { unit_id: awbrn_types::AwbwUnitId },
+    /// A unit is on a different tile. `None` means the unit is carried.
+    UnitPosition {
+        unit_id: awbrn_types::AwbwUnitId,
+        expected: Option<Position>,
+        actual: Option<Position>,
+    },
+    UnitType {
+        unit_id: awbrn_types::AwbwUnitId,
+        expected: awbrn_types::Unit,
+        actual: awbrn_types::Unit,
+    },
+    UnitFaction {
+        unit_id: awbrn_types::AwbwUnitId,
+        expected: PlayerFaction,
+        actual: PlayerFaction,
+    },
+    PropertyOwner {
+        position: Position,
+        expected: awbrn_types::Faction,
+        actual: Option<awbrn_types::Faction>,
+    },
+}
+
+struct WorldUnit {
+    position: Option<Position>,
+    unit: awbrn_types::Unit,
+    faction: PlayerFaction,
+}
+
+/// Compare the world's units and property owners against a game snapshot.
+pub fn compare_with_snapshot(world: &mut World, game: &AwbwGame) -> Vec<ReplayDiscrepancy> {
+    let mut world_units: BTreeMap<_, _> = {
+        let mut query = world.query::<(&AwbwUnitId, Option<&MapPosition>, &Unit, &Faction)>();
+        query
+            .iter(world)
+            .map(|(unit_id, position, unit, faction)| {
+                let unit = WorldUnit {
+                    position: position.map(MapPosition::position),
+                    unit: unit.0,
+                    faction: faction.0,
+                };
+                (unit_id.0, unit)
+            })
+            .collect()
+    };
+
+    let mut discrepancies = Vec::new();
+    for expected in &game.units {
+        let unit_id = expected.id;
+        let Some(actual) = world_units.remove(&unit_id) else {
+            discrepancies.push(ReplayDiscrepancy::MissingUnit { unit_id });
+            continue;
+        };
+
+        let expected_position =
+            (!expected.carried).then(|| Position::new(expected.x as usize, expected.y as usize));
+        if expected_position != actual.position {
+            discrepancies.push(ReplayDiscrepancy::UnitPosition {
+                unit_id,
+                expected: expected_position,
+                actual: actual.position,
+            });
+        }
+
+        if expected.name != actual.unit {
+            discrepancies.push(ReplayDiscrepancy::UnitType {
+                unit_id,
+                expected: expected.name,
+                actual: actual.unit,
+            });
+        }
+
+        let expected_faction = game
+            .players
+            .iter()
+            .find(|player| player.id == expected.players_id)
+            .map(|player| player.faction);
+        if let Some(expected_faction) = expected_faction
+            && expected_faction != actual.faction
+        {
+            discrepancies.push(ReplayDiscrepancy::UnitFaction {
+                unit_id,
+                expected: expected_faction,
+                actual: actual.faction,
+            });
+        }
+    }
+
+    discrepancies.extend(
+        world_units
+            .into_keys()
+            .map(|unit_id| ReplayDiscrepancy::UnexpectedUnit { unit_id }),
+    );
+
+    if let Some(game_map) = world.get_resource::<GameMap>() {
+        for building in &game.buildings {
+            let Some(expected) = building.terrain_id.owner() else {
+                continue;
+            };
+
+            let position = Position::new(building.x as usize, building.y as usize);
+            let actual = match game_map.terrain_at(position) {
+                Some(GraphicalTerrain::Property(property)) => Some(property.faction()),
+                _ => None,
+            };
+            if actual != Some(expected) {
+                discrepancies.push(ReplayDiscrepancy::PropertyOwner {
+                    position,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    discrepancies
+}
+
+/// Compare the world against the embedded snapshot when the most recently
+/// applied action passed the turn.
+///
+/// Returns `None` when the last action didn't pass the turn or the replay has
+/// no snapshot for the new turn.
+pub fn validate_replay_turn(world: &mut World) -> Option<Vec<ReplayDiscrepancy>> {
+    let applied = world.resource::<ReplayState>().next_action_index as usize;
+    let replay = &world.get_resource::<LoadedReplay>()?.0;
+    let applied_actions = replay.turns.get(..applied)?;
+    applied_actions.last()?.next_turn_day()?;
+
+    let turn = applied_actions
+        .iter()
+        .filter(|action| action.next_turn_day().is_some())
+        .count();
+    let game = replay.games.get(turn)?.clone();
+    Some(compare_with_snapshot(world, &game))
+}
+
+/// Log any difference between playback and the embedded snapshot after each
+/// turn is passed.
+pub(crate) fn log_replay_discrepancies(world: &mut World) {
+    if world.resource::<ReplayAdvanceLock>().is_active() {
+        return;
+    }
+
+    let Some(discrepancies) = validate_replay_turn(world) else {
+        return;
+    };
+
+    let action_index = world.resource::<ReplayState>().next_action_index;
+    for discrepancy in discrepancies {
+        warn!("Replay state differs from snapshot after action {action_index}: {discrepancy:?}");
+    }
+}
@@ -1,4 +1,5 @@
 use crate::core::INACTIVE_UNIT_COLOR;
+use crate::features::CameraScale;
 use crate::modes::replay::navigation;
 use awbrn_content::get_unit_animation_frames;
 use awbrn_game::world::{Faction, Unit};
@@ -14,6 +15,101 @@ pub(crate) struct Animation {
     pub(crate) frame_timer: Timer,
 }
 
+/// How long a newly built unit takes to pop in to full size.
+pub const SPAWN_ANIMATION_DURATION: Duration = Duration::from_millis(250);
+
+/// The scale a newly built unit starts from before growing to full size.
+const SPAWN_ANIMATION_START_SCALE: f32 = 0.4;
+
+/// Pop-in animation for units built during play. Drives `Transform.scale` from
+/// a reduced size up to full size and removes itself when done. Units deployed
+/// at the start of a replay don't get this.
+#[derive(Component, Debug, Clone)]
+#[component(storage = "SparseSet")]
+pub struct SpawnAnim {
+    pub timer: Timer,
+}
+
+impl Default for SpawnAnim {
+    fn default() -> Self {
+        Self {
+            timer: Timer::new(SPAWN_ANIMATION_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+/// Slows unit animations once the camera is zoomed out far enough that unit
+/// sprites are only a few pixels tall, so large maps don't pay to animate
+/// hundreds of units nobody can make out.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct UnitAnimationLod {
+    /// Camera scale below which unit animations slow down.
+    pub min_camera_scale: f32,
+    /// Fraction of elapsed time unit animations advance by when zoomed out.
+    pub rate: f32,
+    /// How much time accumulates between unit animation ticks when zoomed
+    /// out. Frames in between skip the unit query entirely.
+    pub tick_interval: Duration,
+}
+
+impl UnitAnimationLod {
+    /// Fraction of elapsed time unit animations advance by at `camera_scale`.
+    pub fn rate_at(&self, camera_scale: f32) -> f32 {
+        if self.is_zoomed_out(camera_scale) {
+            self.rate.clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    fn is_zoomed_out(&self, camera_scale: f32) -> bool {
+        camera_scale < self.min_camera_scale
+    }
+}
+
+impl Default for UnitAnimationLod {
+    fn default() -> Self {
+        Self {
+            min_camera_scale: 0.75,
+            rate: 0.25,
+            tick_interval: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Which animation set a unit is playing. Changing it swaps the unit's
+/// [`Animation`] frames to the matching set for its type and faction.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnitAnimState {
+    #[default]
+    Idle,
+    MovingUp,
+    MovingDown,
+    MovingSide,
+}
+
+impl UnitAnimState {
+    pub const fn movement(self) -> GraphicalMovement {
+        match self {
+            UnitAnimState::Idle => GraphicalMovement::Idle,
+            UnitAnimState::MovingUp => GraphicalMovement::Up,
+            UnitAnimState::MovingDown => GraphicalMovement::Down,
+            UnitAnimState::MovingSide => GraphicalMovement::Lateral,
+        }
+    }
+}
+
+impl From<GraphicalMovement> for UnitAnimState {
+    fn from(movement: GraphicalMovement) -> Self {
+        match movement {
+            GraphicalMovement::Idle => UnitAnimState::Idle,
+            GraphicalMovement::Up => UnitAnimState::MovingUp,
+            GraphicalMovement::Down => UnitAnimState::MovingDown,
+            GraphicalMovement::Lateral => UnitAnimState::MovingSide,
+        }
+    }
+}
+
 #[derive(Component)]
 pub(crate) struct TerrainAnimation {
     pub(crate) start_index: u16,
@@ -121,6 +217,9 @@ pub(crate) fn set_unit_animation_state(
     set_unit_pose(sprite, visual_state, movement);
     let (_, new_animation) = unit_animation_for(visual_state.unit, visual_state.faction, movement);
     sprite.color = Color::WHITE;
+    commands
+        .entity(entity)
+        .insert(UnitAnimState::from(movement));
 
     if let Some(mut animation) = animation {
         animation.start_index = new_animation.start_index;
@@ -152,7 +251,10 @@ pub(crate) fn restore_unit_visual_state(
         );
     } else {
         sprite.color = INACTIVE_UNIT_COLOR;
-        commands.entity(entity).remove::<Animation>();
+        commands
+            .entity(entity)
+            .insert(UnitAnimState::Idle)
+            .remove::<Animation>();
     }
 }
 
@@ -160,9 +262,68 @@ pub(crate) fn ease_out_quint(progress: f32) -> f32 {
     1.0 - (1.0 - progress.clamp(0.0, 1.0)).powi(5)
 }
 
-pub(crate) fn animate_units(time: Res<Time>, mut query: Query<(&mut Animation, &mut Sprite)>) {
+type UnitAnimStateItem<'a> = (
+    &'a UnitAnimState,
+    &'a Unit,
+    &'a Faction,
+    &'a mut Sprite,
+    Option<&'a mut Animation>,
+);
+
+/// Swap a unit's frames when its [`UnitAnimState`] changes. Units without an
+/// [`Animation`] (e.g. inactive ones) only have their sprite re-posed.
+pub(crate) fn sync_unit_anim_state(
+    mut query: Query<UnitAnimStateItem<'_>, Changed<UnitAnimState>>,
+) {
+    for (state, unit, faction, mut sprite, animation) in &mut query {
+        let visual_state = UnitVisualState {
+            unit: *unit,
+            faction: *faction,
+            flip_x: sprite.flip_x,
+        };
+        let movement = state.movement();
+        let frames = get_unit_animation_frames(movement, unit.0, faction.0);
+        if animation
+            .as_ref()
+            .is_some_and(|animation| animation.start_index == frames.start_index())
+        {
+            continue;
+        }
+
+        set_unit_pose(&mut sprite, visual_state, movement);
+        let Some(mut animation) = animation else {
+            continue;
+        };
+
+        let (_, new_animation) = unit_animation_for(*unit, *faction, movement);
+        animation.start_index = new_animation.start_index;
+        animation.frame_durations = new_animation.frame_durations;
+        animation.current_frame = 0;
+        animation.frame_timer = new_animation.frame_timer;
+    }
+}
+
+pub(crate) fn animate_units(
+    time: Res<Time>,
+    camera_scale: Option<Res<CameraScale>>,
+    lod: Option<Res<UnitAnimationLod>>,
+    mut pending: Local<Duration>,
+    mut query: Query<(&mut Animation, &mut Sprite)>,
+) {
+    *pending += time.delta();
+    let rate = match (camera_scale, lod) {
+        (Some(camera_scale), Some(lod)) if lod.is_zoomed_out(camera_scale.scale()) => {
+            if *pending < lod.tick_interval {
+                return;
+            }
+            lod.rate_at(camera_scale.scale())
+        }
+        _ => 1.0,
+    };
+    let delta = std::mem::take(&mut *pending).mul_f64(f64::from(rate));
+
     for (mut animation, mut sprite) in query.iter_mut() {
-        animation.frame_timer.tick(time.delta());
+        animation.frame_timer.tick(delta);
 
         if animation.frame_timer.just_finished() {
             let start_frame = animation.current_frame;
@@ -217,13 +378,222 @@ pub(crate) fn animate_terrain(
     }
 }
 
+pub(crate) fn animate_spawns(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut SpawnAnim, &mut Transform)>,
+) {
+    for (entity, mut spawn, mut transform) in query.iter_mut() {
+        spawn.timer.tick(time.delta());
+
+        if spawn.timer.is_finished() {
+            transform.scale = Vec3::ONE;
+            commands.entity(entity).remove::<SpawnAnim>();
+            continue;
+        }
+
+        let progress = ease_out_quint(spawn.timer.fraction());
+        let scale = SPAWN_ANIMATION_START_SCALE + (1.0 - SPAWN_ANIMATION_START_SCALE) * progress;
+        transform.scale = Vec3::new(scale, scale, 1.0);
+    }
+}
+
 pub struct AnimationPlugin;
 
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<UnitAnimationLod>().add_systems(
             Update,
-            (animate_units, animate_terrain).run_if(in_state(crate::core::AppState::InGame)),
+            (
+                sync_unit_anim_state.before(animate_units),
+                animate_units,
+                animate_terrain,
+                animate_spawns,
+            )
+                .run_if(in_state(crate::core::AppState::InGame)),
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::FixedTimestepPlugin;
+    use bevy::time::TimePlugin;
+
+    #[test]
+    fn zoomed_out_camera_slows_unit_animations() {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            FixedTimestepPlugin {
+                step: Duration::from_millis(50),
+            },
+        ))
+        .insert_resource(CameraScale::new(0.5))
+        .insert_resource(UnitAnimationLod {
+            min_camera_scale: 0.75,
+            rate: 0.5,
+            tick_interval: Duration::from_millis(100),
+        })
+        .add_systems(Update, animate_units);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Animation {
+                    start_index: 10,
+                    frame_durations: [100, 100, 100, 100],
+                    current_frame: 0,
+                    frame_timer: Timer::new(Duration::from_millis(100), TimerMode::Once),
+                },
+                Sprite {
+                    texture_atlas: Some(TextureAtlas {
+                        layout: Handle::default(),
+                        index: 10,
+                    }),
+                    ..default()
+                },
+            ))
+            .id();
+
+        // Zoomed out, units only tick once 100ms has accumulated.
+        app.update();
+        app.update();
+        let elapsed = |app: &App| {
+            app.world()
+                .get::<Animation>(entity)
+                .unwrap()
+                .frame_timer
+                .elapsed()
+        };
+        assert_eq!(elapsed(&app), Duration::ZERO);
+        app.update();
+        assert_eq!(elapsed(&app), Duration::from_millis(50));
+
+        for _ in 0..4 {
+            app.update();
+        }
+
+        // 300ms of real time only advances the animation by 150ms.
+        assert_eq!(
+            app.world()
+                .entity(entity)
+                .get::<Animation>()
+                .unwrap()
+                .current_frame,
+            1
+        );
+
+        // Zooming back in restores the full rate.
+        app.insert_resource(CameraScale::new(1.0));
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            app.world()
+                .entity(entity)
+                .get::<Animation>()
+                .unwrap()
+                .current_frame,
+            3
+        );
+    }
+
+    #[test]
+    fn moving_side_state_swaps_unit_animation_frames() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_unit_anim_state);
+
+        let unit = Unit(awbrn_types::Unit::Infantry);
+        let faction = Faction(awbrn_types::PlayerFaction::OrangeStar);
+        let (idle_frames, animation) = unit_animation_for(unit, faction, GraphicalMovement::Idle);
+        let entity = app
+            .world_mut()
+            .spawn((
+                unit,
+                faction,
+                UnitAnimState::Idle,
+                animation,
+                Sprite {
+                    texture_atlas: Some(TextureAtlas {
+                        layout: Handle::default(),
+                        index: idle_frames.start_index() as usize,
+                    }),
+                    ..default()
+                },
+            ))
+            .id();
+        app.update();
+
+        *app.world_mut().get_mut::<UnitAnimState>(entity).unwrap() = UnitAnimState::MovingSide;
+        app.update();
+
+        let lateral_frames =
+            awbrn_content::get_unit_animation_frames(GraphicalMovement::Lateral, unit.0, faction.0);
+        assert_ne!(lateral_frames.start_index(), idle_frames.start_index());
+
+        let animation = app.world().get::<Animation>(entity).unwrap();
+        assert_eq!(animation.start_index, lateral_frames.start_index());
+        assert_eq!(animation.frame_durations, lateral_frames.raw());
+        assert_eq!(animation.current_frame, 0);
+
+        let sprite = app.world().get::<Sprite>(entity).unwrap();
+        assert_eq!(
+            sprite.texture_atlas.as_ref().unwrap().index,
+            lateral_frames.start_index() as usize
+        );
+    }
+
+    #[test]
+    fn unit_animations_follow_encoded_frame_durations() {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            FixedTimestepPlugin {
+                step: Duration::from_millis(30),
+            },
+        ))
+        .add_systems(Update, animate_units);
+
+        let unit = Unit(awbrn_types::Unit::Infantry);
+        let faction = Faction(awbrn_types::PlayerFaction::OrangeStar);
+        let (frames, animation) = unit_animation_for(unit, faction, GraphicalMovement::Idle);
+
+        // Infantry idles with a held third frame rather than a flat cadence
+        assert_eq!(frames.raw(), [160, 160, 320, 160]);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                animation,
+                Sprite {
+                    texture_atlas: Some(TextureAtlas {
+                        layout: Handle::default(),
+                        index: frames.start_index() as usize,
+                    }),
+                    ..default()
+                },
+            ))
+            .id();
+        let current_frame = |app: &App| app.world().get::<Animation>(entity).unwrap().current_frame;
+
+        // The first frame only establishes the time baseline.
+        app.update();
+
+        // Record the tick on which each frame begins. Leftover time is dropped
+        // when a frame ends, so each frame lasts ceil(duration / 30ms) ticks.
+        let mut transitions = Vec::new();
+        let mut previous = current_frame(&app);
+        for tick in 1..=30 {
+            app.update();
+            let frame = current_frame(&app);
+            if frame != previous {
+                transitions.push((tick, frame));
+                previous = frame;
+            }
+        }
+
+        assert_eq!(transitions, [(6, 1), (12, 2), (23, 3), (29, 0)]);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::core::coords::map_position_to_world_translation;
+use crate::core::{RenderLayer, SpriteSize};
+use crate::render::UiAtlasResource;
+use awbrn_game::MapPosition;
+use awbrn_game::world::{GameMap, UnitDestroyed};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// UI atlas sprites whose names start with this prefix are the explosion
+/// frames, played in name order.
+pub const EXPLOSION_FRAME_PREFIX: &str = "Effects/Explosion/";
+
+const EXPLOSION_FRAME_DURATION: Duration = Duration::from_millis(60);
+
+const EXPLOSION_SPRITE_SIZE: SpriteSize = SpriteSize {
+    width: 16.0,
+    height: 16.0,
+    z_index: RenderLayer::COURSE_ARROW,
+};
+
+/// A one-shot explosion played where a unit was destroyed. Despawns itself
+/// after the last frame.
+#[derive(Component, Debug)]
+pub struct Explosion {
+    frames: Vec<usize>,
+    current_frame: usize,
+    frame_timer: Timer,
+}
+
+/// Atlas indices of the explosion frames, in playback order.
+fn explosion_frames(atlas: &crate::UiAtlasAsset) -> Vec<usize> {
+    let mut frames: Vec<_> = atlas
+        .sprites
+        .iter()
+        .enumerate()
+        .filter(|(_, sprite)| sprite.name.starts_with(EXPLOSION_FRAME_PREFIX))
+        .map(|(index, sprite)| (sprite.name.as_str(), index))
+        .collect();
+
+    // Shorter names first so "10.png" sorts after "9.png".
+    frames.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    frames.into_iter().map(|(_, index)| index).collect()
+}
+
+/// Observer: spawn an explosion at a unit's tile when it is destroyed.
+pub(crate) fn spawn_explosion_on_unit_destroyed(
+    trigger: On<UnitDestroyed>,
+    mut commands: Commands,
+    positions: Query<&MapPosition>,
+    game_map: Res<GameMap>,
+    atlas_res: Option<Res<UiAtlasResource>>,
+    atlas_assets: Option<Res<Assets<crate::UiAtlasAsset>>>,
+) {
+    let Ok(map_position) = positions.get(trigger.entity) else {
+        return;
+    };
+
+    let (Some(atlas_res), Some(atlas_assets)) = (atlas_res, atlas_assets) else {
+        return;
+    };
+
+    let Some(atlas) = atlas_assets.get(&atlas_res.handle) else {
+        return;
+    };
+
+    let frames = explosion_frames(atlas);
+    let Some(&first_frame) = frames.first() else {
+        debug!("UI atlas has no explosion frames; skipping explosion");
+        return;
+    };
+
+    let translation =
+        map_position_to_world_translation(&EXPLOSION_SPRITE_SIZE, *map_position, &game_map);
+
+    commands.spawn((
+        Explosion {
+            frames,
+            current_frame: 0,
+            frame_timer: Timer::new(EXPLOSION_FRAME_DURATION, TimerMode::Once),
+        },
+        Sprite::from_atlas_image(
+            atlas_res.texture.clone(),
+            TextureAtlas {
+                layout: atlas_res.layout.clone(),
+                index: first_frame,
+            },
+        ),
+        Transform::from_translation(translation),
+    ));
+}
+
+pub(crate) fn animate_explosions(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Explosion, &mut Sprite)>,
+) {
+    for (entity, mut explosion, mut sprite) in query.iter_mut() {
+        explosion.frame_timer.tick(time.delta());
+
+        if !explosion.frame_timer.just_finished() {
+            continue;
+        }
+
+        explosion.current_frame += 1;
+        let Some(&index) = explosion.frames.get(explosion.current_frame) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        if let Some(atlas) = &mut sprite.texture_atlas {
+            atlas.index = index;
+        }
+        explosion.frame_timer = Timer::new(EXPLOSION_FRAME_DURATION, TimerMode::Once);
+    }
+}
+
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(spawn_explosion_on_unit_destroyed)
+            .add_systems(
+                Update,
+                animate_explosions.run_if(in_state(crate::core::AppState::InGame)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use awbrn_game::world::Unit;
+    use awbrn_map::AwbrnMap;
+    use awbrn_types::GraphicalTerrain;
+
+    fn explosion_test_app() -> App {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default());
+        app.init_resource::<GameMap>();
+        app.world_mut()
+            .resource_mut::<GameMap>()
+            .set(AwbrnMap::new(4, 4, GraphicalTerrain::Plain));
+
+        let sprite = |name: &str, x: u32| crate::UiAtlasSprite {
+            name: name.to_string(),
+            x,
+            y: 0,
+            width: 16,
+            height: 16,
+        };
+        let mut atlas_assets = Assets::<crate::UiAtlasAsset>::default();
+        let handle = atlas_assets.add(crate::UiAtlasAsset {
+            size: crate::UiAtlasSize {
+                width: 48,
+                height: 16,
+            },
+            sprites: vec![
+                sprite("Effects/Select.png", 0),
+                sprite("Effects/Explosion/1.png", 16),
+                sprite("Effects/Explosion/0.png", 32),
+            ],
+        });
+        app.insert_resource(atlas_assets);
+        app.insert_resource(UiAtlasResource {
+            handle,
+            texture: Handle::default(),
+            layout: Handle::default(),
+        });
+
+        app.add_observer(spawn_explosion_on_unit_destroyed);
+        app.add_systems(Update, animate_explosions);
+        app
+    }
+
+    fn explosions(app: &mut App) -> Vec<(Entity, usize)> {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(Entity, &Sprite), With<Explosion>>();
+        query
+            .iter(app.world())
+            .map(|(entity, sprite)| (entity, sprite.texture_atlas.as_ref().unwrap().index))
+            .collect()
+    }
+
+    #[test]
+    fn destroying_a_unit_spawns_an_explosion() {
+        let mut app = explosion_test_app();
+        let unit = app
+            .world_mut()
+            .spawn((MapPosition::new(1, 2), Unit(awbrn_types::Unit::Tank)))
+            .id();
+
+        app.world_mut()
+            .entity_mut(unit)
+            .trigger(|entity| UnitDestroyed { entity });
+        app.world_mut().flush();
+
+        let explosions = explosions(&mut app);
+        assert_eq!(explosions.len(), 1);
+        assert_eq!(explosions[0].1, 2, "first frame should be Explosion/0.png");
+    }
+
+    #[test]
+    fn explosion_despawns_after_last_frame() {
+        let mut app = explosion_test_app();
+        let unit = app
+            .world_mut()
+            .spawn((MapPosition::new(0, 0), Unit(awbrn_types::Unit::Infantry)))
+            .id();
+        app.world_mut()
+            .entity_mut(unit)
+            .trigger(|entity| UnitDestroyed { entity });
+
+        for _ in 0..2 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(EXPLOSION_FRAME_DURATION);
+            app.update();
+        }
+
+        assert!(explosions(&mut app).is_empty());
+    }
+}
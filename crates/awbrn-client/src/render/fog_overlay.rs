@@ -28,29 +28,26 @@ pub fn spawn_fog_overlay_tiles(
         return;
     }
 
-    for y in 0..game_map.height() {
-        for x in 0..game_map.width() {
-            let pos = Position::new(x, y);
-            let world_pos = crate::core::coords::position_to_world_translation(
-                &FOG_OVERLAY_SPRITE_SIZE,
-                pos,
-                &game_map,
-            );
+    for pos in game_map.positions() {
+        let world_pos = crate::core::coords::position_to_world_translation(
+            &FOG_OVERLAY_SPRITE_SIZE,
+            pos,
+            &game_map,
+        );
 
-            commands.spawn((
-                FogOverlayTile,
-                FogTilePosition(pos),
-                Sprite::from_color(
-                    Color::srgba(0.0, 0.0, 0.0, 0.0),
-                    Vec2::new(
-                        FOG_OVERLAY_SPRITE_SIZE.width,
-                        FOG_OVERLAY_SPRITE_SIZE.height,
-                    ),
+        commands.spawn((
+            FogOverlayTile,
+            FogTilePosition(pos),
+            Sprite::from_color(
+                Color::srgba(0.0, 0.0, 0.0, 0.0),
+                Vec2::new(
+                    FOG_OVERLAY_SPRITE_SIZE.width,
+                    FOG_OVERLAY_SPRITE_SIZE.height,
                 ),
-                FOG_OVERLAY_SPRITE_SIZE,
-                Transform::from_translation(world_pos),
-            ));
-        }
+            ),
+            FOG_OVERLAY_SPRITE_SIZE,
+            Transform::from_translation(world_pos),
+        ));
     }
 }
 
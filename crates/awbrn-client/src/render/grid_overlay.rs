@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+
+use crate::core::coords::{TILE_SIZE, map_tile_world_size, map_visual_top_world_y};
+use crate::core::{AppState, RenderLayer};
+use awbrn_game::world::GameMap;
+
+const GRID_LINE_THICKNESS: f32 = 1.0;
+const GRID_LINE_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.35);
+const GRID_LABEL_FONT_SIZE: f32 = 8.0;
+const GRID_LABEL_COLOR: Color = Color::WHITE;
+
+/// Which parts of the tile grid are drawn over the terrain.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GridOverlay {
+    /// Lines along every tile edge.
+    pub lines: bool,
+    /// Column numbers along the top edge and row numbers along the left edge.
+    pub labels: bool,
+}
+
+#[derive(Component)]
+pub struct GridLine;
+
+#[derive(Component)]
+pub struct GridLabel;
+
+fn spawn_grid_lines(commands: &mut Commands, game_map: &GameMap) {
+    let size = map_tile_world_size(game_map);
+    let left = -size.x / 2.0;
+    let top = map_visual_top_world_y(game_map) - TILE_SIZE / 2.0;
+    let z = RenderLayer::GRID_OVERLAY as f32;
+
+    for x in 0..=game_map.width() {
+        let world_x = left + x as f32 * TILE_SIZE;
+        commands.spawn((
+            GridLine,
+            Sprite::from_color(GRID_LINE_COLOR, Vec2::new(GRID_LINE_THICKNESS, size.y)),
+            Transform::from_xyz(world_x, top - size.y / 2.0, z),
+        ));
+    }
+
+    for y in 0..=game_map.height() {
+        let world_y = top - y as f32 * TILE_SIZE;
+        commands.spawn((
+            GridLine,
+            Sprite::from_color(GRID_LINE_COLOR, Vec2::new(size.x, GRID_LINE_THICKNESS)),
+            Transform::from_xyz(0.0, world_y, z),
+        ));
+    }
+}
+
+fn spawn_grid_labels(commands: &mut Commands, game_map: &GameMap) {
+    let size = map_tile_world_size(game_map);
+    let left = -size.x / 2.0;
+    let top = map_visual_top_world_y(game_map) - TILE_SIZE / 2.0;
+    let z = RenderLayer::GRID_OVERLAY as f32;
+    let font = TextFont::from_font_size(GRID_LABEL_FONT_SIZE);
+
+    for x in 0..game_map.width() {
+        let world_x = left + (x as f32 + 0.5) * TILE_SIZE;
+        commands.spawn((
+            GridLabel,
+            Text2d::new(x.to_string()),
+            font.clone(),
+            TextColor(GRID_LABEL_COLOR),
+            Transform::from_xyz(world_x, top + TILE_SIZE / 2.0, z),
+        ));
+    }
+
+    for y in 0..game_map.height() {
+        let world_y = top - (y as f32 + 0.5) * TILE_SIZE;
+        commands.spawn((
+            GridLabel,
+            Text2d::new(y.to_string()),
+            font.clone(),
+            TextColor(GRID_LABEL_COLOR),
+            Transform::from_xyz(left - TILE_SIZE / 2.0, world_y, z),
+        ));
+    }
+}
+
+/// Spawns or despawns the grid lines and labels to match [`GridOverlay`] and
+/// the current map dimensions.
+pub(crate) fn sync_grid_overlay(
+    mut commands: Commands,
+    grid_overlay: Res<GridOverlay>,
+    game_map: Res<GameMap>,
+    lines: Query<Entity, With<GridLine>>,
+    labels: Query<Entity, With<GridLabel>>,
+) {
+    let rebuild = game_map.is_changed() && !game_map.is_added();
+    if !grid_overlay.lines || rebuild {
+        for entity in &lines {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    if grid_overlay.lines && (lines.is_empty() || rebuild) {
+        spawn_grid_lines(&mut commands, &game_map);
+    }
+
+    if !grid_overlay.labels || rebuild {
+        for entity in &labels {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    if grid_overlay.labels && (labels.is_empty() || rebuild) {
+        spawn_grid_labels(&mut commands, &game_map);
+    }
+}
+
+pub(crate) fn handle_grid_overlay_toggle(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut grid_overlay: ResMut<GridOverlay>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyG) {
+        return;
+    }
+
+    if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        grid_overlay.labels = !grid_overlay.labels;
+        info!("Grid labels enabled: {}", grid_overlay.labels);
+    } else {
+        grid_overlay.lines = !grid_overlay.lines;
+        info!("Grid overlay enabled: {}", grid_overlay.lines);
+    }
+}
+
+pub struct GridOverlayPlugin;
+
+impl Plugin for GridOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GridOverlay>().add_systems(
+            Update,
+            (
+                handle_grid_overlay_toggle,
+                sync_grid_overlay
+                    .run_if(resource_changed::<GridOverlay>.or(resource_changed::<GameMap>)),
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use awbrn_types::GraphicalTerrain;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn grid_overlay_test_app(width: usize, height: usize) -> App {
+        let mut app = App::new();
+        app.init_resource::<GameMap>();
+        app.init_resource::<GridOverlay>();
+        app.world_mut()
+            .resource_mut::<GameMap>()
+            .set(awbrn_map::AwbrnMap::new(
+                width,
+                height,
+                GraphicalTerrain::Plain,
+            ));
+        app
+    }
+
+    fn grid_line_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<Entity, With<GridLine>>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn disabled_overlay_spawns_no_lines() {
+        let mut app = grid_overlay_test_app(3, 2);
+
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+
+        assert_eq!(grid_line_count(&mut app), 0);
+    }
+
+    #[test]
+    fn enabling_overlay_spawns_one_line_per_tile_edge() {
+        let mut app = grid_overlay_test_app(3, 2);
+        app.world_mut().resource_mut::<GridOverlay>().lines = true;
+
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+        assert_eq!(grid_line_count(&mut app), 4 + 3);
+
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+        assert_eq!(grid_line_count(&mut app), 4 + 3, "lines are not duplicated");
+
+        app.world_mut().resource_mut::<GridOverlay>().lines = false;
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+        assert_eq!(grid_line_count(&mut app), 0);
+    }
+
+    fn grid_label_texts(app: &mut App) -> Vec<(String, Vec3)> {
+        app.world_mut()
+            .query_filtered::<(&Text2d, &Transform), With<GridLabel>>()
+            .iter(app.world())
+            .map(|(text, transform)| (text.0.clone(), transform.translation))
+            .collect()
+    }
+
+    #[test]
+    fn toggling_labels_spawns_and_despawns_edge_numbers() {
+        let mut app = grid_overlay_test_app(3, 2);
+        app.world_mut().resource_mut::<GridOverlay>().labels = true;
+
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+        let labels = grid_label_texts(&mut app);
+        assert_eq!(labels.len(), 3 + 2);
+        assert_eq!(grid_line_count(&mut app), 0, "labels do not need lines");
+
+        let left = -1.5 * TILE_SIZE;
+        let columns = labels.iter().filter(|(_, at)| at.x > left).count();
+        let rows = labels.iter().filter(|(_, at)| at.x < left).count();
+        assert_eq!((columns, rows), (3, 2));
+        for (_, at) in &labels {
+            assert_eq!(at.z, RenderLayer::GRID_OVERLAY as f32);
+        }
+
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+        assert_eq!(grid_label_texts(&mut app).len(), 3 + 2);
+
+        app.world_mut().resource_mut::<GridOverlay>().labels = false;
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+        assert!(grid_label_texts(&mut app).is_empty());
+    }
+
+    #[test]
+    fn grid_lines_draw_above_fog_and_below_move_ranges() {
+        let mut app = grid_overlay_test_app(1, 1);
+        app.world_mut().resource_mut::<GridOverlay>().lines = true;
+
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+
+        let zs: Vec<f32> = app
+            .world_mut()
+            .query_filtered::<&Transform, With<GridLine>>()
+            .iter(app.world())
+            .map(|transform| transform.translation.z)
+            .collect();
+        assert!(!zs.is_empty());
+        for z in zs {
+            assert!(z > RenderLayer::FOG_OVERLAY as f32);
+            assert!(z < RenderLayer::MOVE_RANGE_OVERLAY as f32);
+        }
+    }
+
+    #[test]
+    fn grid_lines_align_with_tile_edges() {
+        let mut app = grid_overlay_test_app(2, 2);
+        app.world_mut().resource_mut::<GridOverlay>().lines = true;
+
+        app.world_mut().run_system_once(sync_grid_overlay).unwrap();
+
+        let mut xs = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Sprite), With<GridLine>>()
+            .iter(app.world())
+            .filter(|(_, sprite)| sprite.custom_size.unwrap().x == GRID_LINE_THICKNESS)
+            .map(|(transform, _)| transform.translation.x)
+            .collect::<Vec<_>>();
+        xs.sort_by(f32::total_cmp);
+        assert_eq!(xs, vec![-16.0, 0.0, 16.0]);
+    }
+}
@@ -5,7 +5,9 @@ use crate::loading::ClientAssetLoader;
 use crate::projection::{ClientProjectionSet, ProjectedTerrainRenderState};
 use crate::render::TerrainAtlasResource;
 use crate::render::animation::TerrainAnimation;
+use awbrn_game::MapPosition;
 use awbrn_game::world::GameMap;
+use awbrn_map::Position;
 use awbrn_types::GraphicalTerrain;
 use bevy::asset::RenderAssetUsages;
 use bevy::image::{ImageAddressMode, ImageSampler, ImageSamplerDescriptor, TextureFormatPixelInfo};
@@ -14,6 +16,7 @@ use bevy::prelude::*;
 use bevy::render::render_resource::{Extent3d, TextureDimension};
 use bevy::sprite::Anchor;
 use bevy::sprite_render::AlphaMode2d;
+use std::collections::{BTreeSet, HashMap};
 use std::time::Duration;
 
 #[derive(Component)]
@@ -22,6 +25,83 @@ pub struct MapBackdrop;
 #[derive(Component)]
 pub(crate) struct AnimatedTerrain;
 
+/// Tiles whose terrain sprite changed this frame, e.g. from a weather toggle,
+/// a capture, or a map edit, so front ends can redraw only what changed.
+/// Cleared at the start of every frame.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DirtyTiles(BTreeSet<Position>);
+
+impl DirtyTiles {
+    /// Changed positions, sorted by column then row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.0.contains(&position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn mark(&mut self, position: Position) {
+        self.0.insert(position);
+    }
+}
+
+/// Seeds the per-tile phase offset given to animated terrain when it spawns,
+/// so neighbouring water and property tiles don't shimmer in lockstep. Each
+/// tile's offset is derived from its position, so a seed always yields the
+/// same pattern.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerrainAnimationPhase {
+    pub seed: u64,
+}
+
+impl TerrainAnimationPhase {
+    /// Where in its animation cycle the tile at `position` starts: the frame
+    /// it shows first and how far into that frame it is. The offset is drawn
+    /// from the whole cycle, so any frame can come first.
+    pub fn start(&self, position: Position, frame_durations: &[Duration]) -> (u8, Duration) {
+        let cycle: u64 = frame_durations
+            .iter()
+            .map(|duration| duration.as_millis() as u64)
+            .sum();
+        if cycle == 0 {
+            return (0, Duration::ZERO);
+        }
+
+        let key = self.seed ^ ((position.x as u64) << 32 | position.y as u64);
+        let mut offset = splitmix64(key) % cycle;
+        for (frame, duration) in frame_durations.iter().enumerate() {
+            let millis = duration.as_millis() as u64;
+            if offset < millis {
+                return (frame as u8, Duration::from_millis(offset));
+            }
+            offset -= millis;
+        }
+        (0, Duration::ZERO)
+    }
+}
+
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn clear_dirty_tiles(mut dirty_tiles: ResMut<DirtyTiles>) {
+    if !dirty_tiles.is_empty() {
+        dirty_tiles.0.clear();
+    }
+}
+
 #[derive(Resource, Clone)]
 pub(crate) struct BackdropTexturesResource {
     clear: Handle<Image>,
@@ -39,23 +119,61 @@ impl BackdropTexturesResource {
     }
 }
 
-const TERRAIN_TILE_WIDTH: u32 = 16;
-const TERRAIN_TILE_HEIGHT: u32 = 32;
+const TERRAIN_TILE_WIDTH: u32 = awbrn_content::TILESHEET_SPRITE_WIDTH;
+const TERRAIN_TILE_HEIGHT: u32 = awbrn_content::TILESHEET_SPRITE_HEIGHT;
 const BACKDROP_TILE_SIZE: u32 = 16;
 
+/// The terrain atlas grid, using the cell size and padding the asset
+/// generator packed the tilesheet with.
+fn terrain_atlas_layout() -> TextureAtlasLayout {
+    TextureAtlasLayout::from_grid(
+        UVec2::new(TERRAIN_TILE_WIDTH, TERRAIN_TILE_HEIGHT),
+        awbrn_content::TILESHEET_COLUMNS,
+        awbrn_content::TILESHEET_ROWS,
+        Some(UVec2::new(
+            awbrn_content::TILESHEET_PADDING_X,
+            awbrn_content::TILESHEET_PADDING_Y,
+        )),
+        Some(UVec2::new(
+            awbrn_content::TILESHEET_OFFSET_X,
+            awbrn_content::TILESHEET_OFFSET_Y,
+        )),
+    )
+}
+
+/// Pixel size of a tilesheet packed with the generated grid.
+fn expected_terrain_atlas_size() -> UVec2 {
+    UVec2::new(
+        awbrn_content::TILESHEET_COLUMNS
+            * (TERRAIN_TILE_WIDTH + awbrn_content::TILESHEET_PADDING_X),
+        awbrn_content::TILESHEET_ROWS * (TERRAIN_TILE_HEIGHT + awbrn_content::TILESHEET_PADDING_Y),
+    )
+}
+
+/// Logs when the loaded tilesheet doesn't match the generated grid, e.g. a
+/// stale `tiles.png` after new tiles were added. Sprites would otherwise
+/// silently render from the wrong cells. Panics in debug builds.
+fn check_terrain_atlas_size(image_size: UVec2) -> bool {
+    let expected = expected_terrain_atlas_size();
+    if image_size == expected {
+        return true;
+    }
+
+    error!(
+        "Terrain tilesheet is {}x{} but the generated layout expects {}x{}; regenerate the tile assets",
+        image_size.x, image_size.y, expected.x, expected.y
+    );
+    debug_assert_eq!(image_size, expected, "terrain tilesheet layout mismatch");
+    false
+}
+
 pub(crate) fn setup_terrain_atlas(
     mut commands: Commands,
     asset_loader: ClientAssetLoader,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     let texture = asset_loader.load_terrain_texture();
-    let layout = texture_atlas_layouts.add(TextureAtlasLayout::from_grid(
-        UVec2::new(16, 32),
-        awbrn_content::TILESHEET_COLUMNS,
-        awbrn_content::TILESHEET_ROWS,
-        None,
-        None,
-    ));
+    let layout = texture_atlas_layouts.add(terrain_atlas_layout());
 
     commands.insert_resource(TerrainAtlasResource { texture, layout });
 }
@@ -69,6 +187,7 @@ pub(crate) fn initialize_backdrop_textures(
         let Some(atlas_image) = images.get(&terrain_atlas.texture) else {
             return;
         };
+        check_terrain_atlas_size(atlas_image.size());
 
         (
             extract_plain_backdrop_image(atlas_image, awbrn_types::Weather::Clear),
@@ -135,96 +254,185 @@ pub(crate) fn refresh_map_backdrop_on_weather_change(
     material.texture = Some(backdrop_textures.texture_for(current_weather.weather()));
 }
 
-type TerrainVisualItem<'a> = (Entity, &'a ProjectedTerrainRenderState);
+type TerrainVisualItem<'a> = (Entity, &'a ProjectedTerrainRenderState, &'a MapPosition);
 
 pub(crate) fn sync_changed_terrain_visuals(
     mut commands: Commands,
     terrain_tiles: Query<TerrainVisualItem<'_>, Changed<ProjectedTerrainRenderState>>,
     current_weather: Option<Res<CurrentWeather>>,
     terrain_atlas: Res<TerrainAtlasResource>,
+    mut dirty_tiles: Option<ResMut<DirtyTiles>>,
+    phase: Option<Res<TerrainAnimationPhase>>,
 ) {
     let weather = current_weather
         .as_ref()
         .map_or(awbrn_types::Weather::Clear, |weather| weather.weather());
 
-    for (entity, projected_state) in &terrain_tiles {
+    for (entity, projected_state, position) in &terrain_tiles {
+        if let Some(dirty_tiles) = dirty_tiles.as_mut() {
+            dirty_tiles.mark(position.position());
+        }
         insert_terrain_visual(
             commands.entity(entity),
             &terrain_atlas,
-            weather,
+            awbrn_content::spritesheet_index(weather, projected_state.0),
             projected_state.0,
+            position.position(),
+            phase.as_deref(),
         );
     }
 }
 
+/// Atlas index of every terrain seen so far, per weather. A weather toggle
+/// looks each tile up here, so the index is computed once per distinct
+/// terrain rather than once per tile.
+#[derive(Debug, Default)]
+pub(crate) struct TerrainSpriteIndices {
+    clear: HashMap<GraphicalTerrain, awbrn_content::SpritesheetIndex>,
+    snow: HashMap<GraphicalTerrain, awbrn_content::SpritesheetIndex>,
+    rain: HashMap<GraphicalTerrain, awbrn_content::SpritesheetIndex>,
+}
+
+impl TerrainSpriteIndices {
+    fn get(
+        &mut self,
+        weather: awbrn_types::Weather,
+        terrain: GraphicalTerrain,
+    ) -> awbrn_content::SpritesheetIndex {
+        let indices = match weather {
+            awbrn_types::Weather::Clear => &mut self.clear,
+            awbrn_types::Weather::Snow => &mut self.snow,
+            awbrn_types::Weather::Rain => &mut self.rain,
+        };
+        *indices
+            .entry(terrain)
+            .or_insert_with(|| awbrn_content::spritesheet_index(weather, terrain))
+    }
+}
+
+type WeatherSyncItem<'a> = (
+    Entity,
+    &'a ProjectedTerrainRenderState,
+    &'a MapPosition,
+    Option<&'a mut Sprite>,
+    Option<&'a mut TerrainAnimation>,
+);
+
+/// Re-point every terrain sprite at the atlas cell for the new weather.
+///
+/// Tiles whose animation shape is unchanged are updated in place, so a
+/// weather toggle doesn't queue a component insert for every tile on the map.
 pub(crate) fn sync_all_terrain_visuals_on_weather_change(
     mut commands: Commands,
-    terrain_tiles: Query<(Entity, &ProjectedTerrainRenderState)>,
+    mut terrain_tiles: Query<WeatherSyncItem<'_>>,
     current_weather: Res<CurrentWeather>,
     terrain_atlas: Res<TerrainAtlasResource>,
+    mut dirty_tiles: Option<ResMut<DirtyTiles>>,
+    phase: Option<Res<TerrainAnimationPhase>>,
+    mut sprite_indices: Local<TerrainSpriteIndices>,
 ) {
     let weather = current_weather.weather();
 
-    for (entity, projected_state) in &terrain_tiles {
-        insert_terrain_visual(
-            commands.entity(entity),
-            &terrain_atlas,
-            weather,
-            projected_state.0,
-        );
+    for (entity, projected_state, position, sprite, animation) in &mut terrain_tiles {
+        if let Some(dirty_tiles) = dirty_tiles.as_mut() {
+            dirty_tiles.mark(position.position());
+        }
+
+        let sprite_index = sprite_indices.get(weather, projected_state.0);
+        let in_place = match (&sprite, &animation) {
+            (Some(sprite), None) => {
+                sprite_index.animation_frames() <= 1 && sprite.texture_atlas.is_some()
+            }
+            (Some(sprite), Some(animation)) => {
+                animation.frame_count == sprite_index.animation_frames()
+                    && sprite.texture_atlas.is_some()
+            }
+            (None, _) => false,
+        };
+
+        if !in_place {
+            insert_terrain_visual(
+                commands.entity(entity),
+                &terrain_atlas,
+                sprite_index,
+                projected_state.0,
+                position.position(),
+                phase.as_deref(),
+            );
+            continue;
+        }
+
+        let current_frame = animation.map_or(0, |mut animation| {
+            animation.start_index = sprite_index.index();
+            animation.current_frame
+        });
+        if let Some(atlas) = sprite.and_then(|sprite| sprite.into_inner().texture_atlas.as_mut()) {
+            atlas.index = sprite_index.index() as usize + current_frame as usize;
+        }
     }
 }
 
 fn insert_terrain_visual(
     mut entity_commands: EntityCommands,
     terrain_atlas: &TerrainAtlasResource,
-    weather: awbrn_types::Weather,
+    sprite_index: awbrn_content::SpritesheetIndex,
     terrain: GraphicalTerrain,
+    position: Position,
+    phase: Option<&TerrainAnimationPhase>,
 ) {
-    let sprite_index = awbrn_content::spritesheet_index(weather, terrain);
+    let mut animation = None;
+    if sprite_index.animation_frames() > 1 {
+        let frame_durations = awbrn_content::get_terrain_animation_frames(terrain);
+        let durations: Vec<_> = (0..sprite_index.animation_frames())
+            .map(|frame| {
+                let millis = frame_durations
+                    .as_ref()
+                    .map(|f| f.get_duration(frame))
+                    .unwrap_or(300);
+                Duration::from_millis(millis as u64)
+            })
+            .collect();
+        let (current_frame, elapsed) = phase
+            .map(|phase| phase.start(position, &durations))
+            .unwrap_or_default();
+        let mut frame_timer = Timer::new(durations[current_frame as usize], TimerMode::Once);
+        frame_timer.set_elapsed(elapsed);
+        animation = Some(TerrainAnimation {
+            start_index: sprite_index.index(),
+            frame_count: sprite_index.animation_frames(),
+            current_frame,
+            frame_timer,
+            frame_durations,
+        });
+    }
 
+    let current_frame = animation.as_ref().map_or(0, |a| a.current_frame);
     entity_commands.insert((
         Sprite::from_atlas_image(
             terrain_atlas.texture.clone(),
             TextureAtlas {
                 layout: terrain_atlas.layout.clone(),
-                index: sprite_index.index() as usize,
+                index: sprite_index.index() as usize + current_frame as usize,
             },
         ),
         Anchor::default(),
     ));
 
-    if sprite_index.animation_frames() > 1 {
-        let frame_durations = awbrn_content::get_terrain_animation_frames(terrain);
-        let initial_duration = frame_durations
-            .as_ref()
-            .map(|f| f.get_duration(0))
-            .unwrap_or(300);
-        entity_commands.insert((
-            TerrainAnimation {
-                start_index: sprite_index.index(),
-                frame_count: sprite_index.animation_frames(),
-                current_frame: 0,
-                frame_timer: Timer::new(
-                    Duration::from_millis(initial_duration as u64),
-                    TimerMode::Once,
-                ),
-                frame_durations,
-            },
-            AnimatedTerrain,
-        ));
-    } else {
-        entity_commands.remove::<(TerrainAnimation, AnimatedTerrain)>();
+    match animation {
+        Some(animation) => {
+            entity_commands.insert((animation, AnimatedTerrain));
+        }
+        None => {
+            entity_commands.remove::<(TerrainAnimation, AnimatedTerrain)>();
+        }
     }
 }
 
 fn extract_plain_backdrop_image(source: &Image, weather: awbrn_types::Weather) -> Image {
     let sprite_index = awbrn_content::spritesheet_index(weather, GraphicalTerrain::Plain).index();
-    let columns = awbrn_content::TILESHEET_COLUMNS;
-    let col = u32::from(sprite_index) % columns;
-    let row = u32::from(sprite_index) / columns;
-    let base_x = col * TERRAIN_TILE_WIDTH;
-    let base_y = row * TERRAIN_TILE_HEIGHT + (TERRAIN_TILE_HEIGHT - BACKDROP_TILE_SIZE);
+    let cell = terrain_atlas_layout().textures[usize::from(sprite_index)];
+    let base_x = cell.min.x;
+    let base_y = cell.min.y + (TERRAIN_TILE_HEIGHT - BACKDROP_TILE_SIZE);
     let source_width = source.texture_descriptor.size.width as usize;
     let pixel_size = source
         .texture_descriptor
@@ -268,7 +476,10 @@ pub struct MapVisualsPlugin;
 
 impl Plugin for MapVisualsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup_terrain_atlas)
+        app.init_resource::<DirtyTiles>()
+            .init_resource::<TerrainAnimationPhase>()
+            .add_systems(Startup, setup_terrain_atlas)
+            .add_systems(First, clear_dirty_tiles)
             .add_systems(
                 Update,
                 initialize_backdrop_textures
@@ -308,9 +519,9 @@ impl Plugin for MapVisualsPlugin {
 mod tests {
     use super::*;
     use crate::core::coords::TILE_SIZE;
+    use crate::features::weather::{SetWeather, apply_set_weather};
     use crate::features::{FogActive, FogOfWarMap, FriendlyFactions};
     use crate::projection::project_terrain_render_state;
-    use awbrn_game::MapPosition;
     use awbrn_game::world::TerrainTile;
     use awbrn_types::{Faction, PlayerFaction, Property, SeaDirection, Weather};
     use bevy::ecs::system::RunSystemOnce;
@@ -326,6 +537,8 @@ mod tests {
         app.init_resource::<FogOfWarMap>();
         app.init_resource::<FogActive>();
         app.init_resource::<FriendlyFactions>();
+        app.init_resource::<DirtyTiles>();
+        app.add_systems(First, clear_dirty_tiles);
         app.add_systems(
             Update,
             (
@@ -533,6 +746,224 @@ mod tests {
         );
     }
 
+    #[test]
+    fn weather_change_keeps_animated_terrain_frame() {
+        let mut app = terrain_render_test_app();
+        let city =
+            GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::OrangeStar)));
+
+        let entity = app
+            .world_mut()
+            .spawn((MapPosition::new(0, 0), TerrainTile { terrain: city }))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .entity_mut(entity)
+            .get_mut::<TerrainAnimation>()
+            .unwrap()
+            .current_frame = 1;
+        app.world_mut()
+            .resource_mut::<CurrentWeather>()
+            .set(Weather::Snow);
+        app.update();
+
+        let snow_index = awbrn_content::spritesheet_index(Weather::Snow, city);
+        let animation = app
+            .world()
+            .entity(entity)
+            .get::<TerrainAnimation>()
+            .unwrap();
+        assert_eq!(animation.start_index, snow_index.index());
+        assert_eq!(animation.current_frame, 1);
+        let sprite = app.world().entity(entity).get::<Sprite>().unwrap();
+        assert_eq!(
+            sprite.texture_atlas.as_ref().unwrap().index,
+            snow_index.index() as usize + 1
+        );
+    }
+
+    #[test]
+    fn terrain_animation_phase_offsets_identical_tiles() {
+        let city =
+            GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::OrangeStar)));
+        let spawn_timers = |seed: u64| {
+            let mut app = terrain_render_test_app();
+            app.insert_resource(TerrainAnimationPhase { seed });
+            let entities = [MapPosition::new(0, 0), MapPosition::new(1, 0)].map(|position| {
+                app.world_mut()
+                    .spawn((position, TerrainTile { terrain: city }))
+                    .id()
+            });
+            app.update();
+
+            entities.map(|entity| {
+                let animation = app.world().get::<TerrainAnimation>(entity).unwrap();
+                let sprite = app.world().get::<Sprite>(entity).unwrap();
+                assert_eq!(
+                    sprite.texture_atlas.as_ref().unwrap().index,
+                    animation.start_index as usize + animation.current_frame as usize
+                );
+                (animation.current_frame, animation.frame_timer.elapsed())
+            })
+        };
+
+        let [first, second] = spawn_timers(7);
+        assert_ne!(first, second);
+        assert_eq!(spawn_timers(7), [first, second]);
+
+        // Tiles start on any frame of the cycle, not only the first.
+        let starting_frames: BTreeSet<u8> = (0..16)
+            .flat_map(|seed| spawn_timers(seed).map(|(frame, _)| frame))
+            .collect();
+        assert!(starting_frames.len() > 1);
+    }
+
+    #[test]
+    fn dirty_tiles_track_weather_toggles_and_captures() {
+        let mut app = terrain_render_test_app();
+        let city = app
+            .world_mut()
+            .spawn((
+                MapPosition::new(1, 0),
+                TerrainTile {
+                    terrain: GraphicalTerrain::Property(Property::City(Faction::Neutral)),
+                },
+            ))
+            .id();
+        for x in [0, 2] {
+            app.world_mut().spawn((
+                MapPosition::new(x, 0),
+                TerrainTile {
+                    terrain: GraphicalTerrain::Plain,
+                },
+            ));
+        }
+        app.update();
+        assert_eq!(app.world().resource::<DirtyTiles>().len(), 3);
+
+        app.update();
+        assert!(app.world().resource::<DirtyTiles>().is_empty());
+
+        app.world_mut()
+            .resource_mut::<CurrentWeather>()
+            .set(Weather::Snow);
+        app.update();
+        let dirty_tiles = app.world().resource::<DirtyTiles>();
+        assert_eq!(
+            dirty_tiles.positions().collect::<Vec<_>>(),
+            [
+                Position::new(0, 0),
+                Position::new(1, 0),
+                Position::new(2, 0)
+            ]
+        );
+
+        app.world_mut().entity_mut(city).insert(TerrainTile {
+            terrain: GraphicalTerrain::Property(Property::City(Faction::Player(
+                PlayerFaction::OrangeStar,
+            ))),
+        });
+        app.update();
+        let dirty_tiles = app.world().resource::<DirtyTiles>();
+        assert_eq!(dirty_tiles.len(), 1);
+        assert!(dirty_tiles.contains(Position::new(1, 0)));
+    }
+
+    #[test]
+    fn set_weather_message_updates_terrain_sprite_indices() {
+        let mut app = terrain_render_test_app();
+        app.add_message::<SetWeather>().add_systems(
+            Update,
+            apply_set_weather.before(sync_all_terrain_visuals_on_weather_change),
+        );
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                MapPosition::new(0, 0),
+                TerrainTile {
+                    terrain: GraphicalTerrain::Plain,
+                },
+            ))
+            .id();
+        app.update();
+
+        app.world_mut().write_message(SetWeather(Weather::Snow));
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<CurrentWeather>().weather(),
+            Weather::Snow
+        );
+        let snow_index = app
+            .world()
+            .entity(entity)
+            .get::<Sprite>()
+            .and_then(|sprite| sprite.texture_atlas.as_ref())
+            .map(|atlas| atlas.index)
+            .unwrap();
+        assert_eq!(
+            snow_index,
+            awbrn_content::spritesheet_index(Weather::Snow, GraphicalTerrain::Plain).index()
+                as usize
+        );
+    }
+
+    #[test]
+    fn terrain_atlas_layout_matches_generated_packing() {
+        let layout = terrain_atlas_layout();
+        let columns = awbrn_content::TILESHEET_COLUMNS;
+        let stride = UVec2::new(
+            TERRAIN_TILE_WIDTH + awbrn_content::TILESHEET_PADDING_X,
+            TERRAIN_TILE_HEIGHT + awbrn_content::TILESHEET_PADDING_Y,
+        );
+        let offset = UVec2::new(
+            awbrn_content::TILESHEET_OFFSET_X,
+            awbrn_content::TILESHEET_OFFSET_Y,
+        );
+
+        assert_eq!(
+            layout.textures.len(),
+            (columns * awbrn_content::TILESHEET_ROWS) as usize
+        );
+        for weather in [Weather::Clear, Weather::Snow, Weather::Rain] {
+            let index = awbrn_content::spritesheet_index(weather, GraphicalTerrain::Plain).index();
+            let cell = layout.textures[usize::from(index)];
+            let grid = UVec2::new(u32::from(index) % columns, u32::from(index) / columns);
+            assert_eq!(cell.min, offset + grid * stride);
+            assert_eq!(
+                cell.size(),
+                UVec2::new(TERRAIN_TILE_WIDTH, TERRAIN_TILE_HEIGHT)
+            );
+        }
+    }
+
+    #[test]
+    fn terrain_atlas_size_check_uses_generated_grid() {
+        let expected = UVec2::new(
+            awbrn_content::TILESHEET_COLUMNS * awbrn_content::TILESHEET_SPRITE_WIDTH,
+            awbrn_content::TILESHEET_ROWS * awbrn_content::TILESHEET_SPRITE_HEIGHT,
+        );
+        assert_eq!(expected_terrain_atlas_size(), expected);
+        assert!(check_terrain_atlas_size(expected));
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn terrain_atlas_size_check_flags_stale_tilesheet() {
+        let stale = expected_terrain_atlas_size() - UVec2::new(0, TERRAIN_TILE_HEIGHT);
+        assert!(!check_terrain_atlas_size(stale));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "terrain tilesheet layout mismatch")]
+    fn terrain_atlas_size_check_panics_on_stale_tilesheet_in_debug() {
+        let stale = expected_terrain_atlas_size() - UVec2::new(0, TERRAIN_TILE_HEIGHT);
+        check_terrain_atlas_size(stale);
+    }
+
     #[test]
     fn extracts_plain_backdrop_tile_from_bottom_of_atlas_cell() {
         let mut atlas = Image::new(
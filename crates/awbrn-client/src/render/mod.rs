@@ -1,12 +1,16 @@
 pub mod animation;
+pub mod explosion;
 pub mod fog_overlay;
+pub mod grid_overlay;
 pub mod map;
 pub mod units;
 
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
-pub use units::{OverlayBlink, OverlayKind, OverlayVisual, UnitOverlayRegistry};
+pub use units::{
+    DamagedUnitShading, OverlayBlink, OverlayKind, OverlayVisual, UnitOverlayRegistry,
+};
 
 /// Resource to store loaded UI atlas for reuse
 #[derive(Resource)]
@@ -72,7 +76,9 @@ impl Plugin for RenderPlugin {
             units::UnitRenderingPlugin,
             map::MapVisualsPlugin,
             animation::AnimationPlugin,
+            explosion::ExplosionPlugin,
             fog_overlay::FogOverlayPlugin,
+            grid_overlay::GridOverlayPlugin,
         ));
     }
 }
@@ -1,12 +1,12 @@
-use crate::core::INACTIVE_UNIT_COLOR;
+use crate::core::{INACTIVE_UNIT_COLOR, RenderLayer};
 use crate::modes::replay::navigation;
 use crate::projection::{ClientProjectionSet, ProjectedUnitOverlayFlags, ProjectedUnitRenderState};
 use crate::render::animation::{
-    Animation, UnitPathAnimation, UnitVisualState, restore_unit_visual_state,
+    Animation, UnitAnimState, UnitPathAnimation, UnitVisualState, restore_unit_visual_state,
 };
 use crate::render::{UiAtlas, UnitAtlasResource};
 use awbrn_content::get_unit_animation_frames;
-use awbrn_game::world::{Faction, Unit, UnitActive};
+use awbrn_game::world::{Faction, GraphicalHp, Unit, UnitActive};
 use bevy::sprite::Anchor;
 use bevy::{log, prelude::*};
 
@@ -114,19 +114,31 @@ type ProjectedUnitRenderFilter = (
 );
 
 fn health_overlay(value: u8) -> OverlaySpec {
-    OverlaySpec::new(format!("Healthv2/{}.png", value), Vec3::new(7.5, -8.0, 1.0))
+    OverlaySpec::new(
+        format!("Healthv2/{}.png", value),
+        Vec3::new(7.5, -8.0, RenderLayer::UNIT_OVERLAY_OFFSET),
+    )
 }
 
 fn capturing_overlay() -> OverlaySpec {
-    OverlaySpec::new("Capturing.png", Vec3::new(0.0, -8.0, 1.0))
+    OverlaySpec::new(
+        "Capturing.png",
+        Vec3::new(0.0, -8.0, RenderLayer::UNIT_OVERLAY_OFFSET),
+    )
 }
 
 fn cargo_overlay() -> OverlaySpec {
-    OverlaySpec::new("HasCargo.png", Vec3::new(0.0, -8.0, 1.0))
+    OverlaySpec::new(
+        "HasCargo.png",
+        Vec3::new(0.0, -8.0, RenderLayer::UNIT_OVERLAY_OFFSET),
+    )
 }
 
 fn dive_overlay() -> OverlaySpec {
-    OverlaySpec::new("Dive.png", Vec3::new(0.0, -8.0, 1.0))
+    OverlaySpec::new(
+        "Dive.png",
+        Vec3::new(0.0, -8.0, RenderLayer::UNIT_OVERLAY_OFFSET),
+    )
 }
 
 fn spawn_overlay_entity(
@@ -329,6 +341,80 @@ fn animate_blinking_overlays(time: Res<Time>, mut query: Query<(&OverlayBlink, &
     }
 }
 
+/// Fades damaged units so weakened ones stand out without reading the HP
+/// digits. Off by default. Only alpha is touched, so the inactive tint is
+/// kept.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct DamagedUnitShading {
+    pub enabled: bool,
+    /// Alpha of a unit on its last HP; full-health units stay opaque.
+    pub min_alpha: f32,
+}
+
+impl DamagedUnitShading {
+    /// Sprite alpha for a unit at `hp` out of 10.
+    pub fn alpha(&self, hp: u8) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+
+        let min_alpha = self.min_alpha.clamp(0.0, 1.0);
+        let health = f32::from(hp.clamp(1, 10) - 1) / 9.0;
+        min_alpha + (1.0 - min_alpha) * health
+    }
+}
+
+impl Default for DamagedUnitShading {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_alpha: 0.55,
+        }
+    }
+}
+
+fn damaged_unit_shading_enabled(shading: Res<DamagedUnitShading>) -> bool {
+    shading.enabled
+}
+
+fn apply_damage_shading(
+    shading: &DamagedUnitShading,
+    sprite: &mut Mut<Sprite>,
+    hp: Option<&GraphicalHp>,
+) {
+    let alpha = shading.alpha(hp.map_or(10, |hp| hp.0));
+    if sprite.color.alpha() != alpha {
+        sprite.color.set_alpha(alpha);
+    }
+}
+
+/// Re-applies the shading to every unit when the setting itself changes, so
+/// turning it off restores full opacity.
+fn refresh_damaged_unit_shading(
+    shading: Res<DamagedUnitShading>,
+    mut query: Query<(&mut Sprite, Option<&GraphicalHp>), With<Unit>>,
+) {
+    for (mut sprite, hp) in &mut query {
+        apply_damage_shading(&shading, &mut sprite, hp);
+    }
+}
+
+type DamagedUnitShadingFilter = (
+    With<Unit>,
+    Or<(Changed<GraphicalHp>, Changed<UnitAnimState>, Added<Sprite>)>,
+);
+
+/// Shades units whose health changed or whose tint was just reset by a spawn
+/// or animation state change.
+fn shade_damaged_units(
+    shading: Res<DamagedUnitShading>,
+    mut query: Query<(&mut Sprite, Option<&GraphicalHp>), DamagedUnitShadingFilter>,
+) {
+    for (mut sprite, hp) in &mut query {
+        apply_damage_shading(&shading, &mut sprite, hp);
+    }
+}
+
 /// Observer that handles unit spawning - creates the base sprite bundle.
 pub(crate) fn handle_unit_spawn(
     trigger: On<Insert, Unit>,
@@ -377,6 +463,7 @@ pub struct UnitRenderingPlugin;
 impl Plugin for UnitRenderingPlugin {
     fn build(&self, app: &mut App) {
         app.register_required_components::<Unit, UnitOverlayRegistry>()
+            .init_resource::<DamagedUnitShading>()
             .add_observer(handle_unit_spawn)
             .add_systems(
                 Update,
@@ -385,6 +472,13 @@ impl Plugin for UnitRenderingPlugin {
                         .in_set(ClientProjectionSet::SyncRender)
                         .after(navigation::animate_unit_paths)
                         .before(crate::render::animation::animate_units),
+                    (
+                        refresh_damaged_unit_shading.run_if(resource_changed::<DamagedUnitShading>),
+                        shade_damaged_units.run_if(damaged_unit_shading_enabled),
+                    )
+                        .chain()
+                        .after(sync_projected_unit_render_state)
+                        .after(navigation::animate_unit_paths),
                     animate_blinking_overlays,
                 )
                     .run_if(in_state(crate::core::AppState::InGame)),
@@ -479,9 +573,12 @@ mod tests {
                     project_unit_render_state,
                     sync_projected_unit_render_state
                         .before(crate::render::animation::animate_units),
+                    refresh_damaged_unit_shading.run_if(resource_changed::<DamagedUnitShading>),
+                    shade_damaged_units.run_if(damaged_unit_shading_enabled),
                 )
                     .chain(),
             );
+        app.init_resource::<DamagedUnitShading>();
         app
     }
 
@@ -516,6 +613,41 @@ mod tests {
             .index
     }
 
+    #[test]
+    fn damaged_unit_shading_fades_low_hp_units_when_enabled() {
+        let mut app = unit_render_test_app();
+        let healthy = spawn_test_unit(&mut app, PlayerFaction::OrangeStar, true);
+        let damaged = spawn_test_unit(&mut app, PlayerFaction::OrangeStar, false);
+        app.world_mut().entity_mut(damaged).insert(GraphicalHp(1));
+        app.update();
+
+        let color = |app: &App, entity| app.world().get::<Sprite>(entity).unwrap().color;
+
+        // Disabled by default: damage leaves the inactive tint untouched
+        assert_eq!(color(&app, damaged), INACTIVE_UNIT_COLOR);
+
+        app.world_mut().resource_mut::<DamagedUnitShading>().enabled = true;
+        app.update();
+
+        assert_eq!(color(&app, healthy), Color::WHITE);
+        assert_ne!(color(&app, damaged), color(&app, healthy));
+        assert_eq!(
+            color(&app, damaged),
+            INACTIVE_UNIT_COLOR.with_alpha(DamagedUnitShading::default().min_alpha)
+        );
+
+        // Healing is picked up through the health change alone
+        app.world_mut().entity_mut(damaged).insert(GraphicalHp(10));
+        app.update();
+        assert_eq!(color(&app, damaged), INACTIVE_UNIT_COLOR);
+
+        app.world_mut().entity_mut(damaged).insert(GraphicalHp(1));
+        app.update();
+        app.world_mut().resource_mut::<DamagedUnitShading>().enabled = false;
+        app.update();
+        assert_eq!(color(&app, damaged), INACTIVE_UNIT_COLOR);
+    }
+
     #[test]
     fn active_unit_spawn_inserts_idle_animation() {
         let mut app = unit_render_test_app();
@@ -707,6 +839,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn overlay_renders_above_its_unit_and_below_course_arrows() {
+        let mut app = unit_render_test_app();
+        let unit = spawn_test_unit(&mut app, PlayerFaction::GreenEarth, true);
+        app.world_mut().entity_mut(unit).insert(Transform::from_xyz(
+            0.0,
+            0.0,
+            RenderLayer::UNIT as f32,
+        ));
+        app.update();
+
+        app.world_mut().entity_mut(unit).insert(GraphicalHp(5));
+        app.update();
+        let overlay = app
+            .world()
+            .entity(unit)
+            .get::<UnitOverlayRegistry>()
+            .unwrap()
+            .overlay(OverlayKind::Health)
+            .unwrap();
+
+        assert_eq!(
+            app.world()
+                .entity(overlay)
+                .get::<ChildOf>()
+                .unwrap()
+                .parent(),
+            unit
+        );
+        let unit_z = app
+            .world()
+            .entity(unit)
+            .get::<Transform>()
+            .unwrap()
+            .translation
+            .z;
+        let overlay_z = unit_z
+            + app
+                .world()
+                .entity(overlay)
+                .get::<Transform>()
+                .unwrap()
+                .translation
+                .z;
+        assert!(overlay_z > unit_z);
+        assert!(overlay_z < RenderLayer::COURSE_ARROW as f32);
+    }
+
     #[test]
     fn health_and_capturing_overlays_can_coexist() {
         let mut app = unit_render_test_app();
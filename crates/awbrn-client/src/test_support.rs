@@ -0,0 +1,237 @@
+//! Headless harness for exercising the client plugins in tests.
+//!
+//! Builds an [`App`] with the semantic replay plugins on top of
+//! [`MinimalPlugins`], an asset server reading from an empty in-memory source,
+//! and stubs of the atlas resources that rendering systems expect, so tests can
+//! load a map and a replay without a window or any files on disk.
+
+use crate::core::CorePlugin;
+use crate::features::fog::FogPlugin;
+use crate::features::{CurrentWeather, FogActive, FogOfWarMap, FriendlyFactions};
+use crate::loading::LoadedReplay;
+use crate::modes::replay::ReplayPlugin;
+use crate::modes::replay::bootstrap::initialize_replay_semantic_world_for_client;
+use crate::modes::replay::commands::{ReplayAdvanceLock, ReplayFollowupCommand, ReplayTurnCommand};
+use crate::projection::project_terrain_render_state;
+use crate::render::map::{
+    DirtyTiles, sync_all_terrain_visuals_on_weather_change, sync_changed_terrain_visuals,
+};
+use crate::render::{TerrainAtlasResource, UiAtlasResource};
+use crate::{UiAtlasAsset, UiAtlasSize, UiAtlasSprite};
+use awbrn_game::replay::{PlaybackDiagnostics, ReplayState};
+use awbrn_game::world::{GameMap, initialize_terrain_semantic_world};
+use awbrn_map::AwbrnMap;
+use awbrn_types::Weather;
+use awbw_replay::AwbwReplay;
+use awbw_replay::turn_models::Action;
+use bevy::asset::io::memory::{Dir, MemoryAssetReader};
+use bevy::asset::io::{AssetSourceBuilder, AssetSourceId};
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+
+/// Creates an app with the core, replay, and fog plugins, an in-memory asset
+/// server, and a stub UI atlas.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.register_asset_source(
+        AssetSourceId::Default,
+        AssetSourceBuilder::new(|| {
+            Box::new(MemoryAssetReader {
+                root: Dir::default(),
+            })
+        }),
+    );
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        StatesPlugin,
+        CorePlugin,
+        ReplayPlugin,
+        FogPlugin,
+    ));
+    app.insert_resource(CurrentWeather::default());
+    insert_test_ui_atlas(&mut app);
+    app
+}
+
+/// Creates an app that runs only the terrain projection and terrain visual
+/// systems over every tile of `map`, with a stub terrain atlas.
+pub fn terrain_visuals_app(map: AwbrnMap) -> App {
+    let mut app = App::new();
+    app.insert_resource(GameMap::default());
+    app.insert_resource(CurrentWeather::default());
+    app.insert_resource(TerrainAtlasResource {
+        texture: Handle::default(),
+        layout: Handle::default(),
+    });
+    app.init_resource::<FogOfWarMap>();
+    app.init_resource::<FogActive>();
+    app.init_resource::<FriendlyFactions>();
+    app.init_resource::<DirtyTiles>();
+    app.add_systems(
+        Update,
+        (
+            project_terrain_render_state,
+            sync_changed_terrain_visuals,
+            sync_all_terrain_visuals_on_weather_change.run_if(resource_changed::<CurrentWeather>),
+        )
+            .chain(),
+    );
+    insert_map(&mut app, map);
+    initialize_terrain_semantic_world(app.world_mut());
+    app.update();
+    app
+}
+
+/// Changes the weather and runs one update so terrain visuals follow it.
+pub fn set_weather(app: &mut App, weather: Weather) {
+    app.world_mut()
+        .resource_mut::<CurrentWeather>()
+        .set(weather);
+    app.update();
+}
+
+/// Replaces the game map.
+pub fn insert_map(app: &mut App, map: AwbrnMap) {
+    app.world_mut().resource_mut::<GameMap>().set(map);
+}
+
+/// Inserts the replay and spawns its initial units and terrain. The map must
+/// already be inserted.
+pub fn load_replay(app: &mut App, replay: AwbwReplay) {
+    app.insert_resource(LoadedReplay(replay));
+    initialize_replay_semantic_world_for_client(app.world_mut());
+}
+
+/// Applies the next replay action, settling any deferred follow-up actions.
+///
+/// Returns the applied action, or `None` once the replay is exhausted.
+pub fn step_replay(app: &mut App) -> Option<Action> {
+    let world = app.world_mut();
+    let next_action_index = world.resource::<ReplayState>().next_action_index as usize;
+    let action = world
+        .resource::<LoadedReplay>()
+        .0
+        .turns
+        .get(next_action_index)
+        .cloned()?;
+
+    ReplayTurnCommand {
+        action: action.clone(),
+    }
+    .apply(world);
+
+    // The replay controls own cursor advancement in the real app before they
+    // queue the command, so mirror that here.
+    world.resource_mut::<ReplayState>().next_action_index += 1;
+    settle_replay(world);
+    Some(action)
+}
+
+/// Outcome of playing a replay to the end with [`simulate_replay`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplaySimulationReport {
+    pub actions_applied: usize,
+    /// Index and kind of each action that playback couldn't apply.
+    pub unhandled_actions: Vec<(usize, &'static str)>,
+}
+
+/// Plays a replay to completion on the given map without rendering, for
+/// checking that replays play back cleanly in bulk.
+pub fn simulate_replay(map: AwbrnMap, replay: AwbwReplay) -> ReplaySimulationReport {
+    let mut app = headless_app();
+    insert_map(&mut app, map);
+    load_replay(&mut app, replay);
+    app.init_resource::<PlaybackDiagnostics>();
+
+    let mut report = ReplaySimulationReport {
+        actions_applied: 0,
+        unhandled_actions: Vec::new(),
+    };
+    loop {
+        let seen = app.world().resource::<PlaybackDiagnostics>().0.len();
+        if step_replay(&mut app).is_none() {
+            break;
+        }
+
+        let diagnostics = &app.world().resource::<PlaybackDiagnostics>().0;
+        report.unhandled_actions.extend(
+            diagnostics[seen..]
+                .iter()
+                .filter_map(|diagnostic| diagnostic.unhandled_action)
+                .map(|kind| (report.actions_applied, kind)),
+        );
+        report.actions_applied += 1;
+    }
+    report
+}
+
+/// Releases the replay advance lock until no animation is pending, applying
+/// each deferred follow-up as if its animation had finished.
+pub fn settle_replay(world: &mut World) {
+    loop {
+        let active_entity = world.resource::<ReplayAdvanceLock>().active_entity();
+        let Some(active_entity) = active_entity else {
+            break;
+        };
+
+        let deferred_action = {
+            let mut replay_lock = world.resource_mut::<ReplayAdvanceLock>();
+            replay_lock.release_for(active_entity)
+        };
+
+        if let Some(followup) = deferred_action {
+            ReplayFollowupCommand {
+                action: followup.action,
+                recompute_fog: followup.recompute_fog,
+            }
+            .apply(world);
+        }
+    }
+}
+
+/// Inserts a UI atlas containing the course arrow sprites without loading
+/// any textures.
+pub fn insert_test_ui_atlas(app: &mut App) {
+    app.init_resource::<Assets<UiAtlasAsset>>();
+    app.init_resource::<Assets<TextureAtlasLayout>>();
+
+    let sprite = |name: &str, x: u32| UiAtlasSprite {
+        name: name.to_string(),
+        x,
+        y: 0,
+        width: 16,
+        height: 16,
+    };
+
+    let atlas_handle = app
+        .world_mut()
+        .resource_mut::<Assets<UiAtlasAsset>>()
+        .add(UiAtlasAsset {
+            size: UiAtlasSize {
+                width: 48,
+                height: 16,
+            },
+            sprites: vec![
+                sprite("Arrow_Body.png", 0),
+                sprite("Arrow_Curved.png", 16),
+                sprite("Arrow_Tip.png", 32),
+            ],
+        });
+    let layout_handle = app
+        .world_mut()
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .add(TextureAtlasLayout::from_grid(
+            UVec2::new(16, 16),
+            3,
+            1,
+            None,
+            None,
+        ));
+
+    app.world_mut().insert_resource(UiAtlasResource {
+        handle: atlas_handle,
+        texture: Handle::default(),
+        layout: layout_handle,
+    });
+}
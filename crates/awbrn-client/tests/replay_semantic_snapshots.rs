@@ -1,27 +1,26 @@
 use std::io::BufWriter;
+use std::ops::Range;
 use std::path::Path;
 
-use awbrn_client::core::CorePlugin;
-use awbrn_client::features::CurrentWeather;
-use awbrn_client::loading::{LoadedReplay, apply_replay_building_overrides};
-use awbrn_client::modes::replay::ReplayPlugin;
-use awbrn_client::modes::replay::bootstrap::initialize_replay_semantic_world_for_client as initialize_replay_semantic_world;
-use awbrn_client::modes::replay::commands::{
-    ReplayAdvanceLock, ReplayFollowupCommand, ReplayTurnCommand,
+use awbrn_client::features::{EventRecorder, EventSink, NewDay, UnitBuilt};
+use awbrn_client::loading::apply_replay_building_overrides;
+use awbrn_client::modes::replay::commands::ReplayApplyDayCommand;
+use awbrn_client::modes::replay::diff::ReplayTurnApplied;
+use awbrn_client::modes::replay::validation::validate_replay_turn;
+use awbrn_client::test_support::{
+    headless_app, insert_map, load_replay, simulate_replay, step_replay,
 };
-use awbrn_client::render::UiAtlasResource;
-use awbrn_game::replay::ReplayState;
+use awbrn_game::replay::{ReplaySnapshot, ReplayState};
 use awbrn_game::snapshot::{
     CanonicalReplaySnapshot, canonicalize_replay_semantic_snapshot, capture_game_snapshot,
 };
-use awbrn_game::world::GameMap;
 use awbrn_map::{AwbrnMap, AwbwMap, AwbwMapData};
-use awbw_replay::ReplayParser;
+use awbrn_types::AwbwUnitId;
+use awbw_replay::{AwbwReplay, ReplayParser};
 use bevy::ecs::reflect::AppTypeRegistry;
 use bevy::prelude::*;
-use bevy::state::app::StatesPlugin;
 use highway::HighwayHash;
-use insta::assert_json_snapshot;
+use insta::{assert_json_snapshot, assert_snapshot};
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
@@ -34,46 +33,11 @@ struct ReplaySnapshotRow {
 
 #[test]
 fn replay_semantic_snapshots_1362397() {
-    let replay_bytes = std::fs::read(replay_fixture_path("1362397.zip")).unwrap();
-    let replay = ReplayParser::new().parse(&replay_bytes).unwrap();
-
-    let map_path = map_fixture_path("162795.json");
-    let map_data: AwbwMapData = serde_json::from_slice(&std::fs::read(map_path).unwrap()).unwrap();
-    let mut awbw_map = AwbwMap::try_from(&map_data).unwrap();
-    apply_replay_building_overrides(&mut awbw_map, &replay.games.first().unwrap().buildings);
-
-    let mut app = App::new();
-    app.add_plugins((
-        StatesPlugin,
-        CorePlugin,
-        ReplayPlugin,
-        awbrn_client::features::fog::FogPlugin,
-    ));
-    app.insert_resource(CurrentWeather::default());
-    app.insert_resource(LoadedReplay(replay));
-    insert_test_ui_atlas(&mut app);
-    app.world_mut()
-        .resource_mut::<GameMap>()
-        .set(AwbrnMap::from_map(&awbw_map));
-
-    initialize_replay_semantic_world(app.world_mut());
-
-    let actions = app.world().resource::<LoadedReplay>().0.turns.clone();
-    let mut rows = Vec::with_capacity(actions.len());
-    for (action_index, action) in actions.into_iter().enumerate() {
-        ReplayTurnCommand {
-            action: action.clone(),
-        }
-        .apply(app.world_mut());
-        // The replay controls own cursor advancement in the real app before they queue the
-        // command. The command itself only mutates semantic world state, so the headless harness
-        // mirrors the control-layer cursor update here.
-        app.world_mut()
-            .resource_mut::<ReplayState>()
-            .next_action_index += 1;
-
-        settle_replay_semantics(app.world_mut());
+    let mut app = replay_app_1362397();
 
+    let mut rows = Vec::new();
+    while let Some(action) = step_replay(&mut app) {
+        let action_index = app.world().resource::<ReplayState>().next_action_index as usize - 1;
         let snapshot = capture_game_snapshot(app.world_mut()).unwrap();
         let type_registry = app.world().resource::<AppTypeRegistry>().read();
         let canonical = canonicalize_replay_semantic_snapshot(&snapshot, &type_registry).unwrap();
@@ -88,92 +52,144 @@ fn replay_semantic_snapshots_1362397() {
     assert_json_snapshot!(rows);
 }
 
-fn settle_replay_semantics(world: &mut World) {
+/// Replay steps kept in the event log: the opening days' builds, moves, a
+/// load and the first capture, then the first attack and the first kill.
+const EVENT_LOG_STEPS: [Range<usize>; 3] = [0..60, 273..274, 347..348];
+
+#[test]
+fn replay_event_log_1362397() {
+    let mut app = replay_app_1362397();
+    let recorder = EventRecorder::new();
+
     loop {
-        let active_entity = world.resource::<ReplayAdvanceLock>().active_entity();
-        let Some(active_entity) = active_entity else {
+        let next_action_index = app.world().resource::<ReplayState>().next_action_index as usize;
+        if EVENT_LOG_STEPS
+            .iter()
+            .any(|steps| steps.contains(&next_action_index))
+        {
+            app.insert_resource(recorder.sink::<NewDay>("NewDay"));
+            app.insert_resource(recorder.sink::<UnitBuilt>("UnitBuilt"));
+            // Turn diffs cover moves, loads, builds, combat damage and kills,
+            // and captures.
+            app.insert_resource(recorder.sink::<ReplayTurnApplied>("ReplayTurnApplied"));
+        } else {
+            app.world_mut().remove_resource::<EventSink<NewDay>>();
+            app.world_mut().remove_resource::<EventSink<UnitBuilt>>();
+            app.world_mut()
+                .remove_resource::<EventSink<ReplayTurnApplied>>();
+        }
+
+        recorder.set_step(next_action_index);
+        if step_replay(&mut app).is_none() {
             break;
-        };
-
-        let deferred_action = {
-            let mut replay_lock = world.resource_mut::<ReplayAdvanceLock>();
-            replay_lock.release_for(active_entity)
-        };
-
-        if let Some(followup) = deferred_action {
-            ReplayFollowupCommand {
-                action: followup.action,
-                recompute_fog: followup.recompute_fog,
-            }
-            .apply(world);
         }
     }
+
+    assert_snapshot!(recorder.to_json_lines());
 }
 
-fn insert_test_ui_atlas(app: &mut App) {
-    if !app
-        .world()
-        .contains_resource::<Assets<awbrn_client::UiAtlasAsset>>()
-    {
-        app.insert_resource(Assets::<awbrn_client::UiAtlasAsset>::default());
+#[test]
+fn replay_unit_hp_timeline_1362397() {
+    let mut app = replay_app_1362397();
+    let mut snapshot = ReplaySnapshot::new();
+    snapshot.record(app.world_mut());
+    while step_replay(&mut app).is_some() {
+        snapshot.record(app.world_mut());
     }
-    if !app
-        .world()
-        .contains_resource::<Assets<TextureAtlasLayout>>()
-    {
-        app.insert_resource(Assets::<TextureAtlasLayout>::default());
+
+    // The infantry built by the first action is hit twice before its first
+    // repair.
+    let infantry = AwbwUnitId::new(170205832);
+    let hp_at = |turn| snapshot.unit_state(infantry, turn).map(|state| state.hp);
+
+    assert_eq!(hp_at(0), None, "unit has not been built yet");
+    assert_eq!(hp_at(1), Some(10));
+    assert_eq!(snapshot.unit_state(infantry, 1).unwrap().fuel, 99);
+    assert_eq!(hp_at(415), Some(10));
+    assert_eq!(hp_at(416), Some(9));
+    assert_eq!(hp_at(474), Some(9));
+    assert_eq!(hp_at(475), Some(3));
+    assert_eq!(hp_at(736), Some(6));
+}
+
+#[test]
+fn replay_1362397_matches_embedded_turn_snapshots() {
+    let mut app = replay_app_1362397();
+
+    let mut turns_validated = 0;
+    while turns_validated < 4 {
+        let action = step_replay(&mut app).expect("replay should span four turns");
+        let discrepancies = validate_replay_turn(app.world_mut());
+        if action.next_turn_day().is_none() {
+            assert_eq!(discrepancies, None);
+            continue;
+        }
+
+        assert_eq!(discrepancies, Some(Vec::new()), "turn {turns_validated}");
+        turns_validated += 1;
     }
+}
 
-    let atlas_handle = {
-        let mut assets = app
-            .world_mut()
-            .resource_mut::<Assets<awbrn_client::UiAtlasAsset>>();
-        assets.add(awbrn_client::UiAtlasAsset {
-            size: awbrn_client::UiAtlasSize {
-                width: 48,
-                height: 16,
-            },
-            sprites: vec![
-                awbrn_client::UiAtlasSprite {
-                    name: "Arrow_Body.png".to_string(),
-                    x: 0,
-                    y: 0,
-                    width: 16,
-                    height: 16,
-                },
-                awbrn_client::UiAtlasSprite {
-                    name: "Arrow_Curved.png".to_string(),
-                    x: 16,
-                    y: 0,
-                    width: 16,
-                    height: 16,
-                },
-                awbrn_client::UiAtlasSprite {
-                    name: "Arrow_Tip.png".to_string(),
-                    x: 32,
-                    y: 0,
-                    width: 16,
-                    height: 16,
-                },
-            ],
-        })
-    };
-    let layout_handle = {
-        let mut layouts = app.world_mut().resource_mut::<Assets<TextureAtlasLayout>>();
-        layouts.add(TextureAtlasLayout::from_grid(
-            UVec2::new(16, 16),
-            3,
-            1,
-            None,
-            None,
-        ))
+#[test]
+fn replay_apply_day_matches_stepping_each_action() {
+    let mut stepped = replay_app_1362397();
+    while stepped.world().resource::<ReplayState>().day == 1 {
+        step_replay(&mut stepped).expect("replay should outlast day one");
+    }
+
+    let mut batched = replay_app_1362397();
+    let recorder = EventRecorder::new();
+    batched.insert_resource(recorder.sink::<NewDay>("NewDay"));
+    ReplayApplyDayCommand { day: 1 }.apply(batched.world_mut());
+
+    let stepped_state = stepped.world().resource::<ReplayState>();
+    let batched_state = batched.world().resource::<ReplayState>();
+    assert_eq!(batched_state.day, 2);
+    assert_eq!(
+        batched_state.next_action_index,
+        stepped_state.next_action_index
+    );
+    assert_eq!(recorder.to_json_lines().lines().count(), 1);
+
+    let canonical_checksum = |app: &mut App| {
+        let snapshot = capture_game_snapshot(app.world_mut()).unwrap();
+        let type_registry = app.world().resource::<AppTypeRegistry>().read();
+        checksum(&canonicalize_replay_semantic_snapshot(&snapshot, &type_registry).unwrap())
     };
+    assert_eq!(
+        canonical_checksum(&mut batched),
+        canonical_checksum(&mut stepped)
+    );
+}
+
+#[test]
+fn replay_1362397_simulates_without_unhandled_actions() {
+    let (replay, map) = replay_1362397(ReplayParser::new().with_strict(true));
+    let action_count = replay.turns.len();
+
+    let report = simulate_replay(map, replay);
+    assert_eq!(report.actions_applied, action_count);
+    assert_eq!(report.unhandled_actions, Vec::new());
+}
+
+fn replay_app_1362397() -> App {
+    let (replay, map) = replay_1362397(ReplayParser::new());
+    let mut app = headless_app();
+    insert_map(&mut app, map);
+    load_replay(&mut app, replay);
+    app
+}
+
+fn replay_1362397(parser: ReplayParser) -> (AwbwReplay, AwbrnMap) {
+    let replay_bytes = std::fs::read(replay_fixture_path("1362397.zip")).unwrap();
+    let replay = parser.parse(&replay_bytes).unwrap();
+
+    let map_path = map_fixture_path("162795.json");
+    let map_data: AwbwMapData = serde_json::from_slice(&std::fs::read(map_path).unwrap()).unwrap();
+    let mut awbw_map = AwbwMap::try_from(&map_data).unwrap();
+    apply_replay_building_overrides(&mut awbw_map, &replay.games.first().unwrap().buildings);
 
-    app.world_mut().insert_resource(UiAtlasResource {
-        handle: atlas_handle,
-        texture: Handle::default(),
-        layout: layout_handle,
-    });
+    (replay, AwbrnMap::from_map(&awbw_map))
 }
 
 fn checksum(snapshot: &CanonicalReplaySnapshot) -> String {
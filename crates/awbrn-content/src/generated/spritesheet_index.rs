@@ -2,6 +2,339 @@
 
 pub const TILESHEET_COLUMNS: u32 = 64;
 pub const TILESHEET_ROWS: u32 = 32;
+pub const TILESHEET_SPRITE_WIDTH: u32 = 16;
+pub const TILESHEET_SPRITE_HEIGHT: u32 = 32;
+
+pub const TILESHEET_PADDING_X: u32 = 0;
+pub const TILESHEET_PADDING_Y: u32 = 0;
+pub const TILESHEET_OFFSET_X: u32 = 0;
+pub const TILESHEET_OFFSET_Y: u32 = 0;
+
+#[rustfmt::skip]
+pub const SPRITESHEET_TERRAINS: [GraphicalTerrain; 322] = [
+    GraphicalTerrain::StubbyMoutain,
+    GraphicalTerrain::Plain,
+    GraphicalTerrain::Mountain,
+    GraphicalTerrain::Wood,
+    GraphicalTerrain::River(RiverType::Horizontal),
+    GraphicalTerrain::River(RiverType::Vertical),
+    GraphicalTerrain::River(RiverType::Cross),
+    GraphicalTerrain::River(RiverType::ES),
+    GraphicalTerrain::River(RiverType::SW),
+    GraphicalTerrain::River(RiverType::WN),
+    GraphicalTerrain::River(RiverType::NE),
+    GraphicalTerrain::River(RiverType::ESW),
+    GraphicalTerrain::River(RiverType::SWN),
+    GraphicalTerrain::River(RiverType::WNE),
+    GraphicalTerrain::River(RiverType::NES),
+    GraphicalTerrain::Road(RoadType::Horizontal),
+    GraphicalTerrain::Road(RoadType::Vertical),
+    GraphicalTerrain::Road(RoadType::Cross),
+    GraphicalTerrain::Road(RoadType::ES),
+    GraphicalTerrain::Road(RoadType::SW),
+    GraphicalTerrain::Road(RoadType::WN),
+    GraphicalTerrain::Road(RoadType::NE),
+    GraphicalTerrain::Road(RoadType::ESW),
+    GraphicalTerrain::Road(RoadType::SWN),
+    GraphicalTerrain::Road(RoadType::WNE),
+    GraphicalTerrain::Road(RoadType::NES),
+    GraphicalTerrain::Bridge(BridgeType::Horizontal),
+    GraphicalTerrain::Bridge(BridgeType::Vertical),
+    GraphicalTerrain::Sea(SeaDirection::Sea),
+    GraphicalTerrain::Shoal(ShoalDirection::N),
+    GraphicalTerrain::Shoal(ShoalDirection::S),
+    GraphicalTerrain::Shoal(ShoalDirection::E),
+    GraphicalTerrain::Shoal(ShoalDirection::W),
+    GraphicalTerrain::Reef,
+    GraphicalTerrain::Property(Property::City(Faction::Neutral)),
+    GraphicalTerrain::Property(Property::Base(Faction::Neutral)),
+    GraphicalTerrain::Property(Property::Airport(Faction::Neutral)),
+    GraphicalTerrain::Property(Property::Port(Faction::Neutral)),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::OrangeStar))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::OrangeStar))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::OrangeStar))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::OrangeStar))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::OrangeStar)),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::BlueMoon))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::BlueMoon))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::BlueMoon))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::BlueMoon))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::BlueMoon)),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::GreenEarth))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::GreenEarth))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::GreenEarth))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::GreenEarth))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::GreenEarth)),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::YellowComet))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::YellowComet))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::YellowComet))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::YellowComet))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::YellowComet)),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::RedFire))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::RedFire))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::RedFire))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::RedFire))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::RedFire)),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::GreySky))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::GreySky))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::GreySky))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::GreySky))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::GreySky)),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::BlackHole))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::BlackHole))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::BlackHole))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::BlackHole))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::BlackHole)),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::BrownDesert))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::BrownDesert))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::BrownDesert))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::BrownDesert))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::BrownDesert)),
+    GraphicalTerrain::Pipe(PipeType::Vertical),
+    GraphicalTerrain::Pipe(PipeType::Horizontal),
+    GraphicalTerrain::Pipe(PipeType::NE),
+    GraphicalTerrain::Pipe(PipeType::ES),
+    GraphicalTerrain::Pipe(PipeType::SW),
+    GraphicalTerrain::Pipe(PipeType::WN),
+    GraphicalTerrain::Pipe(PipeType::NorthEnd),
+    GraphicalTerrain::Pipe(PipeType::EastEnd),
+    GraphicalTerrain::Pipe(PipeType::SouthEnd),
+    GraphicalTerrain::Pipe(PipeType::WestEnd),
+    GraphicalTerrain::MissileSilo(MissileSiloStatus::Loaded),
+    GraphicalTerrain::MissileSilo(MissileSiloStatus::Unloaded),
+    GraphicalTerrain::PipeSeam(PipeSeamType::Horizontal),
+    GraphicalTerrain::PipeSeam(PipeSeamType::Vertical),
+    GraphicalTerrain::PipeRubble(PipeRubbleType::Horizontal),
+    GraphicalTerrain::PipeRubble(PipeRubbleType::Vertical),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::AmberBlossom))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::AmberBlossom))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::AmberBlossom))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::AmberBlossom)),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::AmberBlossom))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::JadeSun))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::JadeSun))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::JadeSun))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::JadeSun)),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::JadeSun))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::AmberBlossom))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::BlackHole))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::BlueMoon))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::BrownDesert))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::GreenEarth))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::JadeSun))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Neutral)),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::OrangeStar))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::RedFire))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::YellowComet))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::GreySky))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::AmberBlossom))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::BlackHole))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::BlueMoon))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::BrownDesert))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::GreenEarth))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::GreySky))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::JadeSun))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Neutral)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::OrangeStar))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::RedFire))),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::YellowComet))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::CobaltIce))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::CobaltIce))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::CobaltIce))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::CobaltIce))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::CobaltIce)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::CobaltIce))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::CobaltIce))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::PinkCosmos))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::PinkCosmos))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::PinkCosmos))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::PinkCosmos))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::PinkCosmos)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::PinkCosmos))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::PinkCosmos))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::TealGalaxy))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::TealGalaxy))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::TealGalaxy))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::TealGalaxy))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::TealGalaxy)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::TealGalaxy))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::TealGalaxy))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::PurpleLightning))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::PurpleLightning))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::PurpleLightning))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::PurpleLightning))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::PurpleLightning)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::PurpleLightning))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::PurpleLightning))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::AcidRain))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::AcidRain))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::AcidRain))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::AcidRain))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::AcidRain)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::AcidRain))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::AcidRain))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::WhiteNova))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::WhiteNova))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::WhiteNova))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::WhiteNova))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::WhiteNova)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::WhiteNova))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::WhiteNova))),
+    GraphicalTerrain::Teleporter,
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::AzureAsteroid))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::AzureAsteroid))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::AzureAsteroid))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::AzureAsteroid))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::AzureAsteroid)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::AzureAsteroid))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::AzureAsteroid))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::NoirEclipse))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::NoirEclipse))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::NoirEclipse))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::NoirEclipse))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::NoirEclipse)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::NoirEclipse))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::NoirEclipse))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::SilverClaw))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::SilverClaw))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::SilverClaw))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::SilverClaw))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::SilverClaw)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::SilverClaw))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::SilverClaw))),
+    GraphicalTerrain::Property(Property::Airport(Faction::Player(PlayerFaction::UmberWilds))),
+    GraphicalTerrain::Property(Property::Base(Faction::Player(PlayerFaction::UmberWilds))),
+    GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::UmberWilds))),
+    GraphicalTerrain::Property(Property::ComTower(Faction::Player(PlayerFaction::UmberWilds))),
+    GraphicalTerrain::Property(Property::HQ(PlayerFaction::UmberWilds)),
+    GraphicalTerrain::Property(Property::Lab(Faction::Player(PlayerFaction::UmberWilds))),
+    GraphicalTerrain::Property(Property::Port(Faction::Player(PlayerFaction::UmberWilds))),
+    GraphicalTerrain::Sea(SeaDirection::N_E_S_W),
+    GraphicalTerrain::Sea(SeaDirection::E_S_W),
+    GraphicalTerrain::Sea(SeaDirection::N_S_W),
+    GraphicalTerrain::Sea(SeaDirection::N_E_W),
+    GraphicalTerrain::Sea(SeaDirection::N_E_S),
+    GraphicalTerrain::Sea(SeaDirection::N_S),
+    GraphicalTerrain::Sea(SeaDirection::E_W),
+    GraphicalTerrain::Sea(SeaDirection::W_E),
+    GraphicalTerrain::Sea(SeaDirection::N_E_SW),
+    GraphicalTerrain::Sea(SeaDirection::N_E),
+    GraphicalTerrain::Sea(SeaDirection::E_S_NW),
+    GraphicalTerrain::Sea(SeaDirection::E_S),
+    GraphicalTerrain::Sea(SeaDirection::S_E),
+    GraphicalTerrain::Sea(SeaDirection::S_W_NE),
+    GraphicalTerrain::Sea(SeaDirection::S_W),
+    GraphicalTerrain::Sea(SeaDirection::N_W_SE),
+    GraphicalTerrain::Sea(SeaDirection::N_W),
+    GraphicalTerrain::Sea(SeaDirection::N_SE_SW),
+    GraphicalTerrain::Sea(SeaDirection::N_SE),
+    GraphicalTerrain::Sea(SeaDirection::N_SW),
+    GraphicalTerrain::Sea(SeaDirection::N),
+    GraphicalTerrain::Sea(SeaDirection::E_NW_SW),
+    GraphicalTerrain::Sea(SeaDirection::E_NW),
+    GraphicalTerrain::Sea(SeaDirection::E_SW),
+    GraphicalTerrain::Sea(SeaDirection::E),
+    GraphicalTerrain::Sea(SeaDirection::S_NW_NE),
+    GraphicalTerrain::Sea(SeaDirection::S_NW),
+    GraphicalTerrain::Sea(SeaDirection::S_NE),
+    GraphicalTerrain::Sea(SeaDirection::S),
+    GraphicalTerrain::Sea(SeaDirection::W_NE_SE),
+    GraphicalTerrain::Sea(SeaDirection::W_NE),
+    GraphicalTerrain::Sea(SeaDirection::W_SE),
+    GraphicalTerrain::Sea(SeaDirection::W),
+    GraphicalTerrain::Sea(SeaDirection::NW_NE_SE_SW),
+    GraphicalTerrain::Sea(SeaDirection::NE_SE_SW),
+    GraphicalTerrain::Sea(SeaDirection::NW_SE_SW),
+    GraphicalTerrain::Sea(SeaDirection::NW_NE_SW),
+    GraphicalTerrain::Sea(SeaDirection::NW_NE_SE),
+    GraphicalTerrain::Sea(SeaDirection::SE_SW),
+    GraphicalTerrain::Sea(SeaDirection::NW_SW),
+    GraphicalTerrain::Sea(SeaDirection::NW_NE),
+    GraphicalTerrain::Sea(SeaDirection::NE_SE),
+    GraphicalTerrain::Sea(SeaDirection::NW_SE),
+    GraphicalTerrain::Sea(SeaDirection::NE_SW),
+    GraphicalTerrain::Sea(SeaDirection::NW),
+    GraphicalTerrain::Sea(SeaDirection::NE),
+    GraphicalTerrain::Sea(SeaDirection::SE),
+    GraphicalTerrain::Sea(SeaDirection::SW),
+    GraphicalTerrain::Shoal(ShoalDirection::C),
+    GraphicalTerrain::Shoal(ShoalDirection::AN),
+    GraphicalTerrain::Shoal(ShoalDirection::AE),
+    GraphicalTerrain::Shoal(ShoalDirection::AS),
+    GraphicalTerrain::Shoal(ShoalDirection::AW),
+    GraphicalTerrain::Shoal(ShoalDirection::NE),
+    GraphicalTerrain::Shoal(ShoalDirection::NAE),
+    GraphicalTerrain::Shoal(ShoalDirection::ANE),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAE),
+    GraphicalTerrain::Shoal(ShoalDirection::NS),
+    GraphicalTerrain::Shoal(ShoalDirection::NAS),
+    GraphicalTerrain::Shoal(ShoalDirection::ANS),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAS),
+    GraphicalTerrain::Shoal(ShoalDirection::NW),
+    GraphicalTerrain::Shoal(ShoalDirection::NAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ES),
+    GraphicalTerrain::Shoal(ShoalDirection::EAS),
+    GraphicalTerrain::Shoal(ShoalDirection::AES),
+    GraphicalTerrain::Shoal(ShoalDirection::AEAS),
+    GraphicalTerrain::Shoal(ShoalDirection::EW),
+    GraphicalTerrain::Shoal(ShoalDirection::EAW),
+    GraphicalTerrain::Shoal(ShoalDirection::AEW),
+    GraphicalTerrain::Shoal(ShoalDirection::AEAW),
+    GraphicalTerrain::Shoal(ShoalDirection::SW),
+    GraphicalTerrain::Shoal(ShoalDirection::SAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ASW),
+    GraphicalTerrain::Shoal(ShoalDirection::ASAW),
+    GraphicalTerrain::Shoal(ShoalDirection::NES),
+    GraphicalTerrain::Shoal(ShoalDirection::NEAS),
+    GraphicalTerrain::Shoal(ShoalDirection::NAES),
+    GraphicalTerrain::Shoal(ShoalDirection::NAEAS),
+    GraphicalTerrain::Shoal(ShoalDirection::ANES),
+    GraphicalTerrain::Shoal(ShoalDirection::ANEAS),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAES),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAEAS),
+    GraphicalTerrain::Shoal(ShoalDirection::NSW),
+    GraphicalTerrain::Shoal(ShoalDirection::NSAW),
+    GraphicalTerrain::Shoal(ShoalDirection::NASW),
+    GraphicalTerrain::Shoal(ShoalDirection::NASAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANSW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANSAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANASW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANASAW),
+    GraphicalTerrain::Shoal(ShoalDirection::NEW),
+    GraphicalTerrain::Shoal(ShoalDirection::NEAW),
+    GraphicalTerrain::Shoal(ShoalDirection::NAEW),
+    GraphicalTerrain::Shoal(ShoalDirection::NAEAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANEW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANEAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAEW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAEAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ESW),
+    GraphicalTerrain::Shoal(ShoalDirection::ESAW),
+    GraphicalTerrain::Shoal(ShoalDirection::EASW),
+    GraphicalTerrain::Shoal(ShoalDirection::EASAW),
+    GraphicalTerrain::Shoal(ShoalDirection::AESW),
+    GraphicalTerrain::Shoal(ShoalDirection::AESAW),
+    GraphicalTerrain::Shoal(ShoalDirection::AEASW),
+    GraphicalTerrain::Shoal(ShoalDirection::AEASAW),
+    GraphicalTerrain::Shoal(ShoalDirection::NESW),
+    GraphicalTerrain::Shoal(ShoalDirection::NESAW),
+    GraphicalTerrain::Shoal(ShoalDirection::NEASW),
+    GraphicalTerrain::Shoal(ShoalDirection::NEASAW),
+    GraphicalTerrain::Shoal(ShoalDirection::NAESW),
+    GraphicalTerrain::Shoal(ShoalDirection::NAESAW),
+    GraphicalTerrain::Shoal(ShoalDirection::NAEASW),
+    GraphicalTerrain::Shoal(ShoalDirection::NAEASAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANESW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANESAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANEASW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANEASAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAESW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAESAW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAEASW),
+    GraphicalTerrain::Shoal(ShoalDirection::ANAEASAW),
+];
 
 #[rustfmt::skip]
 pub const fn spritesheet_index(weather: Weather, terrain: GraphicalTerrain) -> SpritesheetIndex {
@@ -29,4 +29,93 @@ impl SpritesheetIndex {
     }
 }
 
+/// A problem with how terrain frames are packed into the tilesheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutError {
+    /// Two different frame ranges share some, but not all, of their frames.
+    Overlap {
+        first: (GraphicalTerrain, Weather),
+        second: (GraphicalTerrain, Weather),
+    },
+    /// No terrain uses the frames in `start..end`.
+    Gap { start: u16, end: u16 },
+    /// A frame range runs past the last cell of the tilesheet.
+    OutOfBounds {
+        terrain: GraphicalTerrain,
+        weather: Weather,
+    },
+}
+
+impl std::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayoutError::Overlap { first, second } => write!(
+                f,
+                "Frames for {:?} in {:?} overlap frames for {:?} in {:?}",
+                first.0, first.1, second.0, second.1
+            ),
+            LayoutError::Gap { start, end } => {
+                write!(f, "Tilesheet frames {}..{} are unused", start, end)
+            }
+            LayoutError::OutOfBounds { terrain, weather } => write!(
+                f,
+                "Frames for {:?} in {:?} run past the end of the tilesheet",
+                terrain, weather
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+/// Check that every terrain's `[index, index + frames)` range, across all
+/// weathers, packs into the tilesheet without partial overlaps or gaps.
+///
+/// Weathers without dedicated art reuse another weather's range, so
+/// identical ranges are allowed.
+pub fn validate_spritesheet_layout() -> Result<(), LayoutError> {
+    let mut ranges = SPRITESHEET_TERRAINS
+        .iter()
+        .flat_map(|&terrain| {
+            [Weather::Clear, Weather::Snow, Weather::Rain]
+                .map(|weather| (spritesheet_index(weather, terrain), terrain, weather))
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_by_key(|(sprite, _, _)| *sprite);
+    ranges.dedup_by_key(|(sprite, _, _)| *sprite);
+
+    let capacity = TILESHEET_COLUMNS * TILESHEET_ROWS;
+    let mut next = 0u16;
+    let mut previous = None;
+    for (sprite, terrain, weather) in ranges {
+        let start = sprite.index();
+        let end = start + u16::from(sprite.animation_frames());
+
+        match (start.cmp(&next), previous) {
+            (std::cmp::Ordering::Less, Some(first)) => {
+                return Err(LayoutError::Overlap {
+                    first,
+                    second: (terrain, weather),
+                });
+            }
+            (std::cmp::Ordering::Greater, _) => {
+                return Err(LayoutError::Gap {
+                    start: next,
+                    end: start,
+                });
+            }
+            _ => {}
+        }
+
+        if u32::from(end) > capacity {
+            return Err(LayoutError::OutOfBounds { terrain, weather });
+        }
+
+        next = end;
+        previous = Some((terrain, weather));
+    }
+
+    Ok(())
+}
+
 include!("generated/spritesheet_index.rs");
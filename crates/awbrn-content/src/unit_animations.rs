@@ -1,5 +1,6 @@
 use crate::SpritesheetIndex;
 use awbrn_types::{GraphicalMovement, PlayerFaction, Unit};
+use strum::VariantArray;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UnitAnimationData {
@@ -141,6 +142,27 @@ pub fn unit_spritesheet_index(
     SpritesheetIndex::new(frames.start_index(), frames.frame_count() as u8)
 }
 
+/// The highest sprite index any faction's unit animation frame reaches:
+/// `faction_index * TOTAL_FRAMES` plus the furthest unit frame offset.
+pub fn max_unit_sprite_index() -> u32 {
+    let max_faction_index = PlayerFaction::VARIANTS
+        .iter()
+        .map(|&faction| u32::from(faction_index(faction)))
+        .max()
+        .unwrap_or_default();
+
+    let max_offset = Unit::VARIANTS
+        .iter()
+        .map(|&unit| {
+            let (unit_offset, unit_data) = get_animation_data(unit);
+            u32::from(unit_offset) + u32::from(unit_data.total_frames()) - 1
+        })
+        .max()
+        .unwrap_or_default();
+
+    max_faction_index * UnitAnimationData::TOTAL_FRAMES as u32 + max_offset
+}
+
 fn calculate_movement_offset(movement: GraphicalMovement, unit: Unit) -> u16 {
     let (_, unit_data) = get_animation_data(unit);
 
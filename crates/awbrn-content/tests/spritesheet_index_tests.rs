@@ -1,11 +1,14 @@
-use awbrn_content::{get_unit_animation_frames, spritesheet_index, unit_spritesheet_index};
+use awbrn_content::{
+    SPRITESHEET_TERRAINS, get_terrain_animation_frames, get_unit_animation_frames,
+    spritesheet_index, unit_spritesheet_index, validate_spritesheet_layout,
+};
 use awbrn_types::{
     BridgeType, Faction, GraphicalMovement, GraphicalTerrain, MissileSiloStatus, PipeRubbleType,
     PipeSeamType, PipeType, PlayerFaction, Property, PropertyKind, RiverType, RoadType, Unit,
     Weather,
 };
 use insta::assert_json_snapshot;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use strum::VariantArray;
 
 // Helper function to generate all PlayerFaction variants
@@ -269,6 +272,34 @@ fn no_overlapping_indices_clear_weather() {
     }
 }
 
+#[test]
+fn spritesheet_layout_is_contiguous_across_weathers() {
+    let listed: HashSet<_> = SPRITESHEET_TERRAINS.iter().copied().collect();
+    let all: HashSet<_> = get_all_graphical_terrains().into_iter().collect();
+    assert!(all.is_subset(&listed));
+
+    assert_eq!(validate_spritesheet_layout(), Ok(()));
+}
+
+#[test]
+fn only_multi_frame_terrains_have_animation_timing() {
+    for terrain in get_all_graphical_terrains() {
+        let sprite = spritesheet_index(Weather::Clear, terrain);
+        let timing = get_terrain_animation_frames(terrain);
+
+        if sprite.animation_frames() <= 1 {
+            assert!(
+                timing.is_none(),
+                "static terrain {terrain:?} has animation timing"
+            );
+        } else {
+            let timing = timing
+                .unwrap_or_else(|| panic!("animated terrain {terrain:?} has no animation timing"));
+            assert_eq!(timing.frame_count(), sprite.animation_frames());
+        }
+    }
+}
+
 #[test]
 fn snapshot_all_unit_sprite_indices() {
     // Get all possible combinations of parameters
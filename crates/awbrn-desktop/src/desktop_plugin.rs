@@ -1,7 +1,7 @@
-use crate::web_asset_plugin::WebMapAssetPathResolver;
+use crate::settings::{DesktopSettings, save_desktop_settings, track_desktop_settings};
 use awbrn_client::{
-    AwbrnPlugin, EventSink, MapDimensions, NewDay, PlayerRosterSnapshot, ReplayLoaded,
-    TileSelected, UnitBuilt, UnitMoved,
+    AwbrnPlugin, EventSink, MapDimensions, NewDay, PlayerRosterSnapshot,
+    PrefixMapAssetPathResolver, ReplayLoaded, TileSelected, UnitBuilt, UnitMoved,
 };
 use bevy::{asset::AssetMetaCheck, prelude::*};
 use std::{fs, sync::Arc};
@@ -10,16 +10,30 @@ pub struct AwbrnDesktopPlugin;
 
 impl Plugin for AwbrnDesktopPlugin {
     fn build(&self, app: &mut App) {
+        let settings = DesktopSettings::load();
+
         app.add_plugins(
             DefaultPlugins
                 .set(ImagePlugin::default_nearest())
+                .set(WindowPlugin {
+                    primary_window: Some(settings.window()),
+                    ..WindowPlugin::default()
+                })
                 .set(AssetPlugin {
                     file_path: String::from("../../assets"),
                     meta_check: AssetMetaCheck::Never,
                     ..AssetPlugin::default()
                 }),
         )
-        .add_plugins(AwbrnPlugin::new(Arc::new(WebMapAssetPathResolver)))
+        .insert_resource(settings.camera_scale())
+        .insert_resource(settings)
+        .add_plugins(
+            AwbrnPlugin::new(Arc::new(PrefixMapAssetPathResolver::new(
+                "https://awbw.amarriner.com/api/map/map_info.php?maps_id=",
+                "",
+            )))
+            .with_loading_color(Color::BLACK),
+        )
         .insert_resource(EventSink::<NewDay>::new(|e| {
             info!("New Day: Day {}", e.day);
         }))
@@ -53,7 +67,8 @@ impl Plugin for AwbrnDesktopPlugin {
             );
         }))
         .insert_resource(EventSink::<PlayerRosterSnapshot>::new(|_| {}))
-        .add_systems(Update, handle_file_drop);
+        .add_systems(Update, (handle_file_drop, track_desktop_settings))
+        .add_systems(Last, save_desktop_settings);
     }
 }
 
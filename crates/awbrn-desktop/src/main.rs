@@ -5,7 +5,7 @@ use desktop_plugin::AwbrnDesktopPlugin;
 use bevy_inspector_egui::{bevy_egui::EguiPlugin, quick::WorldInspectorPlugin};
 
 mod desktop_plugin;
-mod web_asset_plugin;
+mod settings;
 
 fn main() {
     let mut app = App::new();
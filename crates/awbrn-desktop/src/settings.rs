@@ -0,0 +1,140 @@
+//! Window size and zoom level persisted between launches.
+
+use awbrn_client::features::CameraScale;
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowCloseRequested};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const SETTINGS_FILE_NAME: &str = "desktop-settings.json";
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DesktopSettings {
+    /// Logical width of the primary window.
+    pub window_width: f32,
+    /// Logical height of the primary window.
+    pub window_height: f32,
+    pub camera_scale: f32,
+}
+
+impl Default for DesktopSettings {
+    fn default() -> Self {
+        let window = Window::default();
+        Self {
+            window_width: window.resolution.width(),
+            window_height: window.resolution.height(),
+            camera_scale: CameraScale::default().scale(),
+        }
+    }
+}
+
+impl DesktopSettings {
+    /// Load the saved settings, falling back to defaults when there are none
+    /// or they cannot be read.
+    pub(crate) fn load() -> Self {
+        let Some(path) = settings_path() else {
+            return Self::default();
+        };
+
+        let Ok(data) = fs::read(&path) else {
+            return Self::default();
+        };
+
+        match serde_json::from_slice(&data) {
+            Ok(settings) => settings,
+            Err(e) => {
+                warn!("Ignoring unreadable settings at {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    fn save(&self) {
+        let Some(path) = settings_path() else {
+            return;
+        };
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&path, serde_json::to_vec_pretty(self).unwrap_or_default()));
+        if let Err(e) = result {
+            warn!("Failed to save settings to {}: {}", path.display(), e);
+        }
+    }
+
+    pub(crate) fn window(&self) -> Window {
+        let mut window = Window::default();
+        window.resolution.set(self.window_width, self.window_height);
+        window
+    }
+
+    pub(crate) fn camera_scale(&self) -> CameraScale {
+        CameraScale::new(self.camera_scale)
+    }
+}
+
+fn settings_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("APPDATA")
+        .or_else(|| std::env::var_os("XDG_CONFIG_HOME"))
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("awbrn").join(SETTINGS_FILE_NAME))
+}
+
+/// Track the window size and zoom level as they change.
+pub(crate) fn track_desktop_settings(
+    mut settings: ResMut<DesktopSettings>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_scale: Res<CameraScale>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    settings.set_if_neq(DesktopSettings {
+        window_width: window.resolution.width(),
+        window_height: window.resolution.height(),
+        camera_scale: camera_scale.scale(),
+    });
+}
+
+/// Write the settings once, when the window is closed or the app exits.
+pub(crate) fn save_desktop_settings(
+    settings: Res<DesktopSettings>,
+    mut close_requests: MessageReader<WindowCloseRequested>,
+    mut exits: MessageReader<AppExit>,
+    mut saved: Local<bool>,
+) {
+    let closing = close_requests.read().count() > 0;
+    let exiting = exits.read().count() > 0;
+    if (closing || exiting) && !*saved {
+        settings.save();
+        *saved = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_round_trip_through_json() {
+        let settings = DesktopSettings {
+            window_width: 1600.0,
+            window_height: 900.0,
+            camera_scale: 2.5,
+        };
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert_eq!(
+            json,
+            r#"{"window_width":1600.0,"window_height":900.0,"camera_scale":2.5}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<DesktopSettings>(&json).unwrap(),
+            settings
+        );
+    }
+}
@@ -6,12 +6,13 @@
 
 use awbrn_map::Position;
 use awbrn_types::PlayerFaction;
-use awbw_replay::AwbwReplay;
+use awbw_replay::{AwbwReplay, UNRECOGNIZED_UNIT_PLACEHOLDER};
 use bevy::prelude::*;
 
 use crate::MapPosition;
 use crate::replay::{
-    AwbwUnitId, ReplayFogEnabled, ReplayPlayerRegistry, ReplayState, ReplayTerrainKnowledge,
+    AwbwUnitId, PlaybackDiagnostic, PlaybackDiagnostics, ReplayFogEnabled, ReplayPlayerRegistry,
+    ReplayState, ReplayTerrainKnowledge,
 };
 use crate::world::{
     Ammo, Faction, FogActive, FogOfWarMap, FriendlyFactions, Fuel, GameMap, GraphicalHp, TerrainHp,
@@ -70,7 +71,15 @@ pub fn initialize_replay_semantic_world(replay: &AwbwReplay, world: &mut World)
                         .iter()
                         .find(|player| player.id == unit.players_id)
                         .map(|player| player.faction)
-                        .unwrap_or(PlayerFaction::OrangeStar);
+                        .unwrap_or_else(|| {
+                            warn!(
+                                "Unit {} references unknown player {}, defaulting to {:?}",
+                                unit.id.as_u32(),
+                                unit.players_id.as_u32(),
+                                PlayerFaction::OrangeStar
+                            );
+                            PlayerFaction::OrangeStar
+                        });
 
                     (unit, faction)
                 })
@@ -119,6 +128,21 @@ pub fn initialize_replay_semantic_world(replay: &AwbwReplay, world: &mut World)
         active_player_id: first_player_id,
         ..ReplayState::default()
     });
+    world.insert_resource(PlaybackDiagnostics(
+        replay
+            .unrecognized_units()
+            .iter()
+            .map(|unit| PlaybackDiagnostic {
+                action_index: u32::try_from(unit.action_index).ok(),
+                unhandled_action: None,
+                message: format!(
+                    "Unrecognized unit {:?} shown as {}",
+                    unit.name,
+                    UNRECOGNIZED_UNIT_PLACEHOLDER.name()
+                ),
+            })
+            .collect(),
+    ));
 }
 
 fn initial_graphical_hp(hit_points: f64) -> u8 {
@@ -185,10 +209,7 @@ mod tests {
             GraphicalTerrain::PipeSeam(awbrn_types::PipeSeamType::Vertical),
         ));
 
-        let empty_replay = awbw_replay::AwbwReplay {
-            games: Vec::new(),
-            turns: Vec::new(),
-        };
+        let empty_replay = awbw_replay::AwbwReplay::new(Vec::new(), Vec::new());
         initialize_replay_semantic_world(&empty_replay, app.world_mut());
 
         let mut query = app.world_mut().query::<(&TerrainTile, &TerrainHp)>();
@@ -228,4 +249,48 @@ mod tests {
             .unwrap();
         assert_eq!(terrain_hp.value(), expected_hp);
     }
+
+    #[test]
+    fn units_with_unknown_player_default_to_orange_star() {
+        let replay_bytes = std::fs::read(replay_fixture_path("1362397.zip")).unwrap();
+        let mut replay = ReplayParser::new().parse(&replay_bytes).unwrap();
+
+        let first_game = replay.games.first_mut().unwrap();
+        let bogus_player_id = awbrn_types::AwbwGamePlayerId::new(1);
+        assert!(
+            first_game
+                .players
+                .iter()
+                .all(|player| player.id != bogus_player_id)
+        );
+        let orange_star_players: Vec<_> = first_game
+            .players
+            .iter()
+            .filter(|player| player.faction == PlayerFaction::OrangeStar)
+            .map(|player| player.id)
+            .collect();
+        let unit = first_game
+            .units
+            .iter_mut()
+            .find(|unit| !orange_star_players.contains(&unit.players_id))
+            .expect("fixture should have a unit not owned by Orange Star");
+        unit.players_id = bogus_player_id;
+        let unit_id = unit.id;
+
+        let mut app = bootstrap_test_app();
+        app.world_mut().resource_mut::<GameMap>().set(AwbrnMap::new(
+            40,
+            40,
+            GraphicalTerrain::Plain,
+        ));
+
+        initialize_replay_semantic_world(&replay, app.world_mut());
+
+        let mut query = app.world_mut().query::<(&AwbwUnitId, &Faction)>();
+        let (_, faction) = query
+            .iter(app.world())
+            .find(|(id, _)| id.0 == unit_id)
+            .expect("unit with unknown player should still spawn");
+        assert_eq!(faction.0, PlayerFaction::OrangeStar);
+    }
 }
@@ -15,7 +15,8 @@ use bevy::{log, prelude::*};
 
 use crate::MapPosition;
 use crate::replay::{
-    AwbwUnitId, PowerMovementBoosts, PowerVisionBoosts, ReplayPlayerRegistry, ReplayState,
+    AwbwUnitId, PlaybackDiagnostic, PlaybackDiagnostics, PowerMovementBoosts, PowerVisionBoosts,
+    ReplayPlayerRegistry, ReplayState,
 };
 use crate::world::{
     Ammo, BoardIndex, CaptureAction as WorldCaptureAction, CaptureProgress, CaptureProgressInput,
@@ -66,9 +67,12 @@ pub fn apply_move_state(move_action: &MoveAction, world: &mut World) -> Option<M
     };
 
     let entity = entity.or_else(|| {
-        log::warn!(
-            "Unit with ID {} not found in unit storage",
-            unit.units_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Unit with ID {} not found in unit storage",
+                unit.units_id.as_u32()
+            ),
         );
         None
     })?;
@@ -125,8 +129,59 @@ pub fn apply_non_move_action(action: &Action, world: &mut World) {
         Action::Join { join_action, .. } => apply_join(join_action, world),
         Action::Hide { move_action } => apply_hide(move_action.as_ref(), world),
         Action::Unhide { move_action } => apply_unhide(move_action.as_ref(), world),
-        Action::Move(_) => {}
-        _ => log::warn!("Unhandled action: {:?}", action),
+        Action::Move(move_action) => apply_wait(move_action, world),
+        _ => {
+            log::warn!("Unhandled action: {:?}", action);
+            record_playback_diagnostic(
+                world,
+                Some(action.kind_name()),
+                format!("Unhandled {} action", action.kind_name()),
+            );
+        }
+    }
+}
+
+/// Log a playback problem and record it in [`PlaybackDiagnostics`] when that
+/// resource is present.
+pub fn warn_playback(world: &mut World, message: impl Into<String>) {
+    let message = message.into();
+    log::warn!("{message}");
+    record_playback_diagnostic(world, None, message);
+}
+
+fn record_playback_diagnostic(
+    world: &mut World,
+    unhandled_action: Option<&'static str>,
+    message: String,
+) {
+    let action_index = world
+        .get_resource::<ReplayState>()
+        .and_then(|state| state.next_action_index.checked_sub(1));
+    if let Some(mut diagnostics) = world.get_resource_mut::<PlaybackDiagnostics>() {
+        diagnostics.0.push(PlaybackDiagnostic {
+            action_index,
+            unhandled_action,
+            message,
+        });
+    }
+}
+
+/// A bare move is AWBW's "Wait": the unit ends its turn without capturing,
+/// so any partial capture is abandoned even when it didn't leave the tile.
+pub fn apply_wait(move_action: &MoveAction, world: &mut World) {
+    let Some((_, unit)) = replay_move_view(move_action) else {
+        return;
+    };
+
+    let entity = {
+        let units = world.resource::<StrongIdMap<AwbwUnitId>>();
+        units.get(&AwbwUnitId(unit.units_id))
+    };
+
+    if let Some(entity) = entity {
+        world
+            .entity_mut(entity)
+            .remove::<(CaptureProgress, UnitActive)>();
     }
 }
 
@@ -139,11 +194,14 @@ pub fn apply_attack_seam(attack_seam_action: &AttackSeamAction, world: &mut Worl
 
     let Some(new_terrain) = pipe_terrain_from_replay(attack_seam_action.buildings_terrain_id)
     else {
-        log::warn!(
-            "Unsupported AttackSeam terrain ID {} at ({}, {})",
-            attack_seam_action.buildings_terrain_id,
-            attack_seam_action.seam_x,
-            attack_seam_action.seam_y
+        warn_playback(
+            world,
+            format!(
+                "Unsupported AttackSeam terrain ID {} at ({}, {})",
+                attack_seam_action.buildings_terrain_id,
+                attack_seam_action.seam_x,
+                attack_seam_action.seam_y
+            ),
         );
         if let Some(entity) = attacker_entity {
             world.entity_mut(entity).remove::<UnitActive>();
@@ -160,11 +218,14 @@ pub fn apply_attack_seam(attack_seam_action: &AttackSeamAction, world: &mut Worl
     };
 
     if matches!(new_terrain, GraphicalTerrain::PipeSeam(_)) && terrain_hp.is_none() {
-        log::warn!(
-            "AttackSeam left seam terrain with invalid HP {} at ({}, {})",
-            attack_seam_action.buildings_hit_points,
-            attack_seam_action.seam_x,
-            attack_seam_action.seam_y
+        warn_playback(
+            world,
+            format!(
+                "AttackSeam left seam terrain with invalid HP {} at ({}, {})",
+                attack_seam_action.buildings_hit_points,
+                attack_seam_action.seam_x,
+                attack_seam_action.seam_y
+            ),
         );
     }
 
@@ -187,12 +248,19 @@ pub fn apply_build(new_unit: &UnitMap, world: &mut World) {
             let unit = unit_data.get_value()?;
             let x = unit.units_x?;
             let y = unit.units_y?;
+            let player_id = awbrn_types::AwbwGamePlayerId::new(unit.units_players_id);
             let faction = world
                 .get_resource::<ReplayPlayerRegistry>()
-                .and_then(|r| {
-                    r.faction_for_player(awbrn_types::AwbwGamePlayerId::new(unit.units_players_id))
-                })
-                .unwrap_or(PlayerFaction::OrangeStar);
+                .and_then(|r| r.faction_for_player(player_id))
+                .unwrap_or_else(|| {
+                    warn!(
+                        "Built unit {} references unknown player {}, defaulting to {:?}",
+                        unit.units_id.as_u32(),
+                        player_id.as_u32(),
+                        PlayerFaction::OrangeStar
+                    );
+                    PlayerFaction::OrangeStar
+                });
             Some((unit.clone(), x, y, faction))
         })
         .collect();
@@ -232,7 +300,10 @@ pub fn apply_capture(
     let capturing_unit = resolve_capture_unit(move_action, building_pos, world);
 
     let Some(entity) = capturing_unit else {
-        log::warn!("No unit found at capture position {:?}", building_pos);
+        warn_playback(
+            world,
+            format!("No unit found at capture position {:?}", building_pos),
+        );
         return;
     };
 
@@ -245,7 +316,10 @@ pub fn apply_capture(
     .apply(world);
 
     if let Err(error) = result {
-        log::warn!("Failed to apply capture at {:?}: {}", building_pos, error);
+        warn_playback(
+            world,
+            format!("Failed to apply capture at {:?}: {}", building_pos, error),
+        );
     }
 }
 
@@ -319,12 +393,12 @@ pub fn apply_load(load_action: &LoadAction, world: &mut World) {
         .find_map(|hidden| hidden.get_value().copied());
 
     let Some(loaded_id_core) = loaded_unit_id else {
-        log::warn!("No loaded unit ID found in load action");
+        warn_playback(world, "No loaded unit ID found in load action");
         return;
     };
 
     let Some(transport_id_core) = transport_unit_id else {
-        log::warn!("No transport unit ID found in load action");
+        warn_playback(world, "No transport unit ID found in load action");
         return;
     };
 
@@ -337,17 +411,23 @@ pub fn apply_load(load_action: &LoadAction, world: &mut World) {
     };
 
     let Some(loaded_entity) = loaded_entity else {
-        log::warn!(
-            "Loaded unit entity not found for ID: {}",
-            loaded_id_core.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Loaded unit entity not found for ID: {}",
+                loaded_id_core.as_u32()
+            ),
         );
         return;
     };
 
     let Some(transport_entity) = transport_entity else {
-        log::warn!(
-            "Transport unit entity not found for ID: {}",
-            transport_id_core.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Transport unit entity not found for ID: {}",
+                transport_id_core.as_u32()
+            ),
         );
         return;
     };
@@ -373,17 +453,17 @@ pub fn apply_unload(
     let unloaded_unit = unit_map.values().find_map(|hidden| hidden.get_value());
 
     let Some(unit) = unloaded_unit else {
-        log::warn!("No unloaded unit found in unload action");
+        warn_playback(world, "No unloaded unit found in unload action");
         return;
     };
 
     let Some(x) = unit.units_x else {
-        log::warn!("Unloaded unit has no x coordinate");
+        warn_playback(world, "Unloaded unit has no x coordinate");
         return;
     };
 
     let Some(y) = unit.units_y else {
-        log::warn!("Unloaded unit has no y coordinate");
+        warn_playback(world, "Unloaded unit has no y coordinate");
         return;
     };
 
@@ -395,9 +475,12 @@ pub fn apply_unload(
     };
 
     let Some(unloaded_entity) = unloaded_entity else {
-        log::warn!(
-            "Unloaded unit entity not found for ID: {}",
-            unit.units_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Unloaded unit entity not found for ID: {}",
+                unit.units_id.as_u32()
+            ),
         );
         return;
     };
@@ -455,9 +538,12 @@ pub fn apply_power(power_action: &PowerAction, world: &mut World) {
             .faction_for_player(power_action.player_id);
 
         let Some(faction) = maybe_faction else {
-            log::warn!(
-                "Power action player {:?} missing from replay registry",
-                power_action.player_id
+            warn_playback(
+                world,
+                format!(
+                    "Power action player {:?} missing from replay registry",
+                    power_action.player_id
+                ),
             );
             return;
         };
@@ -512,12 +598,12 @@ pub fn apply_repair(repair_action: &RepairAction, world: &mut World) {
     let Some(repairing_id) =
         targeted_hidden_value(&repair_action.unit).map(awbrn_types::AwbwUnitId::new)
     else {
-        log::warn!("Repair action missing repairing unit ID");
+        warn_playback(world, "Repair action missing repairing unit ID");
         return;
     };
 
     let Some(repaired) = targeted_value(&repair_action.repaired).cloned() else {
-        log::warn!("Repair action missing repaired unit payload");
+        warn_playback(world, "Repair action missing repaired unit payload");
         return;
     };
 
@@ -580,9 +666,12 @@ fn apply_power_unit_change(world: &mut World, change: &UnitChange) {
     };
 
     let Some(entity) = entity else {
-        log::warn!(
-            "Power unit replacement target not found for ID: {}",
-            change.units_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Power unit replacement target not found for ID: {}",
+                change.units_id.as_u32()
+            ),
         );
         return;
     };
@@ -635,7 +724,7 @@ pub fn apply_supply(supply_action: &SupplyAction, world: &mut World) {
     let Some(supplying_id) =
         targeted_hidden_value(&supply_action.unit).map(awbrn_types::AwbwUnitId::new)
     else {
-        log::warn!("Supply action missing supplying unit ID");
+        warn_playback(world, "Supply action missing supplying unit ID");
         return;
     };
 
@@ -650,7 +739,7 @@ pub fn apply_join(join_action: &JoinAction, world: &mut World) {
     let surviving_unit = join_action.unit.values().find_map(|h| h.get_value());
 
     let Some(unit) = surviving_unit else {
-        log::warn!("Join action missing surviving unit data");
+        warn_playback(world, "Join action missing surviving unit data");
         return;
     };
 
@@ -661,9 +750,12 @@ pub fn apply_join(join_action: &JoinAction, world: &mut World) {
     };
 
     let Some(surviving_entity) = surviving_entity else {
-        log::warn!(
-            "Surviving unit entity not found for ID: {}",
-            unit.units_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Surviving unit entity not found for ID: {}",
+                unit.units_id.as_u32()
+            ),
         );
         return;
     };
@@ -677,7 +769,7 @@ pub fn apply_join(join_action: &JoinAction, world: &mut World) {
     let Some(joining_id) =
         targeted_hidden_value(&join_action.join_id).map(awbrn_types::AwbwUnitId::new)
     else {
-        log::warn!("Join action missing joining unit ID");
+        warn_playback(world, "Join action missing joining unit ID");
         world.entity_mut(surviving_entity).remove::<UnitActive>();
         return;
     };
@@ -696,9 +788,12 @@ pub fn apply_join(join_action: &JoinAction, world: &mut World) {
             hp_value,
         );
     } else {
-        log::warn!(
-            "Joining unit entity not found for ID: {}",
-            joining_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Joining unit entity not found for ID: {}",
+                joining_id.as_u32()
+            ),
         );
     }
 
@@ -707,12 +802,12 @@ pub fn apply_join(join_action: &JoinAction, world: &mut World) {
 
 pub fn apply_hide(move_action: Option<&MoveAction>, world: &mut World) {
     let Some(mov) = move_action else {
-        log::warn!("Hide action missing move data");
+        warn_playback(world, "Hide action missing move data");
         return;
     };
 
     let Some((_, unit)) = replay_move_view(mov) else {
-        log::warn!("Hide action missing visible unit data");
+        warn_playback(world, "Hide action missing visible unit data");
         return;
     };
 
@@ -725,21 +820,24 @@ pub fn apply_hide(move_action: Option<&MoveAction>, world: &mut World) {
         world.entity_mut(entity).insert(Hiding);
         log::info!("Unit {} is now hiding", unit.units_id.as_u32());
     } else {
-        log::warn!(
-            "Hide unit entity not found for ID: {}",
-            unit.units_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Hide unit entity not found for ID: {}",
+                unit.units_id.as_u32()
+            ),
         );
     }
 }
 
 pub fn apply_unhide(move_action: Option<&MoveAction>, world: &mut World) {
     let Some(mov) = move_action else {
-        log::warn!("Unhide action missing move data");
+        warn_playback(world, "Unhide action missing move data");
         return;
     };
 
     let Some((_, unit)) = replay_move_view(mov) else {
-        log::warn!("Unhide action missing visible unit data");
+        warn_playback(world, "Unhide action missing visible unit data");
         return;
     };
 
@@ -752,9 +850,12 @@ pub fn apply_unhide(move_action: Option<&MoveAction>, world: &mut World) {
         world.entity_mut(entity).remove::<Hiding>();
         log::info!("Unit {} is no longer hiding", unit.units_id.as_u32());
     } else {
-        log::warn!(
-            "Unhide unit entity not found for ID: {}",
-            unit.units_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Unhide unit entity not found for ID: {}",
+                unit.units_id.as_u32()
+            ),
         );
     }
 }
@@ -768,9 +869,12 @@ pub fn update_combat_unit_state(world: &mut World, combat_unit: &CombatUnit) ->
     };
 
     let Some(entity) = entity else {
-        log::warn!(
-            "Unit entity not found for ID: {}",
-            combat_unit.units_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Unit entity not found for ID: {}",
+                combat_unit.units_id.as_u32()
+            ),
         );
         return None;
     };
@@ -815,9 +919,12 @@ pub fn update_unit_resources_from_property(world: &mut World, entity: Entity, un
 
 pub fn refill_unit_resources(world: &mut World, entity: Entity) {
     let Some(unit) = world.get::<Unit>(entity).copied() else {
-        log::warn!(
-            "Cannot refill resources for entity {:?} without Unit",
-            entity
+        warn_playback(
+            world,
+            format!(
+                "Cannot refill resources for entity {:?} without Unit",
+                entity
+            ),
         );
         return;
     };
@@ -834,7 +941,10 @@ pub fn refill_unit_resources_by_id(world: &mut World, unit_id: awbrn_types::Awbw
     };
 
     let Some(entity) = entity else {
-        log::warn!("Unit entity not found for ID: {}", unit_id.as_u32());
+        warn_playback(
+            world,
+            format!("Unit entity not found for ID: {}", unit_id.as_u32()),
+        );
         return;
     };
 
@@ -848,7 +958,10 @@ pub fn mark_unit_inactive(world: &mut World, unit_id: awbrn_types::AwbwUnitId) {
     };
 
     let Some(entity) = entity else {
-        log::warn!("Unit entity not found for ID: {}", unit_id.as_u32());
+        warn_playback(
+            world,
+            format!("Unit entity not found for ID: {}", unit_id.as_u32()),
+        );
         return;
     };
 
@@ -863,9 +976,12 @@ pub fn apply_repaired_unit(world: &mut World, repaired: &RepairedUnit) {
     };
 
     let Some(entity) = entity else {
-        log::warn!(
-            "Repaired unit entity not found for ID: {}",
-            repaired.units_id.as_u32()
+        warn_playback(
+            world,
+            format!(
+                "Repaired unit entity not found for ID: {}",
+                repaired.units_id.as_u32()
+            ),
         );
         return;
     };
@@ -891,9 +1007,12 @@ pub fn activate_all_units(world: &mut World) {
 
     for entity in &unit_entities {
         let Ok(mut entity_mut) = world.get_entity_mut(*entity) else {
-            log::warn!(
-                "expected entity from query missing when setting UnitActive: {:?}",
-                entity
+            warn_playback(
+                world,
+                format!(
+                    "expected entity from query missing when setting UnitActive: {:?}",
+                    entity
+                ),
             );
             continue;
         };
@@ -916,7 +1035,7 @@ pub fn set_terrain_at(
     let terrain_entity = world.resource::<BoardIndex>().terrain_entity(pos).ok();
 
     let Some(terrain_entity) = terrain_entity else {
-        log::warn!("No terrain entity found at {:?}", pos);
+        warn_playback(world, format!("No terrain entity found at {:?}", pos));
         return;
     };
 
@@ -925,7 +1044,7 @@ pub fn set_terrain_at(
         .set_terrain(pos, new_terrain)
         .is_some();
     if !map_updated {
-        log::warn!("No GameMap tile found at {:?}", pos);
+        warn_playback(world, format!("No GameMap tile found at {:?}", pos));
         return;
     }
 
@@ -1225,6 +1344,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unknown_actions_are_recorded_as_unhandled() {
+        let mut world = World::new();
+        world.init_resource::<PlaybackDiagnostics>();
+        world.insert_resource(ReplayState {
+            next_action_index: 4,
+            ..Default::default()
+        });
+        warn_playback(&mut world, "Unit not found");
+
+        let action = Action::Unknown(serde_json::json!({"action": "Teleport"}));
+        apply_non_move_action(&action, &mut world);
+
+        let diagnostics = world.resource::<PlaybackDiagnostics>();
+        assert_eq!(diagnostics.0.len(), 2);
+        assert_eq!(
+            diagnostics.unhandled_actions().collect::<Vec<_>>(),
+            vec!["Unknown"]
+        );
+        assert_eq!(
+            diagnostics.0[1],
+            PlaybackDiagnostic {
+                action_index: Some(3),
+                unhandled_action: Some("Unknown"),
+                message: "Unhandled Unknown action".to_string(),
+            }
+        );
+    }
+
     #[test]
     fn stationary_supply_refills_supplied_units_and_inactivates_supplier() {
         let mut app = replay_turn_test_app();
@@ -1485,6 +1633,36 @@ mod tests {
         assert_eq!(vision_range.0, 2);
     }
 
+    #[test]
+    fn build_with_unknown_player_spawns_with_default_faction() {
+        let mut app = replay_turn_test_app();
+        app.world_mut()
+            .resource_mut::<crate::replay::ReplayPlayerRegistry>()
+            .add_player(
+                awbrn_types::AwbwGamePlayerId::new(1),
+                PlayerFaction::BlueMoon,
+                0,
+            );
+
+        let mut unit = test_unit_property(CoreUnitId::new(7), 4, 5);
+        unit.units_players_id = 999;
+        apply_non_move_action(
+            &Action::Build {
+                new_unit: [(TargetedPlayer::Global, Hidden::Visible(unit))].into(),
+                discovered: Default::default(),
+            },
+            app.world_mut(),
+        );
+
+        let mut query = app.world_mut().query::<(&AwbwUnitId, &Faction)>();
+        let (_, faction) = query
+            .iter(app.world())
+            .find(|(unit_id, _)| unit_id.0 == CoreUnitId::new(7))
+            .expect("unit with unknown player should still be built");
+
+        assert_eq!(faction.0, PlayerFaction::OrangeStar);
+    }
+
     #[test]
     fn power_action_updates_weather_and_active_player_stat_boosts() {
         let mut app = replay_turn_test_app();
@@ -2179,6 +2357,69 @@ mod tests {
         );
     }
 
+    fn wait_action(unit_id: CoreUnitId, x: u32, y: u32, path: &[(u32, u32)]) -> MoveAction {
+        MoveAction {
+            unit: [(
+                TargetedPlayer::Global,
+                Hidden::Visible(test_unit_property(unit_id, x, y)),
+            )]
+            .into(),
+            paths: [(
+                TargetedPlayer::Global,
+                path.iter()
+                    .map(|&(x, y)| PathTile {
+                        unit_visible: true,
+                        x,
+                        y,
+                    })
+                    .collect(),
+            )]
+            .into(),
+            dist: path.len().saturating_sub(1) as u32,
+            trapped: false,
+            discovered: None,
+        }
+    }
+
+    #[test]
+    fn waiting_elsewhere_resets_partial_capture() {
+        let mut app = replay_turn_test_app();
+        let unit_entity = spawn_test_unit(&mut app, Position::new(2, 2), CoreUnitId::new(1));
+        app.world_mut()
+            .entity_mut(unit_entity)
+            .insert(CaptureProgress::new(10).unwrap());
+
+        let move_action = wait_action(CoreUnitId::new(1), 3, 2, &[(2, 2), (3, 2)]);
+        let outcome = apply_move_state(&move_action, app.world_mut()).unwrap();
+        app.world_mut()
+            .entity_mut(outcome.entity)
+            .insert(outcome.new_position);
+        apply_non_move_action(&Action::Move(move_action), app.world_mut());
+
+        assert!(
+            !app.world()
+                .entity(unit_entity)
+                .contains::<CaptureProgress>()
+        );
+    }
+
+    #[test]
+    fn waiting_in_place_resets_partial_capture() {
+        let mut app = replay_turn_test_app();
+        let unit_entity = spawn_test_unit(&mut app, Position::new(2, 2), CoreUnitId::new(1));
+        app.world_mut()
+            .entity_mut(unit_entity)
+            .insert((CaptureProgress::new(10).unwrap(), UnitActive));
+
+        let move_action = wait_action(CoreUnitId::new(1), 2, 2, &[(2, 2)]);
+        apply_move_state(&move_action, app.world_mut());
+        apply_non_move_action(&Action::Move(move_action), app.world_mut());
+
+        let unit = app.world().entity(unit_entity);
+        assert!(!unit.contains::<CaptureProgress>());
+        assert!(!unit.contains::<UnitActive>());
+    }
+
     #[test]
     fn stationary_capture_marks_unit_inactive() {
         let mut app = replay_turn_test_app();
@@ -2,17 +2,22 @@ pub mod bootstrap;
 pub mod commands;
 pub mod fog;
 pub mod state;
+pub mod timeline;
 
 pub use crate::world::{
     FriendlyUnit, collect_friendly_units, range_modifier_for_weather, rebuild_fog_map,
 };
 pub use bootstrap::initialize_replay_semantic_world;
 pub use commands::{
-    MoveOutcome, NewDay, apply_move_state, apply_non_move_action, replay_move_view,
+    MoveOutcome, NewDay, apply_move_state, apply_non_move_action, replay_move_view, warn_playback,
 };
 pub use fog::{
     ReplayFogDirty, ReplayFogEnabled, ReplayKnowledgeKey, ReplayPlayerRegistry,
     ReplayTerrainKnowledge, ReplayViewpoint, sync_viewpoint,
     trigger_fog_recompute_on_weather_change,
 };
-pub use state::{AwbwUnitId, PowerMovementBoosts, PowerVisionBoosts, ReplayState};
+pub use state::{
+    AwbwUnitId, PlaybackDiagnostic, PlaybackDiagnostics, PowerMovementBoosts, PowerVisionBoosts,
+    ReplayState,
+};
+pub use timeline::{PlaybackClock, ReplaySnapshot, UnitState};
@@ -33,6 +33,33 @@ pub struct PowerVisionBoosts(pub HashMap<PlayerFaction, i32>);
 #[derive(Resource, Debug, Default, Clone)]
 pub struct PowerMovementBoosts(pub HashMap<PlayerFaction, i32>);
 
+/// A problem playback hit while applying a replay action, such as an unknown
+/// action or a unit missing from the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaybackDiagnostic {
+    /// Index of the replay action being applied, when known.
+    pub action_index: Option<u32>,
+    /// Kind of the action when playback didn't know how to apply it at all.
+    pub unhandled_action: Option<&'static str>,
+    pub message: String,
+}
+
+/// Problems playback ran into, in the order they were encountered, so front
+/// ends can report actions that couldn't be rendered. Only recorded when the
+/// resource is present.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PlaybackDiagnostics(pub Vec<PlaybackDiagnostic>);
+
+impl PlaybackDiagnostics {
+    /// Kinds of the actions that playback didn't know how to apply, in the
+    /// order they were encountered.
+    pub fn unhandled_actions(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0
+            .iter()
+            .filter_map(|diagnostic| diagnostic.unhandled_action)
+    }
+}
+
 /// Resource tracking the current state of replay playback.
 #[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
 #[reflect(Resource)]
@@ -0,0 +1,139 @@
+//! Per-action history of unit state, recorded while stepping a replay.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::replay::AwbwUnitId;
+use crate::world::{Fuel, GraphicalHp};
+
+/// A unit's HP and fuel at one point in a replay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct UnitState {
+    pub hp: u8,
+    pub fuel: u32,
+}
+
+/// Unit state recorded after each replay action.
+///
+/// Call [`ReplaySnapshot::record`] once on the freshly bootstrapped world and
+/// again after every applied action. Turn `0` is the initial state and turn
+/// `n` is the state after the first `n` actions.
+#[derive(Debug, Clone, Default)]
+pub struct ReplaySnapshot {
+    turns: Vec<HashMap<awbrn_types::AwbwUnitId, UnitState>>,
+}
+
+impl ReplaySnapshot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append the current state of every unit in the world as the next turn.
+    pub fn record(&mut self, world: &mut World) {
+        let mut query = world.query::<(&AwbwUnitId, &GraphicalHp, Option<&Fuel>)>();
+        let units = query
+            .iter(world)
+            .map(|(unit_id, hp, fuel)| {
+                (
+                    unit_id.0,
+                    UnitState {
+                        hp: hp.value(),
+                        fuel: fuel.map_or(0, |fuel| fuel.0),
+                    },
+                )
+            })
+            .collect();
+        self.turns.push(units);
+    }
+
+    /// Number of recorded turns, including the initial state.
+    pub fn turn_count(&self) -> usize {
+        self.turns.len()
+    }
+
+    /// The unit's state at `turn`, or `None` if the turn was not recorded or
+    /// the unit did not exist then.
+    pub fn unit_state(&self, unit_id: awbrn_types::AwbwUnitId, turn: usize) -> Option<UnitState> {
+        self.turns.get(turn)?.get(&unit_id).copied()
+    }
+
+    /// The unit's state at every recorded turn it was alive for.
+    pub fn unit_timeline(
+        &self,
+        unit_id: awbrn_types::AwbwUnitId,
+    ) -> impl Iterator<Item = (usize, UnitState)> + '_ {
+        self.turns
+            .iter()
+            .enumerate()
+            .filter_map(move |(turn, units)| units.get(&unit_id).map(|state| (turn, *state)))
+    }
+}
+
+/// Maps wall-clock playback time to replay turns and back, assuming every
+/// action takes the same time to play, so external tooling such as video
+/// commentary can scrub in sync with a replay.
+///
+/// Turns are numbered like [`ReplaySnapshot`]: turn `0` is the initial state
+/// and turn `n` is shown once the first `n` actions have played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaybackClock {
+    /// Wall-clock time one action takes at the chosen speed
+    per_action: Duration,
+    action_count: usize,
+}
+
+impl PlaybackClock {
+    /// Clock for `action_count` actions that each take `action_duration` at
+    /// 1× speed, played back at `speed` (2.0 plays twice as fast).
+    pub fn new(action_duration: Duration, speed: f32, action_count: usize) -> Self {
+        let speed = f64::from(speed.max(f32::EPSILON));
+        let nanos = (action_duration.as_nanos() as f64 / speed).round() as u64;
+        Self {
+            per_action: Duration::from_nanos(nanos.max(1)),
+            action_count,
+        }
+    }
+
+    /// Turn on screen `elapsed` after playback started, clamped to the end of
+    /// the replay.
+    pub fn turn_at(&self, elapsed: Duration) -> usize {
+        let turn = elapsed.as_nanos() / self.per_action.as_nanos();
+        usize::try_from(turn).map_or(self.action_count, |turn| turn.min(self.action_count))
+    }
+
+    /// Time since playback started at which `turn` first appears.
+    pub fn elapsed_at(&self, turn: usize) -> Duration {
+        let turn = turn.min(self.action_count);
+        Duration::from_nanos(self.per_action.as_nanos() as u64 * turn as u64)
+    }
+
+    /// Wall-clock length of the whole replay.
+    pub fn total_duration(&self) -> Duration {
+        self.elapsed_at(self.action_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_speed_maps_elapsed_time_to_turns() {
+        let clock = PlaybackClock::new(Duration::from_secs(1), 2.0, 10);
+
+        assert_eq!(clock.turn_at(Duration::ZERO), 0);
+        assert_eq!(clock.turn_at(Duration::from_millis(499)), 0);
+        assert_eq!(clock.turn_at(Duration::from_millis(500)), 1);
+        assert_eq!(clock.turn_at(Duration::from_millis(1750)), 3);
+        assert_eq!(clock.turn_at(Duration::from_secs(60)), 10);
+
+        assert_eq!(clock.elapsed_at(3), Duration::from_millis(1500));
+        assert_eq!(clock.elapsed_at(20), Duration::from_secs(5));
+        assert_eq!(clock.total_duration(), Duration::from_secs(5));
+        for turn in 0..=10 {
+            assert_eq!(clock.turn_at(clock.elapsed_at(turn)), turn);
+        }
+    }
+}
@@ -43,6 +43,48 @@ pub struct GameSnapshot {
     pub scene: DynamicScene,
 }
 
+/// A unit recorded in a [`GameSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotUnit {
+    pub unit_id: Option<awbrn_types::AwbwUnitId>,
+    pub unit: awbrn_types::Unit,
+    /// `None` for units carried by a transport.
+    pub position: Option<awbrn_map::Position>,
+}
+
+impl GameSnapshot {
+    /// Units belonging to `faction`, in snapshot order.
+    pub fn units_of(
+        &self,
+        faction: awbrn_types::PlayerFaction,
+    ) -> impl Iterator<Item = SnapshotUnit> + '_ {
+        self.scene.entities.iter().filter_map(move |entity| {
+            let mut unit = None;
+            let mut unit_faction = None;
+            let mut unit_id = None;
+            let mut position = None;
+
+            for component in &entity.components {
+                if let Some(value) = component.try_downcast_ref::<Unit>() {
+                    unit = Some(value.0);
+                } else if let Some(value) = component.try_downcast_ref::<Faction>() {
+                    unit_faction = Some(value.0);
+                } else if let Some(value) = component.try_downcast_ref::<AwbwUnitId>() {
+                    unit_id = Some(value.0);
+                } else if let Some(value) = component.try_downcast_ref::<MapPosition>() {
+                    position = Some(value.position());
+                }
+            }
+
+            (unit_faction == Some(faction)).then_some(SnapshotUnit {
+                unit_id,
+                unit: unit?,
+                position,
+            })
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct CanonicalReplaySnapshot {
     pub next_action_index: u32,
@@ -436,6 +478,52 @@ mod tests {
         assert_eq!(canonical, restored_canonical);
     }
 
+    #[test]
+    fn snapshot_units_of_filters_by_faction() {
+        let mut app = snapshot_test_app();
+        app.world_mut().insert_resource(ReplayState::default());
+
+        for (id, x, faction, unit) in [
+            (1, 0, PlayerFaction::OrangeStar, awbrn_types::Unit::Infantry),
+            (2, 1, PlayerFaction::BlueMoon, awbrn_types::Unit::Tank),
+            (
+                3,
+                2,
+                PlayerFaction::OrangeStar,
+                awbrn_types::Unit::Artillery,
+            ),
+        ] {
+            app.world_mut().spawn((
+                MapPosition::new(x, 0),
+                Faction(faction),
+                AwbwUnitId(awbrn_types::AwbwUnitId::new(id)),
+                Unit(unit),
+            ));
+        }
+
+        let snapshot = capture_game_snapshot(app.world_mut()).unwrap();
+
+        let mut orange_star: Vec<_> = snapshot.units_of(PlayerFaction::OrangeStar).collect();
+        orange_star.sort_by_key(|unit| unit.unit_id);
+        assert_eq!(
+            orange_star,
+            vec![
+                SnapshotUnit {
+                    unit_id: Some(awbrn_types::AwbwUnitId::new(1)),
+                    unit: awbrn_types::Unit::Infantry,
+                    position: Some(awbrn_map::Position::new(0, 0)),
+                },
+                SnapshotUnit {
+                    unit_id: Some(awbrn_types::AwbwUnitId::new(3)),
+                    unit: awbrn_types::Unit::Artillery,
+                    position: Some(awbrn_map::Position::new(2, 0)),
+                },
+            ]
+        );
+        assert_eq!(snapshot.units_of(PlayerFaction::BlueMoon).count(), 1);
+        assert_eq!(snapshot.units_of(PlayerFaction::GreenEarth).count(), 0);
+    }
+
     #[test]
     fn snapshot_restore_preserves_active_player_id() {
         let mut app = snapshot_test_app();
@@ -20,7 +20,9 @@ pub struct BoardIndex {
     width: usize,
     height: usize,
     terrain_by_tile: Vec<Option<Entity>>,
-    unit_by_tile: Vec<Option<Entity>>,
+    /// Units on each tile in arrival order. More than one unit only shares a
+    /// tile transiently (mid capture, load, or join resolution).
+    units_by_tile: Vec<Vec<Entity>>,
 }
 
 impl Default for BoardIndex {
@@ -37,7 +39,7 @@ impl BoardIndex {
             width,
             height,
             terrain_by_tile: vec![None; tile_count],
-            unit_by_tile: vec![None; tile_count],
+            units_by_tile: vec![Vec::new(); tile_count],
         }
     }
 
@@ -50,9 +52,19 @@ impl BoardIndex {
         self.terrain_by_tile[index].ok_or(BoardIndexError::MissingTerrain { position })
     }
 
+    /// The most recently placed unit on the tile.
     pub fn unit_entity(&self, position: Position) -> Result<Option<Entity>, BoardIndexError> {
         let index = self.tile_index(position)?;
-        Ok(self.unit_by_tile[index])
+        Ok(self.units_by_tile[index].last().copied())
+    }
+
+    /// All units on the tile in arrival order. Out-of-bounds positions have
+    /// no units.
+    pub fn units_at(&self, position: Position) -> impl Iterator<Item = Entity> + '_ {
+        self.tile_index(position)
+            .ok()
+            .into_iter()
+            .flat_map(|index| self.units_by_tile[index].iter().copied())
     }
 
     pub fn set_terrain(
@@ -73,16 +85,15 @@ impl BoardIndex {
 
     pub fn set_unit(&mut self, position: Position, entity: Entity) -> Result<(), BoardIndexError> {
         let index = self.tile_index(position)?;
-        match self.unit_by_tile[index] {
-            Some(existing) if existing != entity => {
-                warn!(
-                    "BoardIndex unit collision at {:?}: replacing {:?} with {:?}",
-                    position, existing, entity
-                );
-            }
-            _ => {}
+        let units = &mut self.units_by_tile[index];
+        units.retain(|&existing| existing != entity);
+        if let Some(existing) = units.last() {
+            warn!(
+                "BoardIndex unit collision at {:?}: stacking {:?} on {:?}",
+                position, entity, existing
+            );
         }
-        self.unit_by_tile[index] = Some(entity);
+        units.push(entity);
         Ok(())
     }
 
@@ -92,9 +103,7 @@ impl BoardIndex {
         entity: Entity,
     ) -> Result<(), BoardIndexError> {
         let index = self.tile_index(position)?;
-        if self.unit_by_tile[index] == Some(entity) {
-            self.unit_by_tile[index] = None;
-        }
+        self.units_by_tile[index].retain(|&existing| existing != entity);
         Ok(())
     }
 
@@ -284,6 +293,43 @@ mod tests {
         assert_eq!(board_index.unit_entity(position).unwrap(), Some(second));
     }
 
+    #[test]
+    fn units_at_returns_every_unit_sharing_a_tile() {
+        let mut app = App::new();
+        app.insert_resource(BoardIndex::new(4, 4));
+
+        let position = Position::new(1, 2);
+        let first = app
+            .world_mut()
+            .spawn((
+                MapPosition::from(position),
+                Unit(awbrn_types::Unit::Infantry),
+            ))
+            .id();
+        let second = app
+            .world_mut()
+            .spawn((
+                MapPosition::from(position),
+                Unit(awbrn_types::Unit::Infantry),
+            ))
+            .id();
+
+        let board_index = app.world().resource::<BoardIndex>();
+        assert_eq!(
+            board_index.units_at(position).collect::<Vec<_>>(),
+            vec![first, second]
+        );
+        assert_eq!(board_index.units_at(Position::new(9, 9)).count(), 0);
+
+        app.world_mut().despawn(second);
+        let board_index = app.world().resource::<BoardIndex>();
+        assert_eq!(
+            board_index.units_at(position).collect::<Vec<_>>(),
+            vec![first]
+        );
+        assert_eq!(board_index.unit_entity(position).unwrap(), Some(first));
+    }
+
     #[test]
     fn terrain_bootstrap_registers_spawned_terrain_entities() {
         let mut app = App::new();
@@ -196,7 +196,7 @@ pub struct FogActive(pub bool);
 pub struct FriendlyFactions(pub HashSet<PlayerFaction>);
 
 pub fn range_modifier_for_weather(weather: Weather) -> i32 {
-    if weather == Weather::Rain { -1 } else { 0 }
+    if weather.reduces_vision() { -1 } else { 0 }
 }
 
 pub struct FriendlyUnit {
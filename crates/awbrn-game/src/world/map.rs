@@ -1,7 +1,7 @@
 use crate::MapPosition;
 use crate::world::BoardIndex;
-use awbrn_map::{AwbrnMap, Position};
-use awbrn_types::GraphicalTerrain;
+use awbrn_map::{AwbrnMap, AwbwMap, Position};
+use awbrn_types::{Faction, GraphicalTerrain};
 use bevy::prelude::*;
 
 #[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,6 +43,11 @@ impl GameMap {
         self.0.height()
     }
 
+    /// Every position on the map in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.0.positions()
+    }
+
     pub fn set(&mut self, map: AwbrnMap) {
         self.0 = map;
     }
@@ -58,6 +63,14 @@ impl GameMap {
     ) -> Option<GraphicalTerrain> {
         self.0.set_terrain(position, terrain)
     }
+
+    pub fn com_tower_count(&self, faction: Faction) -> u32 {
+        self.0.com_tower_count(faction)
+    }
+
+    pub fn to_awbw_map(&self) -> AwbwMap {
+        self.0.to_awbw_map()
+    }
 }
 
 pub fn initialize_terrain_semantic_world(world: &mut World) {
@@ -74,14 +87,12 @@ pub fn initialize_terrain_semantic_world(world: &mut World) {
         (
             game_map.width(),
             game_map.height(),
-            (0..game_map.height())
-                .flat_map(|y| {
-                    (0..game_map.width()).filter_map(move |x| {
-                        let position = Position::new(x, y);
-                        game_map
-                            .terrain_at(position)
-                            .map(|terrain| (position, TerrainTile { terrain }))
-                    })
+            game_map
+                .positions()
+                .filter_map(|position| {
+                    game_map
+                        .terrain_at(position)
+                        .map(|terrain| (position, TerrainTile { terrain }))
                 })
                 .collect(),
         )
@@ -19,7 +19,7 @@ pub use fog::{
 pub use id_index::StrongIdMap;
 pub use map::{GameMap, TerrainHp, TerrainTile, initialize_terrain_semantic_world};
 pub use units::{
-    Ammo, CaptureProgress, CaptureResolution, Cargo, CarriedBy, Faction, Fuel, GraphicalHp,
-    HasCargo, Hiding, Unit, UnitActive, UnitDestroyed, UnitHp, VisionRange,
+    Ammo, CaptureProgress, CaptureResolution, Cargo, CarriedBy, Faction, FactionUnits, Fuel,
+    GraphicalHp, HasCargo, Hiding, Unit, UnitActive, UnitDestroyed, UnitHp, VisionRange,
 };
 pub use weather::CurrentWeather;
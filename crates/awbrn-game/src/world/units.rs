@@ -2,6 +2,7 @@ use bevy::ecs::entity::MapEntities;
 use bevy::ecs::lifecycle::HookContext;
 use bevy::ecs::reflect::ReflectMapEntities;
 use bevy::ecs::relationship::RelationshipSourceCollection;
+use bevy::ecs::system::SystemParam;
 use bevy::ecs::world::DeferredWorld;
 use bevy::prelude::*;
 
@@ -25,6 +26,29 @@ pub struct Unit(pub awbrn_types::Unit);
 #[reflect(Component)]
 pub struct Faction(pub awbrn_types::PlayerFaction);
 
+/// Per-faction view over the units in the world, for roster-style UI.
+#[derive(SystemParam)]
+pub struct FactionUnits<'w, 's> {
+    units: Query<'w, 's, (Entity, &'static Unit, &'static Faction)>,
+}
+
+impl FactionUnits<'_, '_> {
+    /// Every unit entity owned by `faction` and its unit type.
+    pub fn units_of(
+        &self,
+        faction: awbrn_types::PlayerFaction,
+    ) -> impl Iterator<Item = (Entity, awbrn_types::Unit)> + '_ {
+        self.units
+            .iter()
+            .filter(move |(_, _, owner)| owner.0 == faction)
+            .map(|(entity, unit, _)| (entity, unit.0))
+    }
+
+    pub fn count(&self, faction: awbrn_types::PlayerFaction) -> usize {
+        self.units_of(faction).count()
+    }
+}
+
 /// Component to mark a unit that can receive orders this turn.
 /// Units without this component have already acted and appear grey/frozen.
 #[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
@@ -237,6 +261,55 @@ pub struct VisionRange(pub u32);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use awbrn_types::PlayerFaction;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn faction_units_lists_units_per_faction() {
+        let mut world = World::new();
+        let infantry = world
+            .spawn((
+                Unit(awbrn_types::Unit::Infantry),
+                Faction(PlayerFaction::OrangeStar),
+            ))
+            .id();
+        let tank = world
+            .spawn((
+                Unit(awbrn_types::Unit::Tank),
+                Faction(PlayerFaction::BlueMoon),
+            ))
+            .id();
+        let artillery = world
+            .spawn((
+                Unit(awbrn_types::Unit::Artillery),
+                Faction(PlayerFaction::OrangeStar),
+            ))
+            .id();
+
+        let (orange_star, blue_moon, green_earth) = world
+            .run_system_once(|faction_units: FactionUnits| {
+                let mut orange_star: Vec<_> =
+                    faction_units.units_of(PlayerFaction::OrangeStar).collect();
+                orange_star.sort();
+                (
+                    orange_star,
+                    faction_units
+                        .units_of(PlayerFaction::BlueMoon)
+                        .collect::<Vec<_>>(),
+                    faction_units.count(PlayerFaction::GreenEarth),
+                )
+            })
+            .unwrap();
+
+        let mut expected = vec![
+            (infantry, awbrn_types::Unit::Infantry),
+            (artillery, awbrn_types::Unit::Artillery),
+        ];
+        expected.sort();
+        assert_eq!(orange_star, expected);
+        assert_eq!(blue_moon, vec![(tank, awbrn_types::Unit::Tank)]);
+        assert_eq!(green_earth, 0);
+    }
 
     #[test]
     fn replay_capture_points_do_not_wrap_when_narrowed() {
@@ -1,9 +1,14 @@
 use crate::{
-    Position,
+    BalanceReport, MapError, Position,
     awbw_map::AwbwMap,
-    pathfinding::{MovementMap, PathFinder},
+    pathfinding::{MovementMap, MovementTypeCosts, PathFinder},
+};
+use std::hash::Hasher;
+
+use awbrn_types::{
+    AwbwTerrain, Faction, GameplayTerrain, GraphicalTerrain, MovementCost, MovementTerrain,
+    Property, SeaDirection, ShoalDirection, Unit, Weather,
 };
-use awbrn_types::{AwbwTerrain, GraphicalTerrain, MovementTerrain, SeaDirection, ShoalDirection};
 
 /// Represents a game map with graphical terrain data
 #[derive(Debug, Clone, PartialEq)]
@@ -462,6 +467,28 @@ impl AwbrnMap {
         }
     }
 
+    /// Parses a map from the AWBW text format written by
+    /// [`AwbwMap::to_awbw_text`]: one row per line with comma-separated
+    /// terrain ids. Handy for writing small maps by hand in tests.
+    ///
+    /// Uneven rows and unknown terrain ids are reported with the row and
+    /// column they occur at.
+    pub fn from_awbw_text(text: &str) -> Result<Self, MapError> {
+        AwbwMap::parse_txt(text).map(|map| Self::from_map(&map))
+    }
+
+    /// Convert back to an AwbwMap, dropping purely graphical distinctions
+    /// such as shoal orientation
+    pub fn to_awbw_map(&self) -> AwbwMap {
+        let mut map = AwbwMap::new(self.width, self.height, AwbwTerrain::Plain);
+        for (pos, terrain) in self.iter() {
+            if let Some(tile) = map.terrain_at_mut(pos) {
+                *tile = terrain.as_terrain();
+            }
+        }
+        map
+    }
+
     /// Create a new map with specified dimensions and default terrain
     pub fn new(width: usize, height: usize, default_terrain: GraphicalTerrain) -> Self {
         Self {
@@ -481,8 +508,24 @@ impl AwbrnMap {
         self.height
     }
 
+    /// Returns true if the position lies within the map
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    /// Clamp the position to the nearest on-map position
+    pub fn clamp(&self, pos: Position) -> Position {
+        Position::new(
+            pos.x.min(self.width.saturating_sub(1)),
+            pos.y.min(self.height.saturating_sub(1)),
+        )
+    }
+
     /// Get the terrain at the specified position
     pub fn terrain_at(&self, pos: Position) -> Option<GraphicalTerrain> {
+        if !self.contains(pos) {
+            return None;
+        }
         self.terrain.get(pos.y * self.width + pos.x).copied()
     }
 
@@ -492,7 +535,7 @@ impl AwbrnMap {
         pos: Position,
         terrain: GraphicalTerrain,
     ) -> Option<GraphicalTerrain> {
-        if pos.x >= self.width || pos.y >= self.height {
+        if !self.contains(pos) {
             return None;
         }
         let slot = self.terrain.get_mut(pos.y * self.width + pos.x)?;
@@ -501,6 +544,26 @@ impl AwbrnMap {
         Some(previous)
     }
 
+    /// Every position on the map in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    /// Positions in the `width` by `height` rectangle whose top-left corner
+    /// is `origin`, in row-major order. The rectangle is clipped to the map.
+    pub fn positions_in_rect(
+        &self,
+        origin: Position,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let end_x = origin.x.saturating_add(width).min(self.width);
+        let end_y = origin.y.saturating_add(height).min(self.height);
+        let start_x = origin.x;
+        (origin.y..end_y).flat_map(move |y| (start_x..end_x).map(move |x| Position::new(x, y)))
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (Position, GraphicalTerrain)> {
         self.terrain.iter().enumerate().map(move |(idx, terrain)| {
             let y = idx / self.width;
@@ -509,9 +572,107 @@ impl AwbrnMap {
         })
     }
 
+    /// Gameplay terrain of every tile in row-major order, for analysis that
+    /// doesn't care about visual variants.
+    pub fn gameplay_grid(&self) -> Vec<GameplayTerrain> {
+        self.terrain
+            .iter()
+            .map(|terrain| terrain.as_terrain().gameplay_type())
+            .collect()
+    }
+
+    /// Number of com towers owned by the faction
+    pub fn com_tower_count(&self, faction: Faction) -> u32 {
+        self.terrain
+            .iter()
+            .filter(|terrain| {
+                matches!(
+                    terrain,
+                    GraphicalTerrain::Property(Property::ComTower(owner)) if *owner == faction
+                )
+            })
+            .count() as u32
+    }
+
+    /// Stable hash of the map dimensions and terrain grid.
+    ///
+    /// Only fixed-width little-endian bytes are hashed, so the result is
+    /// identical across runs and platforms and can key caches of data derived
+    /// from the map.
+    pub fn checksum(&self) -> u64 {
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(&(self.width as u32).to_le_bytes());
+        hasher.write(&(self.height as u32).to_le_bytes());
+        for terrain in &self.terrain {
+            hasher.write(&terrain.stable_id().to_le_bytes());
+        }
+        hasher.finish()
+    }
+
+    /// Cost for `unit` to move onto the tile at `pos`, or `None` when the
+    /// tile is off the map or impassable to the unit.
+    pub fn move_cost(&self, pos: Position, unit: Unit, weather: Weather) -> Option<u32> {
+        let terrain = MovementTerrain::from(self.terrain_at(pos)?.as_terrain());
+        MovementCost::from_terrain_in_weather(&terrain, weather)
+            .cost(unit.movement_type())
+            .map(u32::from)
+    }
+
     pub fn pathfinder(&self) -> PathFinder<&Self> {
         PathFinder::new(self)
     }
+
+    /// Which of `units` can end a move on `target` this turn, paired with the
+    /// movement cost to get there. The inverse of [`PathFinder::reachable`],
+    /// for drawing threat ranges. Other units on the board are not treated as
+    /// obstacles.
+    pub fn units_that_can_reach(
+        &self,
+        target: Position,
+        units: &[(Position, Unit)],
+        weather: Weather,
+    ) -> Vec<(Position, u32)> {
+        let mut pathfinder = self.pathfinder();
+        units
+            .iter()
+            .filter_map(|&(start, unit)| {
+                let costs = MovementTypeCosts::new(unit.movement_type()).with_weather(weather);
+                let (_, cost) = pathfinder
+                    .reachable(start, unit.movement_range(), costs)
+                    .into_positions()
+                    .find(|&(pos, _)| pos == target)?;
+                Some((start, u32::from(cost)))
+            })
+            .collect()
+    }
+
+    /// Property counts, HQ distances, and neutral property split per faction
+    /// for judging whether the map is fair.
+    pub fn balance_report(&self) -> BalanceReport {
+        BalanceReport::new(self)
+    }
+}
+
+/// 64-bit FNV-1a, used for hashes that must not vary between runs.
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
 }
 
 impl MovementMap for AwbrnMap {
@@ -539,10 +700,262 @@ impl MovementMap for AwbrnMap {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use awbrn_types::{BridgeType, PlayerFaction};
     use std::collections::HashSet;
     use std::fs;
     use std::path::Path;
 
+    #[test]
+    fn test_contains() {
+        let map = AwbrnMap::new(3, 2, GraphicalTerrain::Plain);
+
+        assert!(map.contains(Position::new(0, 0)));
+        assert!(map.contains(Position::new(2, 1)));
+        assert!(!map.contains(Position::new(3, 1)));
+        assert!(!map.contains(Position::new(2, 2)));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let map = AwbrnMap::new(3, 2, GraphicalTerrain::Plain);
+
+        assert_eq!(map.clamp(Position::new(1, 1)), Position::new(1, 1));
+        assert_eq!(map.clamp(Position::new(10, 0)), Position::new(2, 0));
+        assert_eq!(map.clamp(Position::new(1, 7)), Position::new(1, 1));
+        assert_eq!(map.clamp(Position::new(10, 7)), Position::new(2, 1));
+    }
+
+    #[test]
+    fn test_from_awbw_text() {
+        let map = AwbrnMap::from_awbw_text("1,1,3\n2,34,1\n1,1,1\n").unwrap();
+
+        assert_eq!((map.width(), map.height()), (3, 3));
+        assert_eq!(
+            map.terrain_at(Position::new(2, 0)),
+            Some(GraphicalTerrain::Wood)
+        );
+        assert_eq!(
+            map.terrain_at(Position::new(1, 1)),
+            Some(GraphicalTerrain::Property(Property::City(Faction::Neutral)))
+        );
+        assert_eq!(map.to_awbw_map().to_awbw_text(), "1,1,3\n2,34,1\n1,1,1\n");
+
+        assert_eq!(
+            AwbrnMap::from_awbw_text("1,1,1\n1,1\n"),
+            Err(MapError::UnevenDimensions {
+                expected: 3,
+                found: 2,
+                row: 1,
+            })
+        );
+        assert_eq!(
+            AwbrnMap::from_awbw_text("1,1\n1,255\n"),
+            Err(MapError::InvalidTerrain {
+                row: 1,
+                col: 1,
+                id: 255,
+            })
+        );
+    }
+
+    #[test]
+    fn test_positions() {
+        let map = AwbrnMap::new(3, 2, GraphicalTerrain::Plain);
+
+        let mut expected = Vec::new();
+        for y in 0..map.height() {
+            for x in 0..map.width() {
+                expected.push(Position::new(x, y));
+            }
+        }
+
+        let positions: Vec<_> = map.positions().collect();
+        assert_eq!(positions.len(), map.width() * map.height());
+        assert_eq!(positions, expected);
+    }
+
+    #[test]
+    fn test_positions_in_rect() {
+        let map = AwbrnMap::new(4, 3, GraphicalTerrain::Plain);
+
+        assert_eq!(
+            map.positions_in_rect(Position::new(1, 1), 2, 2)
+                .collect::<Vec<_>>(),
+            [
+                Position::new(1, 1),
+                Position::new(2, 1),
+                Position::new(1, 2),
+                Position::new(2, 2),
+            ]
+        );
+        // Clipped to the map edge.
+        assert_eq!(
+            map.positions_in_rect(Position::new(3, 2), 5, 5)
+                .collect::<Vec<_>>(),
+            [Position::new(3, 2)]
+        );
+        assert_eq!(map.positions_in_rect(Position::new(4, 0), 1, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_gameplay_grid() {
+        let mut map = AwbrnMap::new(3, 2, GraphicalTerrain::Plain);
+        map.set_terrain(Position::new(1, 0), GraphicalTerrain::Mountain);
+        map.set_terrain(Position::new(2, 0), GraphicalTerrain::StubbyMoutain);
+        map.set_terrain(
+            Position::new(0, 1),
+            GraphicalTerrain::Property(Property::HQ(PlayerFaction::OrangeStar)),
+        );
+        map.set_terrain(
+            Position::new(2, 1),
+            GraphicalTerrain::Sea(SeaDirection::N_E_S_W),
+        );
+
+        let expected = map
+            .positions()
+            .map(|pos| map.terrain_at(pos).unwrap().as_terrain().gameplay_type())
+            .collect::<Vec<_>>();
+        let grid = map.gameplay_grid();
+        assert_eq!(grid, expected);
+        assert_eq!(grid[1], grid[2]);
+        assert_eq!(grid[1], GameplayTerrain::Mountain);
+    }
+
+    #[test]
+    fn test_bridge_spans_water() {
+        // A horizontal bridge carrying a road across a vertical river
+        let map = AwbrnMap::from_awbw_text("1,5,1\n1,26,1\n1,5,1\n").unwrap();
+        let bridge = map.terrain_at(Position::new(1, 1)).unwrap();
+        assert_eq!(bridge, GraphicalTerrain::Bridge(BridgeType::Horizontal));
+        assert_eq!(bridge.as_terrain().gameplay_type(), GameplayTerrain::Bridge);
+
+        // AWBW bridge sprites include the water beneath them, so the
+        // surrounding sea must autotile as if the bridge were open water
+        // rather than drawing shoreline against it.
+        let bridged = AwbrnMap::from_awbw_text("28,28,28\n28,26,28\n28,28,28\n").unwrap();
+        let open = AwbrnMap::from_awbw_text("28,28,28\n28,28,28\n28,28,28\n").unwrap();
+        for pos in bridged
+            .positions()
+            .filter(|&pos| pos != Position::new(1, 1))
+        {
+            assert_eq!(bridged.terrain_at(pos), open.terrain_at(pos), "{pos:?}");
+        }
+    }
+
+    #[test]
+    fn test_terrain_at_rejects_columns_past_the_edge() {
+        let mut map = AwbrnMap::new(2, 2, GraphicalTerrain::Plain);
+        map.set_terrain(Position::new(0, 1), GraphicalTerrain::Mountain);
+
+        assert_eq!(
+            map.terrain_at(Position::new(0, 1)),
+            Some(GraphicalTerrain::Mountain)
+        );
+        // Row-major indexing would otherwise wrap onto the next row.
+        assert_eq!(map.terrain_at(Position::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_move_cost() {
+        let mut map = AwbrnMap::new(2, 1, GraphicalTerrain::Plain);
+        let mountain = Position::new(1, 0);
+        map.set_terrain(mountain, GraphicalTerrain::Mountain);
+
+        assert_eq!(
+            map.move_cost(mountain, Unit::Infantry, Weather::Clear),
+            Some(2)
+        );
+        assert_eq!(map.move_cost(mountain, Unit::Tank, Weather::Clear), None);
+        assert_eq!(
+            map.move_cost(Position::new(0, 0), Unit::Recon, Weather::Clear),
+            Some(2)
+        );
+        assert_eq!(
+            map.move_cost(Position::new(2, 0), Unit::Infantry, Weather::Clear),
+            None
+        );
+        assert_eq!(
+            map.move_cost(mountain, Unit::Infantry, Weather::Snow),
+            Some(4)
+        );
+        assert_eq!(
+            map.move_cost(Position::new(0, 0), Unit::Recon, Weather::Rain),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_units_that_can_reach() {
+        let map = AwbrnMap::new(8, 1, GraphicalTerrain::Plain);
+        let infantry = (Position::new(0, 0), Unit::Infantry);
+        let tank = (Position::new(1, 0), Unit::Tank);
+        let units = [infantry, tank];
+
+        // Six tiles out is beyond the infantry's three movement points
+        assert_eq!(
+            map.units_that_can_reach(Position::new(6, 0), &units, Weather::Clear),
+            vec![(Position::new(1, 0), 5)]
+        );
+        assert_eq!(
+            map.units_that_can_reach(Position::new(3, 0), &units, Weather::Clear),
+            vec![(Position::new(0, 0), 3), (Position::new(1, 0), 2)]
+        );
+        assert!(
+            map.units_that_can_reach(Position::new(8, 0), &units, Weather::Clear)
+                .is_empty()
+        );
+
+        // Snow doubles the cost of plains for both foot and treads
+        assert_eq!(
+            map.units_that_can_reach(Position::new(3, 0), &units, Weather::Snow),
+            vec![(Position::new(1, 0), 4)]
+        );
+    }
+
+    #[test]
+    fn test_checksum() {
+        let map = AwbrnMap::new(3, 2, GraphicalTerrain::Plain);
+        let same = AwbrnMap::new(3, 2, GraphicalTerrain::Plain);
+        assert_eq!(map.checksum(), same.checksum());
+
+        let mut changed = map.clone();
+        changed.set_terrain(Position::new(2, 1), GraphicalTerrain::Mountain);
+        assert_ne!(map.checksum(), changed.checksum());
+
+        let transposed = AwbrnMap::new(2, 3, GraphicalTerrain::Plain);
+        assert_ne!(map.checksum(), transposed.checksum());
+
+        let mut stubby = map.clone();
+        stubby.set_terrain(Position::new(2, 1), GraphicalTerrain::StubbyMoutain);
+        assert_ne!(changed.checksum(), stubby.checksum());
+
+        // Pinned so that a change to the hashed bytes is caught.
+        assert_eq!(map.checksum(), 0x9152_aa49_9d54_02ec);
+    }
+
+    #[test]
+    fn test_com_tower_count() {
+        let orange_star = Faction::Player(PlayerFaction::OrangeStar);
+        let mut map = AwbrnMap::new(3, 1, GraphicalTerrain::Plain);
+        for x in 0..2 {
+            map.set_terrain(
+                Position::new(x, 0),
+                GraphicalTerrain::Property(Property::ComTower(orange_star)),
+            );
+        }
+        map.set_terrain(
+            Position::new(2, 0),
+            GraphicalTerrain::Property(Property::ComTower(Faction::Neutral)),
+        );
+
+        assert_eq!(map.com_tower_count(orange_star), 2);
+        assert_eq!(map.com_tower_count(Faction::Neutral), 1);
+        assert_eq!(
+            map.com_tower_count(Faction::Player(PlayerFaction::BlueMoon)),
+            0
+        );
+    }
+
     #[test]
     fn test_specific_stubby_mountains() {
         // Construct path to the map file - using path relative to workspace root
@@ -99,6 +99,22 @@ impl AwbwMap {
         AwbwMap::try_from(&map_data)
     }
 
+    /// Serializes the map in the AWBW text format accepted by [`Self::parse_txt`]:
+    /// one row per line with comma-separated terrain ids.
+    pub fn to_awbw_text(&self) -> String {
+        let mut out = String::new();
+        for row in self.terrain.chunks(self.width.max(1)) {
+            for (col_idx, terrain) in row.iter().enumerate() {
+                if col_idx > 0 {
+                    out.push(',');
+                }
+                out.push_str(&u8::from(terrain.id()).to_string());
+            }
+            out.push('\n');
+        }
+        out
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -258,6 +274,14 @@ mod tests {
     use super::*;
     use crate::MapError;
 
+    #[test]
+    fn test_to_awbw_text_round_trips() {
+        let map = AwbwMap::parse_txt("1,2,3\n28,34,195").unwrap();
+        let text = map.to_awbw_text();
+        assert_eq!(text, "1,2,3\n28,34,195\n");
+        assert_eq!(AwbwMap::parse_txt(&text).unwrap(), map);
+    }
+
     #[test]
     fn test_parse_empty_input() {
         let result = AwbwMap::parse_txt("");
@@ -0,0 +1,207 @@
+use crate::pathfinding::MovementTypeCosts;
+use crate::{AwbrnMap, Position};
+use awbrn_types::{Faction, GraphicalTerrain, PlayerFaction, Property, UnitMovement};
+
+/// Largest distance the pathfinder can report; its cost map reserves
+/// `u8::MAX` for unvisited tiles.
+const MAX_DISTANCE: u8 = u8::MAX - 1;
+
+/// Fairness figures mapmakers use to vet a map, one entry per faction that
+/// owns a property, in the order each faction first appears (row-major).
+///
+/// Distances are infantry movement costs measured outward from each HQ and
+/// cap out at 254; anything further is treated as unreachable.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BalanceReport {
+    pub factions: Vec<FactionBalance>,
+}
+
+impl BalanceReport {
+    /// The entry for `faction`, if it owns any property on the map.
+    pub fn faction(&self, faction: PlayerFaction) -> Option<&FactionBalance> {
+        self.factions
+            .iter()
+            .find(|balance| balance.faction == faction)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FactionBalance {
+    pub faction: PlayerFaction,
+
+    /// Location of the faction's HQ, if it has one
+    pub hq: Option<Position>,
+
+    /// Number of properties owned at the start, including the HQ
+    pub properties: u32,
+
+    /// Infantry movement cost from the HQ to the nearest enemy HQ, or `None`
+    /// when there is no HQ or no enemy HQ can be reached
+    pub nearest_enemy_hq: Option<u32>,
+
+    /// Neutral properties strictly closer to this faction's HQ than to any
+    /// other HQ. Ties count for nobody.
+    pub nearer_neutral_properties: u32,
+}
+
+impl BalanceReport {
+    pub(crate) fn new(map: &AwbrnMap) -> Self {
+        let mut factions: Vec<FactionBalance> = Vec::new();
+        let mut neutral_properties = Vec::new();
+
+        for (pos, terrain) in map.iter() {
+            let GraphicalTerrain::Property(property) = terrain else {
+                continue;
+            };
+
+            let owner = match property.faction() {
+                Faction::Player(owner) => owner,
+                Faction::Neutral => {
+                    neutral_properties.push(pos);
+                    continue;
+                }
+            };
+
+            let index = match factions.iter().position(|x| x.faction == owner) {
+                Some(index) => index,
+                None => {
+                    factions.push(FactionBalance {
+                        faction: owner,
+                        hq: None,
+                        properties: 0,
+                        nearest_enemy_hq: None,
+                        nearer_neutral_properties: 0,
+                    });
+                    factions.len() - 1
+                }
+            };
+
+            let balance = &mut factions[index];
+            balance.properties += 1;
+            if matches!(property, Property::HQ(_)) && balance.hq.is_none() {
+                balance.hq = Some(pos);
+            }
+        }
+
+        // Distance from each HQ to every tile, indexed by row-major position
+        let mut pathfinder = map.pathfinder();
+        let distances: Vec<Option<Vec<Option<u8>>>> = factions
+            .iter()
+            .map(|balance| {
+                let hq = balance.hq?;
+                let mut tiles = vec![None; map.width() * map.height()];
+                for (pos, cost) in pathfinder
+                    .reachable(hq, MAX_DISTANCE, MovementTypeCosts::new(UnitMovement::Foot))
+                    .into_positions()
+                {
+                    tiles[pos.y * map.width() + pos.x] = Some(cost);
+                }
+                Some(tiles)
+            })
+            .collect();
+
+        let distance = |index: usize, pos: Position| -> Option<u8> {
+            distances[index].as_ref()?[pos.y * map.width() + pos.x]
+        };
+
+        for index in 0..factions.len() {
+            factions[index].nearest_enemy_hq = factions
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .filter_map(|(_, enemy)| enemy.hq)
+                .filter_map(|enemy_hq| distance(index, enemy_hq))
+                .min()
+                .map(u32::from);
+        }
+
+        for pos in neutral_properties {
+            let mut nearest: Option<(usize, u8)> = None;
+            let mut tied = false;
+            for index in 0..factions.len() {
+                let Some(cost) = distance(index, pos) else {
+                    continue;
+                };
+
+                match nearest {
+                    Some((_, best)) if cost > best => {}
+                    Some((_, best)) if cost == best => tied = true,
+                    _ => {
+                        nearest = Some((index, cost));
+                        tied = false;
+                    }
+                }
+            }
+
+            if let Some((index, _)) = nearest.filter(|_| !tied) {
+                factions[index].nearer_neutral_properties += 1;
+            }
+        }
+
+        Self { factions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use awbrn_types::SeaDirection;
+
+    fn place(map: &mut AwbrnMap, x: usize, y: usize, property: Property) {
+        map.set_terrain(Position::new(x, y), GraphicalTerrain::Property(property));
+    }
+
+    #[test]
+    fn test_balance_report_skewed_toward_closer_hq() {
+        let orange_star = PlayerFaction::OrangeStar;
+        let blue_moon = PlayerFaction::BlueMoon;
+
+        // OS HQ on the far left, BM HQ on the far right, with the neutral
+        // cities bunched on the Orange Star side of the midpoint.
+        let mut map = AwbrnMap::new(7, 2, GraphicalTerrain::Plain);
+        place(&mut map, 0, 0, Property::HQ(orange_star));
+        place(&mut map, 6, 0, Property::HQ(blue_moon));
+        place(&mut map, 0, 1, Property::Base(Faction::Player(orange_star)));
+        place(&mut map, 1, 0, Property::City(Faction::Neutral));
+        place(&mut map, 2, 0, Property::City(Faction::Neutral));
+        place(&mut map, 2, 1, Property::City(Faction::Neutral));
+        place(&mut map, 5, 1, Property::City(Faction::Neutral));
+        // Equidistant, so it counts for neither side
+        place(&mut map, 3, 0, Property::City(Faction::Neutral));
+
+        let report = map.balance_report();
+        assert_eq!(
+            report.factions,
+            vec![
+                FactionBalance {
+                    faction: orange_star,
+                    hq: Some(Position::new(0, 0)),
+                    properties: 2,
+                    nearest_enemy_hq: Some(6),
+                    nearer_neutral_properties: 3,
+                },
+                FactionBalance {
+                    faction: blue_moon,
+                    hq: Some(Position::new(6, 0)),
+                    properties: 1,
+                    nearest_enemy_hq: Some(6),
+                    nearer_neutral_properties: 1,
+                },
+            ]
+        );
+        assert_eq!(report.faction(blue_moon).map(|x| x.properties), Some(1));
+        assert!(report.faction(PlayerFaction::GreenEarth).is_none());
+    }
+
+    #[test]
+    fn test_balance_report_unreachable_enemy_hq() {
+        let mut map = AwbrnMap::new(3, 1, GraphicalTerrain::Plain);
+        place(&mut map, 0, 0, Property::HQ(PlayerFaction::OrangeStar));
+        place(&mut map, 2, 0, Property::HQ(PlayerFaction::BlueMoon));
+        map.set_terrain(Position::new(1, 0), GraphicalTerrain::Sea(SeaDirection::E));
+
+        let report = map.balance_report();
+        assert_eq!(report.factions.len(), 2);
+        assert!(report.factions.iter().all(|x| x.nearest_enemy_hq.is_none()));
+    }
+}
@@ -1,11 +1,13 @@
 mod awbrn_map;
 mod awbw_map;
+mod balance;
 mod map_error;
 mod pathfinding;
 mod position;
 
 pub use awbrn_map::AwbrnMap;
 pub use awbw_map::{AwbwMap, AwbwMapData};
+pub use balance::{BalanceReport, FactionBalance};
 pub use map_error::MapError;
-pub use pathfinding::{MovementMap, PathFinder, TerrainCosts};
+pub use pathfinding::{MovementMap, MovementTypeCosts, PathFinder, TerrainCosts};
 pub use position::Position;
@@ -1,5 +1,5 @@
 use crate::Position;
-use awbrn_types::MovementTerrain;
+use awbrn_types::{MovementTerrain, UnitMovement, Weather, movement_cost_in_weather};
 
 /// A trait for maps that provide terrain information for pathfinding
 pub trait MovementMap {
@@ -52,6 +52,34 @@ impl<T: TerrainCosts> TerrainCosts for &'_ T {
     }
 }
 
+/// Standard terrain costs for a movement type, without CO effects. Costs are
+/// for clear weather unless overridden with [`MovementTypeCosts::with_weather`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MovementTypeCosts {
+    movement: UnitMovement,
+    weather: Weather,
+}
+
+impl MovementTypeCosts {
+    pub fn new(movement: UnitMovement) -> Self {
+        Self {
+            movement,
+            weather: Weather::Clear,
+        }
+    }
+
+    /// Use the costs of `weather` instead of clear weather
+    pub fn with_weather(self, weather: Weather) -> Self {
+        Self { weather, ..self }
+    }
+}
+
+impl TerrainCosts for MovementTypeCosts {
+    fn cost(&self, terrain: MovementTerrain) -> Option<u8> {
+        movement_cost_in_weather(terrain, self.movement, self.weather)
+    }
+}
+
 pub struct Reachable<'a, M> {
     map: &'a mut PathFinder<M>,
     map_width: usize,
@@ -215,6 +243,65 @@ impl<M: MovementMap> PathFinder<M> {
         }
     }
 
+    /// Find a cheapest path from `start` to `goal` within the given movement
+    /// points. Returns the path (including both endpoints) and its total
+    /// movement cost, or `None` if the goal is unreachable.
+    pub fn find_path(
+        &mut self,
+        start: Position,
+        goal: Position,
+        movement_points: u8,
+        costs: impl TerrainCosts,
+    ) -> Option<(Vec<Position>, u32)> {
+        self.reachable(start, movement_points, &costs);
+
+        let map_width = self.map.width();
+        let map_height = self.map.height();
+        if goal.x >= map_width || goal.y >= map_height {
+            return None;
+        }
+
+        let goal_idx = goal.y * map_width + goal.x;
+        let total_cost = self.cost_map[goal_idx];
+        if total_cost == u8::MAX {
+            return None;
+        }
+
+        // Walk back from the goal, stepping to any neighbor whose cost plus the
+        // cost of entering the current tile accounts for the current tile's cost.
+        let start_idx = start.y * map_width + start.x;
+        let mut path = vec![goal_idx];
+        let mut current = goal_idx;
+        while current != start_idx {
+            let entry_cost = costs.cost(self.map.terrain_at_flat(current))?;
+            let current_cost = self.cost_map[current];
+            let x = current % map_width;
+            let y = current / map_width;
+
+            let neighbors = [
+                (x + 1 < map_width).then(|| current + 1),
+                (x > 0).then(|| current - 1),
+                (y + 1 < map_height).then(|| current + map_width),
+                (y > 0).then(|| current - map_width),
+            ];
+
+            current = neighbors.into_iter().flatten().find(|&neighbor| {
+                let neighbor_cost = self.cost_map[neighbor];
+                neighbor_cost != u8::MAX
+                    && neighbor_cost as usize + entry_cost as usize == current_cost as usize
+                    && !path.contains(&neighbor)
+            })?;
+            path.push(current);
+        }
+
+        let path = path
+            .into_iter()
+            .rev()
+            .map(|idx| Position::new(idx % map_width, idx / map_width))
+            .collect();
+        Some((path, u32::from(total_cost)))
+    }
+
     /// edge relaxation
     #[inline(always)]
     fn relax_neighbor(
@@ -422,6 +509,31 @@ mod tests {
         assert_positions_with_costs(&positions, &expected_positions);
     }
 
+    #[test]
+    fn test_snow_shrinks_reachable_tiles_over_mountains() {
+        let mut map = AwbwMap::new(5, 1, AwbwTerrain::Mountain);
+        *map.terrain_at_mut(Position::new(0, 0)).unwrap() = AwbwTerrain::Plain;
+        let start = Position::new(0, 0);
+        let costs = MovementTypeCosts::new(UnitMovement::Foot);
+
+        let mut pathfinder = PathFinder::new(&map);
+        let clear: HashMap<Position, u8> = pathfinder
+            .reachable(start, 4, costs)
+            .into_positions()
+            .collect();
+        let snow: HashMap<Position, u8> = pathfinder
+            .reachable(start, 4, costs.with_weather(Weather::Snow))
+            .into_positions()
+            .collect();
+
+        // Mountains cost foot soldiers 2 in clear weather and 4 in snow
+        assert_eq!(clear.len(), 3);
+        assert_eq!(clear.get(&Position::new(2, 0)), Some(&4));
+        assert_eq!(snow.len(), 2);
+        assert_eq!(snow.get(&Position::new(1, 0)), Some(&4));
+        assert!(snow.keys().all(|pos| clear.contains_key(pos)));
+    }
+
     #[test]
     fn test_unreachable_terrain() {
         let mut map = AwbwMap::new(3, 3, AwbwTerrain::Sea);
@@ -493,6 +605,54 @@ mod tests {
         assert!(!positions.contains_key(&Position::new(4, 0)));
     }
 
+    #[test]
+    fn test_find_path_cost_matches_summed_terrain_costs() {
+        // Plain row with a forest and a mountain in the way; the row below is
+        // all sea so the foot unit has to cross them.
+        let mut map = AwbwMap::new(4, 2, AwbwTerrain::Sea);
+        *map.terrain_at_mut(Position::new(0, 0)).unwrap() = AwbwTerrain::Plain;
+        *map.terrain_at_mut(Position::new(1, 0)).unwrap() = AwbwTerrain::Wood;
+        *map.terrain_at_mut(Position::new(2, 0)).unwrap() = AwbwTerrain::Mountain;
+        *map.terrain_at_mut(Position::new(3, 0)).unwrap() = AwbwTerrain::Plain;
+
+        let costs = UnitMovementCosts {
+            movement_type: UnitMovement::Foot,
+        };
+        let mut pathfinder = PathFinder::new(&map);
+        let (path, cost) = pathfinder
+            .find_path(Position::new(0, 0), Position::new(3, 0), 6, &costs)
+            .expect("goal should be reachable");
+
+        assert_eq!(
+            path,
+            vec![
+                Position::new(0, 0),
+                Position::new(1, 0),
+                Position::new(2, 0),
+                Position::new(3, 0),
+            ]
+        );
+
+        let summed: u32 = path[1..]
+            .iter()
+            .map(|&pos| {
+                let terrain = map.terrain_at(pos).map(MovementTerrain::from).unwrap();
+                u32::from(costs.cost(terrain).unwrap())
+            })
+            .sum();
+        assert_eq!(cost, summed);
+        assert_eq!(cost, 1 + 1 + 2);
+
+        assert_eq!(
+            pathfinder.find_path(Position::new(0, 0), Position::new(3, 0), 3, &costs),
+            None
+        );
+        assert_eq!(
+            pathfinder.find_path(Position::new(0, 0), Position::new(0, 1), 6, &costs),
+            None
+        );
+    }
+
     #[test]
     fn test_pathfinder_reuse() {
         let map = AwbwMap::new(5, 5, AwbwTerrain::Plain);
@@ -1,3 +1,5 @@
+use awbrn_types::AwbwTerrain;
+
 #[test]
 fn txt_json_equivalency() {
     let json_data = std::fs::read("../../assets/maps/162795.json").unwrap();
@@ -16,3 +18,26 @@ fn txt_json_equivalency() {
 
     assert_eq!(json_map, txt_map);
 }
+
+#[test]
+fn awbrn_map_exports_to_parseable_awbw_text() {
+    let txt_data = std::fs::read_to_string("../../assets/maps/162795.txt").unwrap();
+    let txt_map = awbrn_map::AwbwMap::parse_txt(&txt_data[..]).unwrap();
+
+    let exported = awbrn_map::AwbrnMap::from_map(&txt_map)
+        .to_awbw_map()
+        .to_awbw_text();
+    let reparsed = awbrn_map::AwbwMap::parse_txt(&exported).unwrap();
+
+    assert_eq!(reparsed.width(), txt_map.width());
+    assert_eq!(reparsed.height(), txt_map.height());
+
+    // Shoal orientation is purely cosmetic and recomputed from neighbors, so
+    // it does not survive the round trip.
+    for ((pos, exported), (_, original)) in reparsed.iter().zip(txt_map.iter()) {
+        match (exported, original) {
+            (AwbwTerrain::Shoal(_), AwbwTerrain::Shoal(_)) => {}
+            _ => assert_eq!(exported, original, "Terrain mismatch at {:?}", pos),
+        }
+    }
+}
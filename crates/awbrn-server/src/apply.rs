@@ -2,7 +2,9 @@ use bevy::prelude::*;
 
 use crate::adjacency::adjacent_positions;
 use crate::command::{GameCommand, PostMoveAction};
-use crate::damage::{CombatInput, CombatOutcome, CombatSide, LuckCap, PercentMod, TerrainStars};
+use crate::damage::{
+    CombatInput, CombatOutcome, CombatSide, LuckCap, PercentMod, TerrainStars, attack_mod,
+};
 use crate::player::PlayerRegistry;
 use crate::replay::ReplayEventError;
 use crate::server::spawn_unit_entity;
@@ -15,7 +17,7 @@ use awbrn_game::world::{
     CarriedBy, Fuel, GameMap, GraphicalHp, Hiding, StrongIdMap, UnitActive, UnitHp,
 };
 use awbrn_game::world::{Faction, Unit};
-use awbrn_types::{DamagePts, ExactHp, PlayerFaction};
+use awbrn_types::{DamagePts, ExactHp, Faction as TerrainFaction, PlayerFaction};
 
 /// The set of world mutations that occurred from applying a command.
 /// Used by the view layer to build per-player updates.
@@ -688,13 +690,15 @@ fn apply_attack(
         .terrain_at(target)
         .map(|t| t.defense_stars())
         .unwrap_or(0);
+    let attacker_com_towers = game_map.com_tower_count(TerrainFaction::Player(attacker_faction));
+    let defender_com_towers = game_map.com_tower_count(TerrainFaction::Player(defender_faction));
 
     // Build the combat input.
     let input = CombatInput {
         attacker: CombatSide {
             unit_type: attacker_unit.0,
             exact_hp: attacker_exact_hp,
-            attack_mod: PercentMod::new(100 + attacker_co_stats.attack_bonus),
+            attack_mod: attack_mod(attacker_co_stats.attack_bonus, attacker_com_towers),
             defense_mod: PercentMod::new(100 + attacker_co_stats.defense_bonus),
             max_good_luck: LuckCap::new(attacker_co_stats.max_good_luck),
             max_bad_luck: LuckCap::new(attacker_co_stats.max_bad_luck),
@@ -704,7 +708,7 @@ fn apply_attack(
         defender: CombatSide {
             unit_type: defender_unit.0,
             exact_hp: defender_exact_hp,
-            attack_mod: PercentMod::new(100 + defender_co_stats.attack_bonus),
+            attack_mod: attack_mod(defender_co_stats.attack_bonus, defender_com_towers),
             defense_mod: PercentMod::new(100 + defender_co_stats.defense_bonus),
             max_good_luck: LuckCap::new(defender_co_stats.max_good_luck),
             max_bad_luck: LuckCap::new(defender_co_stats.max_bad_luck),
@@ -32,6 +32,15 @@ impl PercentMod {
     }
 }
 
+/// Firepower bonus, in percentage points, granted per owned com tower.
+pub const COM_TOWER_ATTACK_BONUS: i32 = 10;
+
+/// Attack modifier for a side with the given CO attack bonus whose owner
+/// holds `com_towers` com towers.
+pub fn attack_mod(co_attack_bonus: i32, com_towers: u32) -> PercentMod {
+    PercentMod::new(100 + co_attack_bonus + com_towers as i32 * COM_TOWER_ATTACK_BONUS)
+}
+
 /// Inclusive upper bound passed to the combat luck roll.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LuckCap(u8);
@@ -487,6 +496,38 @@ mod tests {
         assert_eq!(outcome.defender_damage_pts, Some(27));
     }
 
+    #[test]
+    fn two_com_towers_add_twenty_percent_firepower() {
+        let defender = side(Unit::Infantry, 100, 0, 0);
+        let mut attacker = side(Unit::Infantry, 100, 0, 0);
+        attacker.attack_mod = attack_mod(0, 2);
+        assert_eq!(attacker.attack_mod, PercentMod::new(120));
+
+        let without_towers = calculate_combat(
+            &CombatInput {
+                attacker: side(Unit::Infantry, 100, 0, 0),
+                defender,
+                is_direct_combat: false,
+            },
+            LuckDelta::none(),
+            LuckDelta::none(),
+        )
+        .unwrap();
+        let with_towers = calculate_combat(
+            &CombatInput {
+                attacker,
+                defender,
+                is_direct_combat: false,
+            },
+            LuckDelta::none(),
+            LuckDelta::none(),
+        )
+        .unwrap();
+
+        assert_eq!(without_towers.attacker_damage_pts, 55);
+        assert_eq!(with_towers.attacker_damage_pts, 66);
+    }
+
     #[test]
     fn tank_with_no_ammo_uses_secondary_weapon() {
         assert_eq!(base_damage(Unit::Tank, Unit::Infantry, 0), Some(75));
@@ -1018,6 +1018,48 @@ fn attack_reduces_hp_without_killing() {
     );
 }
 
+#[test]
+fn com_towers_increase_attack_damage() {
+    // Each owned com tower adds 10% firepower; the seeded luck roll is the
+    // same for both games.
+    let attack_damage = |towers: usize| {
+        let mut setup = two_player_setup(5, 5);
+        for x in 0..towers {
+            set_property(
+                &mut setup,
+                Position::new(x, 4),
+                Property::ComTower(TerrainFaction::Player(PlayerFaction::OrangeStar)),
+            );
+        }
+        let mut server = GameServer::new(setup).unwrap();
+        let attacker = server.spawn_unit(
+            Position::new(0, 0),
+            awbrn_types::Unit::Infantry,
+            PlayerFaction::OrangeStar,
+        );
+        server.spawn_unit(
+            Position::new(1, 0),
+            awbrn_types::Unit::Infantry,
+            PlayerFaction::BlueMoon,
+        );
+
+        let result = server
+            .submit_command(
+                p1(),
+                attack_command(attacker, vec![Position::new(0, 0)], Position::new(1, 0)),
+            )
+            .unwrap();
+        result.combat_outcome.unwrap().attacker_damage_pts
+    };
+
+    let without_towers = attack_damage(0);
+    let with_towers = attack_damage(2);
+    assert!(
+        with_towers > without_towers,
+        "{with_towers} should exceed {without_towers}"
+    );
+}
+
 #[test]
 fn indirect_unit_cannot_attack_after_moving() {
     // Artillery is indirect: cannot move then attack.
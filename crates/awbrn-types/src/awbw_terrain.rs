@@ -4,7 +4,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::{
     BridgeType, Faction, GameplayTerrain, MissileSiloStatus, PipeRubbleType, PipeSeamType,
-    PipeType, PlayerFaction, Property, RiverType, RoadType, ShoalType,
+    PipeType, PlayerFaction, Property, RiverType, RoadType, ShoalType, Unit,
 };
 
 /// Main terrain type enum that categorizes terrain by its primary function
@@ -183,6 +183,11 @@ impl AwbwTerrain {
         }
     }
 
+    /// Look up the terrain drawn with `symbol`, the inverse of [`Self::symbol`]
+    pub fn from_symbol(symbol: char) -> Option<AwbwTerrain> {
+        AwbwTerrain::all().find(|terrain| terrain.symbol() == Some(symbol))
+    }
+
     /// Get the name of this terrain
     pub const fn name(&self) -> &'static str {
         match self {
@@ -258,6 +263,12 @@ impl AwbwTerrain {
         }
     }
 
+    /// Every valid AWBW terrain, including each faction's properties, in
+    /// terrain ID order.
+    pub fn all() -> impl Iterator<Item = AwbwTerrain> {
+        (0..=u8::MAX).filter_map(|id| AwbwTerrain::try_from(id).ok())
+    }
+
     /// Get the ID of terrain
     pub fn id(&self) -> AwbwTerrainId {
         AwbwTerrainId::from(*self)
@@ -329,6 +340,38 @@ impl AwbwTerrain {
         matches!(self, AwbwTerrain::Property(_))
     }
 
+    /// Check if terrain can be captured by units of `faction`: any property
+    /// not already owned by that faction
+    pub fn is_capturable_by(&self, faction: Faction) -> bool {
+        self.owner().is_some_and(|owner| owner != faction)
+    }
+
+    /// Funds this terrain generates each turn for its owner, as per
+    /// [`Property::income`]. Non-properties yield nothing.
+    pub fn income(&self, funds_per_property: u32) -> u32 {
+        match self {
+            AwbwTerrain::Property(property) => property.income(funds_per_property),
+            _ => 0,
+        }
+    }
+
+    /// Whether this terrain repairs `unit`, as per [`Property::repairs`].
+    /// Non-properties repair nothing.
+    pub fn repairs(&self, unit: Unit) -> bool {
+        match self {
+            AwbwTerrain::Property(property) => property.repairs(unit),
+            _ => false,
+        }
+    }
+
+    /// HP restored each turn to units this terrain repairs
+    pub fn heal_amount(&self) -> u8 {
+        match self {
+            AwbwTerrain::Property(property) => property.heal_amount(),
+            _ => 0,
+        }
+    }
+
     /// Get the gameplay-relevant terrain type
     pub fn gameplay_type(&self) -> GameplayTerrain {
         match self {
@@ -359,6 +402,12 @@ impl AwbwTerrain {
     }
 }
 
+impl fmt::Display for AwbwTerrain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 /// Custom deserializer implementation to handle deserializing terrain from numeric IDs
 impl<'de> Deserialize<'de> for AwbwTerrain {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -391,6 +440,20 @@ impl AwbwTerrainId {
     pub const fn new(id: u8) -> Self {
         Self(id)
     }
+
+    /// Whether `id` names an AWBW terrain.
+    ///
+    /// Valid ids are 1–57, 81–176 and 181–223. AWBW leaves 0, 58–80,
+    /// 177–180 and everything above 223 unassigned.
+    pub fn is_valid(id: u8) -> bool {
+        AwbwTerrain::try_from(id).is_ok()
+    }
+}
+
+impl From<AwbwTerrainId> for u8 {
+    fn from(id: AwbwTerrainId) -> Self {
+        id.0
+    }
 }
 
 impl From<AwbwTerrain> for AwbwTerrainId {
@@ -1292,6 +1355,46 @@ impl TryFrom<u8> for AwbwTerrain {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_income() {
+        let owned = Faction::Player(PlayerFaction::OrangeStar);
+
+        assert_eq!(
+            AwbwTerrain::Property(Property::City(owned)).income(1000),
+            1000
+        );
+        assert_eq!(
+            AwbwTerrain::Property(Property::HQ(PlayerFaction::BlueMoon)).income(1000),
+            1000
+        );
+        assert_eq!(
+            AwbwTerrain::Property(Property::Port(owned)).income(2000),
+            2000
+        );
+        assert_eq!(
+            AwbwTerrain::Property(Property::City(Faction::Neutral)).income(1000),
+            0
+        );
+        assert_eq!(
+            AwbwTerrain::Property(Property::ComTower(owned)).income(1000),
+            0
+        );
+        assert_eq!(AwbwTerrain::Property(Property::Lab(owned)).income(1000), 0);
+        assert_eq!(AwbwTerrain::Mountain.income(1000), 0);
+    }
+
+    #[test]
+    fn test_terrain_repairs() {
+        let port = AwbwTerrain::Property(Property::Port(Faction::Player(PlayerFaction::BlueMoon)));
+        assert!(port.repairs(Unit::Sub));
+        assert!(!port.repairs(Unit::Infantry));
+        assert_eq!(port.heal_amount(), 2);
+
+        assert!(!AwbwTerrain::Plain.repairs(Unit::Infantry));
+        assert!(!AwbwTerrain::Sea.repairs(Unit::Sub));
+        assert_eq!(AwbwTerrain::Plain.heal_amount(), 0);
+    }
+
     #[test]
     fn test_missile_silo_status() {
         // Test conversion from TerrainType to Terrain ID
@@ -1315,6 +1418,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_display_matches_name() {
+        let terrains = [
+            AwbwTerrain::Plain,
+            AwbwTerrain::River(RiverType::Horizontal),
+            AwbwTerrain::Property(Property::HQ(PlayerFaction::BlueMoon)),
+            AwbwTerrain::MissileSilo(MissileSiloStatus::Unloaded),
+        ];
+        for terrain in terrains {
+            assert_eq!(format!("{}", terrain), terrain.name());
+        }
+
+        let property = Property::City(Faction::Player(PlayerFaction::OrangeStar));
+        assert_eq!(format!("{}", property), property.name());
+        assert_eq!(property.to_string(), "Orange Star City");
+    }
+
     #[test]
     fn test_terrain_type_name() {
         // Test basic terrain names
@@ -1503,6 +1623,26 @@ mod tests {
         assert!(!AwbwTerrain::Sea.is_capturable());
     }
 
+    #[test]
+    fn test_terrain_is_capturable_by() {
+        let orange_star = Faction::Player(PlayerFaction::OrangeStar);
+        let blue_moon = Faction::Player(PlayerFaction::BlueMoon);
+
+        let neutral_city = AwbwTerrain::Property(Property::City(Faction::Neutral));
+        assert!(neutral_city.is_capturable_by(orange_star));
+        assert!(neutral_city.is_capturable_by(blue_moon));
+
+        let own_city = AwbwTerrain::Property(Property::City(orange_star));
+        assert!(!own_city.is_capturable_by(orange_star));
+        assert!(own_city.is_capturable_by(blue_moon));
+
+        let enemy_hq = AwbwTerrain::Property(Property::HQ(PlayerFaction::BlueMoon));
+        assert!(enemy_hq.is_capturable_by(orange_star));
+        assert!(!enemy_hq.is_capturable_by(blue_moon));
+
+        assert!(!AwbwTerrain::Plain.is_capturable_by(orange_star));
+    }
+
     #[test]
     fn test_terrain_symbol() {
         // Test symbols for various terrain types
@@ -1522,6 +1662,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_terrain_from_symbol_round_trips() {
+        for terrain in AwbwTerrain::all() {
+            if let Some(symbol) = terrain.symbol() {
+                assert_eq!(AwbwTerrain::from_symbol(symbol), Some(terrain));
+            }
+        }
+
+        assert_eq!(AwbwTerrain::from_symbol('.'), Some(AwbwTerrain::Plain));
+        assert_eq!(AwbwTerrain::from_symbol('\u{1F600}'), None);
+    }
+
     #[test]
     fn test_gameplay_terrain_type() {
         // Test that gameplay type correctly abstracts visual differences
@@ -1677,4 +1829,51 @@ mod tests {
         let result: Result<TerrainCell, _> = from_str(json);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_all_terrains_round_trip_through_id() {
+        let all = AwbwTerrain::all().collect::<Vec<_>>();
+        assert_eq!(all.len(), 196);
+
+        let unique = all.iter().collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), all.len());
+
+        for terrain in all {
+            let id = u8::from(terrain.id());
+            assert_eq!(AwbwTerrain::try_from(id), Ok(terrain), "terrain id {id}");
+        }
+    }
+
+    #[test]
+    fn test_all_terrains_match_try_from_table() {
+        let valid = (0..=u8::MAX)
+            .filter(|&id| AwbwTerrain::try_from(id).is_ok())
+            .count();
+        assert_eq!(AwbwTerrain::all().count(), valid);
+
+        let ids = AwbwTerrain::all()
+            .map(|terrain| u8::from(terrain.id()))
+            .collect::<Vec<_>>();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_terrain_id_space() {
+        let expected = |id: u8| matches!(id, 1..=57 | 81..=176 | 181..=223);
+
+        for id in 0..=u8::MAX {
+            assert_eq!(AwbwTerrainId::is_valid(id), expected(id), "terrain id {id}");
+            if !expected(id) {
+                assert_eq!(
+                    AwbwTerrain::try_from(id),
+                    Err(TryFromTerrainError::InvalidId(id))
+                );
+            }
+        }
+
+        let valid = (0..=u8::MAX)
+            .filter(|&id| AwbwTerrainId::is_valid(id))
+            .count();
+        assert_eq!(valid, 196);
+    }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::AwbwCoId;
+use crate::{AwbwCoId, PlayerFaction};
 
 /// Typed CO identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -73,6 +73,191 @@ impl Co {
         }
     }
 
+    /// AWBW display name, as found in replay CO name fields
+    pub fn name(self) -> &'static str {
+        match self {
+            Co::Andy => "Andy",
+            Co::Nell => "Nell",
+            Co::Hachi => "Hachi",
+            Co::Jake => "Jake",
+            Co::Rachel => "Rachel",
+            Co::Colin => "Colin",
+            Co::Sasha => "Sasha",
+            Co::Grimm => "Grimm",
+            Co::Grit => "Grit",
+            Co::Olaf => "Olaf",
+            Co::Eagle => "Eagle",
+            Co::Drake => "Drake",
+            Co::Jess => "Jess",
+            Co::Javier => "Javier",
+            Co::Max => "Max",
+            Co::Adder => "Adder",
+            Co::Flak => "Flak",
+            Co::Lash => "Lash",
+            Co::Hawke => "Hawke",
+            Co::Jugger => "Jugger",
+            Co::Kindle => "Kindle",
+            Co::Koal => "Koal",
+            Co::Sami => "Sami",
+            Co::Sonja => "Sonja",
+            Co::Kanbei => "Kanbei",
+            Co::Sensei => "Sensei",
+            Co::Sturm => "Sturm",
+            Co::VonBolt => "Von Bolt",
+            Co::NoCo => "No CO",
+        }
+    }
+
+    /// Convert an AWBW display name to a CO, inverting the `name` method
+    pub fn from_awbw_name(name: &str) -> Option<Self> {
+        match name {
+            "Andy" => Some(Co::Andy),
+            "Nell" => Some(Co::Nell),
+            "Hachi" => Some(Co::Hachi),
+            "Jake" => Some(Co::Jake),
+            "Rachel" => Some(Co::Rachel),
+            "Colin" => Some(Co::Colin),
+            "Sasha" => Some(Co::Sasha),
+            "Grimm" => Some(Co::Grimm),
+            "Grit" => Some(Co::Grit),
+            "Olaf" => Some(Co::Olaf),
+            "Eagle" => Some(Co::Eagle),
+            "Drake" => Some(Co::Drake),
+            "Jess" => Some(Co::Jess),
+            "Javier" => Some(Co::Javier),
+            "Max" => Some(Co::Max),
+            "Adder" => Some(Co::Adder),
+            "Flak" => Some(Co::Flak),
+            "Lash" => Some(Co::Lash),
+            "Hawke" => Some(Co::Hawke),
+            "Jugger" => Some(Co::Jugger),
+            "Kindle" => Some(Co::Kindle),
+            "Koal" => Some(Co::Koal),
+            "Sami" => Some(Co::Sami),
+            "Sonja" => Some(Co::Sonja),
+            "Kanbei" => Some(Co::Kanbei),
+            "Sensei" => Some(Co::Sensei),
+            "Sturm" => Some(Co::Sturm),
+            "Von Bolt" => Some(Co::VonBolt),
+            "No CO" => Some(Co::NoCo),
+            _ => None,
+        }
+    }
+
+    /// The army this CO belongs to in the Advance Wars games. `NoCo` has
+    /// no home army.
+    pub fn faction_default(self) -> Option<PlayerFaction> {
+        match self {
+            Co::Andy => Some(PlayerFaction::OrangeStar),
+            Co::Nell => Some(PlayerFaction::OrangeStar),
+            Co::Hachi => Some(PlayerFaction::OrangeStar),
+            Co::Jake => Some(PlayerFaction::OrangeStar),
+            Co::Rachel => Some(PlayerFaction::OrangeStar),
+            Co::Colin => Some(PlayerFaction::BlueMoon),
+            Co::Sasha => Some(PlayerFaction::BlueMoon),
+            Co::Grimm => Some(PlayerFaction::YellowComet),
+            Co::Grit => Some(PlayerFaction::BlueMoon),
+            Co::Olaf => Some(PlayerFaction::BlueMoon),
+            Co::Eagle => Some(PlayerFaction::GreenEarth),
+            Co::Drake => Some(PlayerFaction::GreenEarth),
+            Co::Jess => Some(PlayerFaction::GreenEarth),
+            Co::Javier => Some(PlayerFaction::GreenEarth),
+            Co::Max => Some(PlayerFaction::OrangeStar),
+            Co::Adder => Some(PlayerFaction::BlackHole),
+            Co::Flak => Some(PlayerFaction::BlackHole),
+            Co::Lash => Some(PlayerFaction::BlackHole),
+            Co::Hawke => Some(PlayerFaction::BlackHole),
+            Co::Jugger => Some(PlayerFaction::BlackHole),
+            Co::Kindle => Some(PlayerFaction::BlackHole),
+            Co::Koal => Some(PlayerFaction::BlackHole),
+            Co::Sami => Some(PlayerFaction::OrangeStar),
+            Co::Sonja => Some(PlayerFaction::YellowComet),
+            Co::Kanbei => Some(PlayerFaction::YellowComet),
+            Co::Sensei => Some(PlayerFaction::YellowComet),
+            Co::Sturm => Some(PlayerFaction::BlackHole),
+            Co::VonBolt => Some(PlayerFaction::BlackHole),
+            Co::NoCo => None,
+        }
+    }
+
+    /// Short summary of the CO's always-on (day-to-day) effects.
+    ///
+    /// Placeholder text for display only; the modelled bonuses live in
+    /// [`Co::stats`].
+    pub fn day_to_day_description(self) -> &'static str {
+        match self {
+            Co::Andy => "No day-to-day strengths or weaknesses.",
+            Co::Nell => "Higher chance of good luck on attacks.",
+            Co::Hachi => "Units cost less to deploy.",
+            Co::Jake => "Units fight better on plains.",
+            Co::Rachel => "Repairs restore an extra HP.",
+            Co::Colin => "Units are cheaper but weaker.",
+            Co::Sasha => "Properties generate extra funds.",
+            Co::Grimm => "Strong attacks but weak defense.",
+            Co::Grit => "Indirect units are stronger with extra range; direct units are weaker.",
+            Co::Olaf => "Snow doesn't slow units; rain does.",
+            Co::Eagle => "Air units are stronger and use less fuel; naval units are weaker.",
+            Co::Drake => "Naval units gain movement and defense; air units are weaker.",
+            Co::Jess => "Vehicles are stronger; other units are weaker.",
+            Co::Javier => "Units resist indirect attacks and grow stronger with com towers.",
+            Co::Max => "Direct units are stronger; indirect units are weaker with less range.",
+            Co::Adder => "Power meter charges faster.",
+            Co::Flak => "Wider luck range in both directions.",
+            Co::Lash => "Units gain firepower from terrain stars.",
+            Co::Hawke => "Units have extra firepower.",
+            Co::Jugger => "Widest luck range in both directions.",
+            Co::Kindle => "Units on urban terrain are stronger.",
+            Co::Koal => "Units fight better on roads.",
+            Co::Sami => {
+                "Foot soldiers are stronger and capture faster; other direct units are weaker."
+            }
+            Co::Sonja => "Extra vision and hidden enemy HP; more bad luck.",
+            Co::Kanbei => "Units are stronger and tougher but cost more.",
+            Co::Sensei => "Copters and foot soldiers are stronger; naval units are weaker.",
+            Co::Sturm => "Units ignore terrain penalties except in snow.",
+            Co::VonBolt => "Units are stronger and tougher.",
+            Co::NoCo => "No day-to-day strengths or weaknesses.",
+        }
+    }
+
+    /// Short summary of the CO's regular power.
+    ///
+    /// Placeholder text for display only; power effects are not modelled
+    /// yet.
+    pub fn power_description(self) -> &'static str {
+        match self {
+            Co::Andy => "Hyper Repair: restores two HP to all units.",
+            Co::Nell => "Lucky Star: further raises good luck.",
+            Co::Hachi => "Barter: units cost half as much to deploy.",
+            Co::Jake => "Beat Down: boosts plains firepower and vehicle range.",
+            Co::Rachel => "Lucky Lass: raises good luck.",
+            Co::Colin => "Gold Rush: multiplies current funds.",
+            Co::Sasha => "Market Crash: drains enemy power meters.",
+            Co::Grimm => "Knuckleduster: further raises firepower.",
+            Co::Grit => "Snipe Attack: extends indirect range and firepower.",
+            Co::Olaf => "Blizzard: causes snow.",
+            Co::Eagle => "Lightning Drive: boosts air units' firepower and defense.",
+            Co::Drake => "Tsunami: damages all enemy units by one HP.",
+            Co::Jess => "Turbo Charge: refuels and boosts vehicles.",
+            Co::Javier => "Tower Shield: boosts indirect defense.",
+            Co::Max => "Max Force: boosts direct units' firepower and movement.",
+            Co::Adder => "Sideslip: raises movement by one.",
+            Co::Flak => "Brute Force: widens luck range.",
+            Co::Lash => "Terrain Tactics: terrain movement costs drop to one.",
+            Co::Hawke => "Black Wave: damages enemies and heals allies by one HP.",
+            Co::Jugger => "Overclock: further widens luck range.",
+            Co::Kindle => "Urban Blight: damages enemies on urban terrain.",
+            Co::Koal => "Forced March: raises movement by one.",
+            Co::Sami => "Double Time: boosts foot soldiers' movement and firepower.",
+            Co::Sonja => "Enhanced Vision: raises vision and reveals hiding places.",
+            Co::Kanbei => "Morale Boost: raises firepower.",
+            Co::Sensei => "Copter Command: deploys infantry on owned cities.",
+            Co::Sturm => "Meteor Strike: damages units in a target area.",
+            Co::VonBolt => "No regular power.",
+            Co::NoCo => "No powers.",
+        }
+    }
+
     /// Returns the D2D stats for this CO.
     ///
     /// COs with special-case mechanics return only their flat bonuses here.
@@ -175,6 +360,30 @@ mod tests {
         assert_eq!(Co::from_awbw_id(AwbwCoId::new(4)), None);
     }
 
+    #[test]
+    fn converts_awbw_co_names() {
+        assert_eq!(Co::from_awbw_name("Andy"), Some(Co::Andy));
+        assert_eq!(Co::from_awbw_name("Drake"), Some(Co::Drake));
+        assert_eq!(Co::from_awbw_name("Von Bolt"), Some(Co::VonBolt));
+        assert_eq!(Co::from_awbw_name("No CO"), Some(Co::NoCo));
+        assert_eq!(Co::from_awbw_name("Nobody"), None);
+        assert_eq!(Co::from_awbw_name("andy"), None);
+
+        for id in 1..=31 {
+            if let Some(co) = Co::from_awbw_id(AwbwCoId::new(id)) {
+                assert_eq!(Co::from_awbw_name(co.name()), Some(co));
+            }
+        }
+    }
+
+    #[test]
+    fn default_factions() {
+        assert_eq!(Co::Andy.faction_default(), Some(PlayerFaction::OrangeStar));
+        assert_eq!(Co::Olaf.faction_default(), Some(PlayerFaction::BlueMoon));
+        assert_eq!(Co::Sturm.faction_default(), Some(PlayerFaction::BlackHole));
+        assert_eq!(Co::NoCo.faction_default(), None);
+    }
+
     #[test]
     fn luck_cos_have_explicit_luck_bounds() {
         let nell = Co::Nell.stats();
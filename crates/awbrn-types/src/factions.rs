@@ -1,7 +1,19 @@
-use crate::AwbwFactionId;
+use crate::{AwbwFactionId, GraphicalTerrain, Property, PropertyKind};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum::VariantArray)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    strum::VariantArray,
+)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum PlayerFaction {
     AcidRain = 0,
@@ -144,11 +156,60 @@ impl PlayerFaction {
         }
     }
 
+    /// Get the next player faction alphabetically
+    #[inline]
+    pub const fn next(&self) -> PlayerFaction {
+        match self {
+            PlayerFaction::AcidRain => PlayerFaction::AmberBlossom,
+            PlayerFaction::AmberBlossom => PlayerFaction::AzureAsteroid,
+            PlayerFaction::AzureAsteroid => PlayerFaction::BlackHole,
+            PlayerFaction::BlackHole => PlayerFaction::BlueMoon,
+            PlayerFaction::BlueMoon => PlayerFaction::BrownDesert,
+            PlayerFaction::BrownDesert => PlayerFaction::CobaltIce,
+            PlayerFaction::CobaltIce => PlayerFaction::GreenEarth,
+            PlayerFaction::GreenEarth => PlayerFaction::GreySky,
+            PlayerFaction::GreySky => PlayerFaction::JadeSun,
+            PlayerFaction::JadeSun => PlayerFaction::NoirEclipse,
+            PlayerFaction::NoirEclipse => PlayerFaction::OrangeStar,
+            PlayerFaction::OrangeStar => PlayerFaction::PinkCosmos,
+            PlayerFaction::PinkCosmos => PlayerFaction::PurpleLightning,
+            PlayerFaction::PurpleLightning => PlayerFaction::RedFire,
+            PlayerFaction::RedFire => PlayerFaction::SilverClaw,
+            PlayerFaction::SilverClaw => PlayerFaction::TealGalaxy,
+            PlayerFaction::TealGalaxy => PlayerFaction::UmberWilds,
+            PlayerFaction::UmberWilds => PlayerFaction::WhiteNova,
+            PlayerFaction::WhiteNova => PlayerFaction::YellowComet,
+            PlayerFaction::YellowComet => PlayerFaction::AcidRain,
+        }
+    }
+
+    /// Zero-based alphabetical position of this faction
     #[inline]
     pub const fn index(&self) -> u8 {
         *self as u8
     }
 
+    /// Create a PlayerFaction from its alphabetical [`Self::index`]
+    pub fn from_index(index: u8) -> Option<Self> {
+        <Self as strum::VariantArray>::VARIANTS
+            .get(usize::from(index))
+            .copied()
+    }
+
+    /// The terrain for a property of the given kind owned by this faction.
+    pub const fn owns(&self, kind: PropertyKind) -> GraphicalTerrain {
+        let owner = Faction::Player(*self);
+        GraphicalTerrain::Property(match kind {
+            PropertyKind::Airport => Property::Airport(owner),
+            PropertyKind::Base => Property::Base(owner),
+            PropertyKind::City => Property::City(owner),
+            PropertyKind::ComTower => Property::ComTower(owner),
+            PropertyKind::HQ => Property::HQ(*self),
+            PropertyKind::Lab => Property::Lab(owner),
+            PropertyKind::Port => Property::Port(owner),
+        })
+    }
+
     /// Returns whether this faction's default unit facing direction is right.
     ///
     /// Ref: `AWBW-Replay-Player/AWBWApp.Resources/Json/Countries.json`
@@ -157,8 +218,14 @@ impl PlayerFaction {
     }
 }
 
+impl std::fmt::Display for PlayerFaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 /// Army factions in the game
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum Faction {
     Neutral,
@@ -183,9 +250,17 @@ impl Faction {
 
 #[cfg(test)]
 mod tests {
-    use super::PlayerFaction;
+    use super::{Faction, PlayerFaction};
+    use crate::{GraphicalTerrain, Property, PropertyKind};
     use strum::VariantArray;
 
+    #[test]
+    fn display_matches_name() {
+        for faction in PlayerFaction::VARIANTS {
+            assert_eq!(format!("{}", faction), faction.name());
+        }
+    }
+
     #[test]
     fn awbw_id_round_trips_for_all_factions() {
         for faction in PlayerFaction::VARIANTS {
@@ -212,4 +287,57 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn from_index_round_trips() {
+        for faction in PlayerFaction::VARIANTS {
+            assert_eq!(PlayerFaction::from_index(faction.index()), Some(*faction));
+        }
+        assert_eq!(PlayerFaction::from_index(20), None);
+    }
+
+    #[test]
+    fn next_and_prev_cycle_through_every_faction() {
+        assert_eq!(PlayerFaction::YellowComet.next(), PlayerFaction::AcidRain);
+        assert_eq!(PlayerFaction::AcidRain.prev(), PlayerFaction::YellowComet);
+
+        for faction in PlayerFaction::VARIANTS {
+            let index = faction.index();
+            assert_eq!(
+                faction.next(),
+                PlayerFaction::from_index((index + 1) % 20).unwrap()
+            );
+            assert_eq!(
+                faction.prev(),
+                PlayerFaction::from_index((index + 19) % 20).unwrap()
+            );
+            assert_eq!(faction.next().prev(), *faction);
+        }
+
+        let mut faction = PlayerFaction::AcidRain;
+        for _ in 0..PlayerFaction::VARIANTS.len() {
+            faction = faction.next();
+        }
+        assert_eq!(faction, PlayerFaction::AcidRain);
+    }
+
+    #[test]
+    fn owns_builds_owned_property_terrain() {
+        let faction = PlayerFaction::BlueMoon;
+        let owner = Faction::Player(faction);
+        let cases = [
+            (PropertyKind::Airport, Property::Airport(owner)),
+            (PropertyKind::Base, Property::Base(owner)),
+            (PropertyKind::City, Property::City(owner)),
+            (PropertyKind::ComTower, Property::ComTower(owner)),
+            (PropertyKind::HQ, Property::HQ(faction)),
+            (PropertyKind::Lab, Property::Lab(owner)),
+            (PropertyKind::Port, Property::Port(owner)),
+        ];
+
+        for (kind, property) in cases {
+            assert_eq!(faction.owns(kind), GraphicalTerrain::Property(property));
+            assert_eq!(property.kind(), kind);
+        }
+    }
 }
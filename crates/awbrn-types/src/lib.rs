@@ -4,6 +4,7 @@ mod co;
 mod factions;
 mod hp;
 mod movement;
+mod orientation;
 mod terrain;
 mod units;
 mod weather;
@@ -1,7 +1,8 @@
-use crate::MovementTerrain;
+use crate::{MovementTerrain, Weather};
+use strum::VariantArray;
 
 /// Represents different movement capabilities of units
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::VariantArray)]
 pub enum UnitMovement {
     Foot,   // Infantry
     Boot,   // Mech
@@ -13,6 +14,21 @@ pub enum UnitMovement {
     Pipe,   // Pipe units
 }
 
+impl UnitMovement {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            UnitMovement::Foot => "Foot",
+            UnitMovement::Boot => "Boot",
+            UnitMovement::Treads => "Treads",
+            UnitMovement::Tires => "Tires",
+            UnitMovement::Sea => "Sea",
+            UnitMovement::Lander => "Lander",
+            UnitMovement::Air => "Air",
+            UnitMovement::Pipe => "Pipe",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MovementCost {
     costs: [Option<u8>; 11],
@@ -56,9 +72,65 @@ impl MovementCost {
         }
     }
 
+    /// Costs for `terrain` under `weather`. See [`movement_cost_in_weather`]
+    /// for how each weather differs from clear skies.
+    pub const fn from_terrain_in_weather(terrain: &MovementTerrain, weather: Weather) -> Self {
+        match (weather, terrain) {
+            (Weather::Snow, MovementTerrain::Plains) => SNOW_PLAINS_MOVEMENT,
+            (Weather::Snow, MovementTerrain::Mountains) => SNOW_MOUNTAINS_MOVEMENT,
+            (Weather::Snow, MovementTerrain::Woods) => SNOW_WOODS_MOVEMENT,
+            (Weather::Snow, MovementTerrain::Rivers) => SNOW_RIVERS_MOVEMENT,
+            (Weather::Snow, MovementTerrain::Infrastructure) => SNOW_INFRASTRUCTURE_MOVEMENT,
+            (Weather::Snow, MovementTerrain::Sea) => SNOW_SEA_MOVEMENT,
+            (Weather::Snow, MovementTerrain::Shoals) => SNOW_SHOALS_MOVEMENT,
+            (Weather::Snow, MovementTerrain::Reefs) => SNOW_REEFS_MOVEMENT,
+            (Weather::Rain, MovementTerrain::Plains) => RAIN_PLAINS_MOVEMENT,
+            (Weather::Rain, MovementTerrain::Woods) => RAIN_WOODS_MOVEMENT,
+            _ => Self::from_terrain(terrain),
+        }
+    }
+
     pub const fn cost(&self, movement_type: UnitMovement) -> Option<u8> {
         self.costs[movement_type as usize]
     }
+
+    /// The full movement cost chart: every terrain and movement type pairing
+    /// under each weather, with its cost, or `None` when impassable.
+    pub fn table() -> impl Iterator<Item = (UnitMovement, MovementTerrain, Weather, Option<u8>)> {
+        Weather::VARIANTS.iter().flat_map(|weather| {
+            MovementTerrain::VARIANTS.iter().flat_map(move |terrain| {
+                let costs = MovementCost::from_terrain_in_weather(terrain, *weather);
+                UnitMovement::VARIANTS
+                    .iter()
+                    .map(move |movement| (*movement, *terrain, *weather, costs.cost(*movement)))
+            })
+        })
+    }
+}
+
+/// Clear-weather cost for a unit with `movement` to enter `terrain`, or `None`
+/// when it can't enter at all.
+pub const fn movement_cost(terrain: MovementTerrain, movement: UnitMovement) -> Option<u8> {
+    movement_cost_in_weather(terrain, movement, Weather::Clear)
+}
+
+/// Cost for a unit with `movement` to enter `terrain` under `weather`, or
+/// `None` when it can't enter at all. Weather never makes terrain passable or
+/// impassable; it only changes costs:
+///
+/// - Rain: treads pay 2 on plains and 3 in woods; tires pay 3 on plains and
+///   4 in woods.
+/// - Snow: air pays 2 everywhere and ships pay 2 at sea. Foot pays 2 on
+///   plains and in woods and 4 on mountains; boots pay 2 in woods and on
+///   mountains; treads pay 2 and tires 3 on plains.
+///
+/// Pipes and teleporters ignore the weather.
+pub const fn movement_cost_in_weather(
+    terrain: MovementTerrain,
+    movement: UnitMovement,
+    weather: Weather,
+) -> Option<u8> {
+    MovementCost::from_terrain_in_weather(&terrain, weather).cost(movement)
 }
 
 const PLAINS_MOVEMENT: MovementCost = MovementCost::new(&[
@@ -131,10 +203,213 @@ const TELEPORT_MOVEMENT: MovementCost = MovementCost::new(&[
     (UnitMovement::Pipe, Some(0)),
 ]);
 
+const SNOW_PLAINS_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Foot, Some(2)),
+    (UnitMovement::Boot, Some(1)),
+    (UnitMovement::Treads, Some(2)),
+    (UnitMovement::Tires, Some(3)),
+    (UnitMovement::Air, Some(2)),
+]);
+
+const SNOW_MOUNTAINS_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Foot, Some(4)),
+    (UnitMovement::Boot, Some(2)),
+    (UnitMovement::Air, Some(2)),
+]);
+
+const SNOW_WOODS_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Foot, Some(2)),
+    (UnitMovement::Boot, Some(2)),
+    (UnitMovement::Treads, Some(2)),
+    (UnitMovement::Tires, Some(3)),
+    (UnitMovement::Air, Some(2)),
+]);
+
+const SNOW_RIVERS_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Foot, Some(2)),
+    (UnitMovement::Boot, Some(1)),
+    (UnitMovement::Air, Some(2)),
+]);
+
+const SNOW_INFRASTRUCTURE_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Foot, Some(1)),
+    (UnitMovement::Boot, Some(1)),
+    (UnitMovement::Treads, Some(1)),
+    (UnitMovement::Tires, Some(1)),
+    (UnitMovement::Air, Some(2)),
+]);
+
+const SNOW_SEA_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Air, Some(2)),
+    (UnitMovement::Sea, Some(2)),
+    (UnitMovement::Lander, Some(2)),
+]);
+
+const SNOW_SHOALS_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Foot, Some(1)),
+    (UnitMovement::Boot, Some(1)),
+    (UnitMovement::Treads, Some(1)),
+    (UnitMovement::Tires, Some(1)),
+    (UnitMovement::Air, Some(2)),
+    (UnitMovement::Lander, Some(1)),
+]);
+
+const SNOW_REEFS_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Air, Some(2)),
+    (UnitMovement::Sea, Some(2)),
+    (UnitMovement::Lander, Some(2)),
+]);
+
+const RAIN_PLAINS_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Foot, Some(1)),
+    (UnitMovement::Boot, Some(1)),
+    (UnitMovement::Treads, Some(2)),
+    (UnitMovement::Tires, Some(3)),
+    (UnitMovement::Air, Some(1)),
+]);
+
+const RAIN_WOODS_MOVEMENT: MovementCost = MovementCost::new(&[
+    (UnitMovement::Foot, Some(1)),
+    (UnitMovement::Boot, Some(1)),
+    (UnitMovement::Treads, Some(3)),
+    (UnitMovement::Tires, Some(4)),
+    (UnitMovement::Air, Some(1)),
+]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_table_covers_every_pair_once() {
+        let entries = MovementCost::table().collect::<Vec<_>>();
+        assert_eq!(
+            entries.len(),
+            UnitMovement::VARIANTS.len()
+                * MovementTerrain::VARIANTS.len()
+                * Weather::VARIANTS.len()
+        );
+
+        let pairs = entries
+            .iter()
+            .map(|(movement, terrain, weather, _)| (*movement, *terrain, *weather))
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(pairs.len(), entries.len());
+
+        for (movement, terrain, weather, cost) in entries {
+            assert_eq!(
+                MovementCost::from_terrain_in_weather(&terrain, weather).cost(movement),
+                cost
+            );
+        }
+    }
+
+    #[test]
+    fn test_movement_cost_lookup() {
+        assert_eq!(
+            movement_cost(MovementTerrain::Mountains, UnitMovement::Foot),
+            Some(2)
+        );
+        assert_eq!(
+            movement_cost(MovementTerrain::Plains, UnitMovement::Tires),
+            Some(2)
+        );
+        assert_eq!(
+            movement_cost(MovementTerrain::Woods, UnitMovement::Treads),
+            Some(2)
+        );
+        assert_eq!(
+            movement_cost(MovementTerrain::Reefs, UnitMovement::Sea),
+            Some(2)
+        );
+        assert_eq!(
+            movement_cost(MovementTerrain::Sea, UnitMovement::Foot),
+            None
+        );
+        assert_eq!(
+            movement_cost(MovementTerrain::Rivers, UnitMovement::Sea),
+            None
+        );
+        assert_eq!(
+            movement_cost(MovementTerrain::Rivers, UnitMovement::Lander),
+            None
+        );
+
+        for (movement, terrain, weather, cost) in MovementCost::table() {
+            assert_eq!(movement_cost_in_weather(terrain, movement, weather), cost);
+        }
+    }
+
+    #[test]
+    fn test_snow_movement_costs() {
+        let snow = |terrain, movement| movement_cost_in_weather(terrain, movement, Weather::Snow);
+
+        assert_eq!(
+            snow(MovementTerrain::Mountains, UnitMovement::Foot),
+            Some(4)
+        );
+        assert_eq!(
+            snow(MovementTerrain::Mountains, UnitMovement::Boot),
+            Some(2)
+        );
+        assert_eq!(snow(MovementTerrain::Plains, UnitMovement::Foot), Some(2));
+        assert_eq!(snow(MovementTerrain::Plains, UnitMovement::Tires), Some(3));
+        assert_eq!(
+            snow(MovementTerrain::Infrastructure, UnitMovement::Tires),
+            Some(1)
+        );
+        assert_eq!(
+            snow(MovementTerrain::Infrastructure, UnitMovement::Air),
+            Some(2)
+        );
+        assert_eq!(snow(MovementTerrain::Sea, UnitMovement::Sea), Some(2));
+        assert_eq!(snow(MovementTerrain::Pipes, UnitMovement::Pipe), Some(1));
+        assert_eq!(snow(MovementTerrain::Mountains, UnitMovement::Treads), None);
+    }
+
+    #[test]
+    fn test_rain_movement_costs() {
+        let rain = |terrain, movement| movement_cost_in_weather(terrain, movement, Weather::Rain);
+
+        assert_eq!(rain(MovementTerrain::Plains, UnitMovement::Treads), Some(2));
+        assert_eq!(rain(MovementTerrain::Plains, UnitMovement::Tires), Some(3));
+        assert_eq!(rain(MovementTerrain::Woods, UnitMovement::Treads), Some(3));
+        assert_eq!(rain(MovementTerrain::Woods, UnitMovement::Tires), Some(4));
+        assert_eq!(rain(MovementTerrain::Plains, UnitMovement::Foot), Some(1));
+        assert_eq!(
+            rain(MovementTerrain::Mountains, UnitMovement::Foot),
+            Some(2)
+        );
+        assert_eq!(rain(MovementTerrain::Shoals, UnitMovement::Treads), Some(1));
+    }
+
+    #[test]
+    fn test_weather_changes_costs_only_where_it_affects_terrain() {
+        for (movement, terrain, weather, cost) in MovementCost::table() {
+            let clear = movement_cost(terrain, movement);
+
+            // Weather never opens or closes terrain to a movement type
+            assert_eq!(cost.is_some(), clear.is_some(), "{weather:?} {terrain:?}");
+            if !weather.affects(terrain) {
+                assert_eq!(cost, clear, "{weather:?} {terrain:?} {movement:?}");
+            }
+        }
+
+        for &weather in Weather::VARIANTS {
+            for terrain in MovementTerrain::VARIANTS {
+                let changed = UnitMovement::VARIANTS.iter().any(|&movement| {
+                    movement_cost_in_weather(*terrain, movement, weather)
+                        != movement_cost(*terrain, movement)
+                });
+                assert_eq!(
+                    changed,
+                    weather.affects(*terrain),
+                    "{weather:?} {terrain:?}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_movement_cost_new() {
         // Test creating a new MovementCost with specified costs
@@ -0,0 +1,395 @@
+//! Mirroring and rotating directional terrain, for flipping and turning
+//! maps in the editor.
+//!
+//! Each orientation is described by the compass points it connects to (or,
+//! for seas and shoals, the neighbours that are land or open sea). A
+//! transform moves those points and looks the orientation back up.
+
+use crate::{BridgeType, PipeType, RiverType, RoadType, SeaDirection, ShoalDirection};
+
+// Compass points as bits, clockwise from north, so a quarter turn is a
+// two-bit rotation.
+const N: u8 = 1 << 0;
+const NE: u8 = 1 << 1;
+const E: u8 = 1 << 2;
+const SE: u8 = 1 << 3;
+const S: u8 = 1 << 4;
+const SW: u8 = 1 << 5;
+const W: u8 = 1 << 6;
+const NW: u8 = 1 << 7;
+
+fn rotate_cw(points: u8) -> u8 {
+    points.rotate_left(2)
+}
+
+/// Reflects each point across the axis through point `axis` and its
+/// opposite: 0 mirrors east and west, 2 mirrors north and south.
+fn reflect(points: u8, axis: u32) -> u8 {
+    (0..8)
+        .filter(|bit| points & (1 << bit) != 0)
+        .fold(0, |out, bit| out | 1 << ((2 * axis + 8 - bit) % 8))
+}
+
+fn flip_horizontal(points: u8) -> u8 {
+    reflect(points, 0)
+}
+
+fn flip_vertical(points: u8) -> u8 {
+    reflect(points, 2)
+}
+
+fn transform<T: Copy + PartialEq + std::fmt::Debug>(
+    table: &[(T, u8)],
+    value: T,
+    op: fn(u8) -> u8,
+) -> T {
+    let (_, points) = table
+        .iter()
+        .find(|(candidate, _)| *candidate == value)
+        .expect("every orientation is in its table");
+    let points = op(*points);
+    table
+        .iter()
+        .find(|(_, candidate)| *candidate == points)
+        .map(|(orientation, _)| *orientation)
+        .unwrap_or_else(|| panic!("{value:?} has no transformed orientation"))
+}
+
+macro_rules! impl_orientation {
+    ($ty:ty, $table:ident) => {
+        impl $ty {
+            /// Mirror across the vertical axis, swapping east and west.
+            pub fn flip_horizontal(self) -> Self {
+                transform(&$table, self, flip_horizontal)
+            }
+
+            /// Mirror across the horizontal axis, swapping north and south.
+            pub fn flip_vertical(self) -> Self {
+                transform(&$table, self, flip_vertical)
+            }
+
+            /// Rotate a quarter turn clockwise.
+            pub fn rotate_cw(self) -> Self {
+                transform(&$table, self, rotate_cw)
+            }
+        }
+    };
+}
+
+const ROAD_POINTS: [(RoadType, u8); 11] = [
+    (RoadType::Horizontal, E | W),
+    (RoadType::Vertical, N | S),
+    (RoadType::Cross, N | E | S | W),
+    (RoadType::ES, E | S),
+    (RoadType::SW, S | W),
+    (RoadType::WN, W | N),
+    (RoadType::NE, N | E),
+    (RoadType::ESW, E | S | W),
+    (RoadType::SWN, S | W | N),
+    (RoadType::WNE, W | N | E),
+    (RoadType::NES, N | E | S),
+];
+
+const RIVER_POINTS: [(RiverType, u8); 11] = [
+    (RiverType::Horizontal, E | W),
+    (RiverType::Vertical, N | S),
+    (RiverType::Cross, N | E | S | W),
+    (RiverType::ES, E | S),
+    (RiverType::SW, S | W),
+    (RiverType::WN, W | N),
+    (RiverType::NE, N | E),
+    (RiverType::ESW, E | S | W),
+    (RiverType::SWN, S | W | N),
+    (RiverType::WNE, W | N | E),
+    (RiverType::NES, N | E | S),
+];
+
+const PIPE_POINTS: [(PipeType, u8); 10] = [
+    (PipeType::Vertical, N | S),
+    (PipeType::Horizontal, E | W),
+    (PipeType::NE, N | E),
+    (PipeType::ES, E | S),
+    (PipeType::SW, S | W),
+    (PipeType::WN, W | N),
+    (PipeType::NorthEnd, N),
+    (PipeType::EastEnd, E),
+    (PipeType::SouthEnd, S),
+    (PipeType::WestEnd, W),
+];
+
+const BRIDGE_POINTS: [(BridgeType, u8); 2] = [
+    (BridgeType::Horizontal, E | W),
+    (BridgeType::Vertical, N | S),
+];
+
+/// Neighbours that are land. `S_E` and `W_E` duplicate `E_S` and `E_W`, so
+/// transforms yield the latter.
+const SEA_POINTS: [(SeaDirection, u8); 49] = [
+    (SeaDirection::E, E),
+    (SeaDirection::E_NW, E | NW),
+    (SeaDirection::E_NW_SW, E | NW | SW),
+    (SeaDirection::E_S, E | S),
+    (SeaDirection::E_S_NW, E | S | NW),
+    (SeaDirection::E_S_W, E | S | W),
+    (SeaDirection::E_SW, E | SW),
+    (SeaDirection::E_W, E | W),
+    (SeaDirection::N, N),
+    (SeaDirection::N_E, N | E),
+    (SeaDirection::N_E_S, N | E | S),
+    (SeaDirection::N_E_S_W, N | E | S | W),
+    (SeaDirection::N_E_SW, N | E | SW),
+    (SeaDirection::N_E_W, N | E | W),
+    (SeaDirection::N_S, N | S),
+    (SeaDirection::N_S_W, N | S | W),
+    (SeaDirection::N_SE, N | SE),
+    (SeaDirection::N_SE_SW, N | SE | SW),
+    (SeaDirection::N_SW, N | SW),
+    (SeaDirection::N_W, N | W),
+    (SeaDirection::N_W_SE, N | W | SE),
+    (SeaDirection::NE, NE),
+    (SeaDirection::NE_SE, NE | SE),
+    (SeaDirection::NE_SE_SW, NE | SE | SW),
+    (SeaDirection::NE_SW, NE | SW),
+    (SeaDirection::NW, NW),
+    (SeaDirection::NW_NE, NW | NE),
+    (SeaDirection::NW_NE_SE, NW | NE | SE),
+    (SeaDirection::NW_NE_SE_SW, NW | NE | SE | SW),
+    (SeaDirection::NW_NE_SW, NW | NE | SW),
+    (SeaDirection::NW_SE, NW | SE),
+    (SeaDirection::NW_SE_SW, NW | SE | SW),
+    (SeaDirection::NW_SW, NW | SW),
+    (SeaDirection::S, S),
+    (SeaDirection::S_E, S | E),
+    (SeaDirection::S_NE, S | NE),
+    (SeaDirection::S_NW, S | NW),
+    (SeaDirection::S_NW_NE, S | NW | NE),
+    (SeaDirection::S_W, S | W),
+    (SeaDirection::S_W_NE, S | W | NE),
+    (SeaDirection::SE, SE),
+    (SeaDirection::SE_SW, SE | SW),
+    (SeaDirection::SW, SW),
+    (SeaDirection::Sea, 0),
+    (SeaDirection::W, W),
+    (SeaDirection::W_E, W | E),
+    (SeaDirection::W_NE, W | NE),
+    (SeaDirection::W_NE_SE, W | NE | SE),
+    (SeaDirection::W_SE, W | SE),
+];
+
+/// Neighbours that are land, then neighbours that are open sea. Shoal
+/// neighbours are in neither.
+const SHOAL_POINTS: [(ShoalDirection, u8, u8); 81] = [
+    (ShoalDirection::AE, 0, E),
+    (ShoalDirection::AEAS, 0, E | S),
+    (ShoalDirection::AEASAW, 0, E | S | W),
+    (ShoalDirection::AEASW, W, E | S),
+    (ShoalDirection::AEAW, 0, E | W),
+    (ShoalDirection::AES, S, E),
+    (ShoalDirection::AESAW, S, E | W),
+    (ShoalDirection::AESW, S | W, E),
+    (ShoalDirection::AEW, W, E),
+    (ShoalDirection::AN, 0, N),
+    (ShoalDirection::ANAE, 0, N | E),
+    (ShoalDirection::ANAEAS, 0, N | E | S),
+    (ShoalDirection::ANAEASAW, 0, N | E | S | W),
+    (ShoalDirection::ANAEASW, W, N | E | S),
+    (ShoalDirection::ANAEAW, 0, N | E | W),
+    (ShoalDirection::ANAES, S, N | E),
+    (ShoalDirection::ANAESAW, S, N | E | W),
+    (ShoalDirection::ANAESW, S | W, N | E),
+    (ShoalDirection::ANAEW, W, N | E),
+    (ShoalDirection::ANAS, 0, N | S),
+    (ShoalDirection::ANASAW, 0, N | S | W),
+    (ShoalDirection::ANASW, W, N | S),
+    (ShoalDirection::ANAW, 0, N | W),
+    (ShoalDirection::ANE, E, N),
+    (ShoalDirection::ANEAS, E, N | S),
+    (ShoalDirection::ANEASAW, E, N | S | W),
+    (ShoalDirection::ANEASW, E | W, N | S),
+    (ShoalDirection::ANEAW, E, N | W),
+    (ShoalDirection::ANES, E | S, N),
+    (ShoalDirection::ANESAW, E | S, N | W),
+    (ShoalDirection::ANESW, E | S | W, N),
+    (ShoalDirection::ANEW, E | W, N),
+    (ShoalDirection::ANS, S, N),
+    (ShoalDirection::ANSAW, S, N | W),
+    (ShoalDirection::ANSW, S | W, N),
+    (ShoalDirection::ANW, W, N),
+    (ShoalDirection::AS, 0, S),
+    (ShoalDirection::ASAW, 0, S | W),
+    (ShoalDirection::ASW, W, S),
+    (ShoalDirection::AW, 0, W),
+    (ShoalDirection::C, 0, 0),
+    (ShoalDirection::E, E, 0),
+    (ShoalDirection::EAS, E, S),
+    (ShoalDirection::EASAW, E, S | W),
+    (ShoalDirection::EASW, E | W, S),
+    (ShoalDirection::EAW, E, W),
+    (ShoalDirection::ES, E | S, 0),
+    (ShoalDirection::ESAW, E | S, W),
+    (ShoalDirection::ESW, E | S | W, 0),
+    (ShoalDirection::EW, E | W, 0),
+    (ShoalDirection::N, N, 0),
+    (ShoalDirection::NAE, N, E),
+    (ShoalDirection::NAEAS, N, E | S),
+    (ShoalDirection::NAEASAW, N, E | S | W),
+    (ShoalDirection::NAEASW, N | W, E | S),
+    (ShoalDirection::NAEAW, N, E | W),
+    (ShoalDirection::NAES, N | S, E),
+    (ShoalDirection::NAESAW, N | S, E | W),
+    (ShoalDirection::NAESW, N | S | W, E),
+    (ShoalDirection::NAEW, N | W, E),
+    (ShoalDirection::NAS, N, S),
+    (ShoalDirection::NASAW, N, S | W),
+    (ShoalDirection::NASW, N | W, S),
+    (ShoalDirection::NAW, N, W),
+    (ShoalDirection::NE, N | E, 0),
+    (ShoalDirection::NEAS, N | E, S),
+    (ShoalDirection::NEASAW, N | E, S | W),
+    (ShoalDirection::NEASW, N | E | W, S),
+    (ShoalDirection::NEAW, N | E, W),
+    (ShoalDirection::NES, N | E | S, 0),
+    (ShoalDirection::NESAW, N | E | S, W),
+    (ShoalDirection::NESW, N | E | S | W, 0),
+    (ShoalDirection::NEW, N | E | W, 0),
+    (ShoalDirection::NS, N | S, 0),
+    (ShoalDirection::NSAW, N | S, W),
+    (ShoalDirection::NSW, N | S | W, 0),
+    (ShoalDirection::NW, N | W, 0),
+    (ShoalDirection::S, S, 0),
+    (ShoalDirection::SAW, S, W),
+    (ShoalDirection::SW, S | W, 0),
+    (ShoalDirection::W, W, 0),
+];
+
+impl_orientation!(RoadType, ROAD_POINTS);
+impl_orientation!(RiverType, RIVER_POINTS);
+impl_orientation!(PipeType, PIPE_POINTS);
+impl_orientation!(BridgeType, BRIDGE_POINTS);
+impl_orientation!(SeaDirection, SEA_POINTS);
+
+impl ShoalDirection {
+    /// Mirror across the vertical axis, swapping east and west.
+    pub fn flip_horizontal(self) -> Self {
+        self.transform(flip_horizontal)
+    }
+
+    /// Mirror across the horizontal axis, swapping north and south.
+    pub fn flip_vertical(self) -> Self {
+        self.transform(flip_vertical)
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_cw(self) -> Self {
+        self.transform(rotate_cw)
+    }
+
+    fn transform(self, op: fn(u8) -> u8) -> Self {
+        let (_, land, sea) = SHOAL_POINTS
+            .iter()
+            .find(|(candidate, _, _)| *candidate == self)
+            .expect("every shoal orientation is in its table");
+        let (land, sea) = (op(*land), op(*sea));
+        SHOAL_POINTS
+            .iter()
+            .find(|(_, l, s)| *l == land && *s == sea)
+            .map(|(orientation, _, _)| *orientation)
+            .unwrap_or_else(|| panic!("{self:?} has no transformed orientation"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn road_es_flipped_horizontally_is_sw() {
+        assert_eq!(RoadType::ES.flip_horizontal(), RoadType::SW);
+        assert_eq!(RoadType::ES.flip_vertical(), RoadType::NE);
+        assert_eq!(RoadType::ES.rotate_cw(), RoadType::SW);
+        assert_eq!(RiverType::NES.rotate_cw(), RiverType::ESW);
+    }
+
+    #[test]
+    fn vertical_pipe_rotates_to_horizontal() {
+        assert_eq!(PipeType::Vertical.rotate_cw(), PipeType::Horizontal);
+        assert_eq!(PipeType::NorthEnd.rotate_cw(), PipeType::EastEnd);
+        assert_eq!(BridgeType::Horizontal.rotate_cw(), BridgeType::Vertical);
+    }
+
+    #[test]
+    fn sea_and_shoal_transforms_follow_land() {
+        assert_eq!(SeaDirection::N_SE.flip_horizontal(), SeaDirection::N_SW);
+        assert_eq!(SeaDirection::E_S_NW.rotate_cw(), SeaDirection::S_W_NE);
+        assert_eq!(ShoalDirection::NAE.flip_horizontal(), ShoalDirection::NAW);
+        assert_eq!(ShoalDirection::NAE.rotate_cw(), ShoalDirection::EAS);
+    }
+
+    #[test]
+    fn transforms_are_closed_and_invertible() {
+        fn check<T: Copy + PartialEq + std::fmt::Debug>(
+            value: T,
+            flip_horizontal: fn(T) -> T,
+            flip_vertical: fn(T) -> T,
+            rotate_cw: fn(T) -> T,
+        ) {
+            assert_eq!(flip_horizontal(flip_horizontal(value)), value);
+            assert_eq!(flip_vertical(flip_vertical(value)), value);
+            let turned = (0..4).fold(value, |value, _| rotate_cw(value));
+            assert_eq!(turned, value);
+        }
+
+        for (road, _) in ROAD_POINTS {
+            check(
+                road,
+                RoadType::flip_horizontal,
+                RoadType::flip_vertical,
+                RoadType::rotate_cw,
+            );
+        }
+        for (river, _) in RIVER_POINTS {
+            check(
+                river,
+                RiverType::flip_horizontal,
+                RiverType::flip_vertical,
+                RiverType::rotate_cw,
+            );
+        }
+        for (pipe, _) in PIPE_POINTS {
+            check(
+                pipe,
+                PipeType::flip_horizontal,
+                PipeType::flip_vertical,
+                PipeType::rotate_cw,
+            );
+        }
+        for (bridge, _) in BRIDGE_POINTS {
+            check(
+                bridge,
+                BridgeType::flip_horizontal,
+                BridgeType::flip_vertical,
+                BridgeType::rotate_cw,
+            );
+        }
+        let aliases = [SeaDirection::S_E, SeaDirection::W_E];
+        for (sea, _) in SEA_POINTS
+            .into_iter()
+            .filter(|(sea, _)| !aliases.contains(sea))
+        {
+            check(
+                sea,
+                SeaDirection::flip_horizontal,
+                SeaDirection::flip_vertical,
+                SeaDirection::rotate_cw,
+            );
+        }
+        for (shoal, _, _) in SHOAL_POINTS {
+            check(
+                shoal,
+                ShoalDirection::flip_horizontal,
+                ShoalDirection::flip_vertical,
+                ShoalDirection::rotate_cw,
+            );
+        }
+    }
+}
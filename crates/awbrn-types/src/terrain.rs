@@ -1,7 +1,7 @@
-use crate::{AwbwTerrain, Faction, PlayerFaction};
+use crate::{AwbwTerrain, AwbwTerrainId, Faction, PlayerFaction, Unit, UnitDomain};
 
 /// Status of the missile silo
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum MissileSiloStatus {
     Loaded,
@@ -9,7 +9,7 @@ pub enum MissileSiloStatus {
 }
 
 /// River configurations
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum RiverType {
     Horizontal, // HRiver
@@ -26,7 +26,7 @@ pub enum RiverType {
 }
 
 /// Road configurations
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum RoadType {
     Horizontal, // HRoad
@@ -43,7 +43,7 @@ pub enum RoadType {
 }
 
 /// Bridge types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum BridgeType {
     Horizontal,
@@ -61,7 +61,7 @@ pub enum ShoalType {
 }
 
 /// Sea configurations based on the variants file
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 #[expect(non_camel_case_types)]
 pub enum SeaDirection {
@@ -117,7 +117,7 @@ pub enum SeaDirection {
 }
 
 /// Shoal configurations based on the variants file
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum ShoalDirection {
     AE,
@@ -204,7 +204,7 @@ pub enum ShoalDirection {
 }
 
 /// Pipe configurations
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum PipeType {
     Vertical,
@@ -220,7 +220,7 @@ pub enum PipeType {
 }
 
 /// Pipe seam types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum PipeSeamType {
     Horizontal,
@@ -228,15 +228,20 @@ pub enum PipeSeamType {
 }
 
 /// Pipe rubble types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum PipeRubbleType {
     Horizontal,
     Vertical,
 }
 
+/// Funds each income-generating property yields per turn in a standard game.
+/// AWBW games can configure a different rate, so income methods take the rate
+/// as an argument.
+pub const PROPERTY_INCOME: u32 = 1000;
+
 /// Property types combining building type and owner
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum Property {
     // Regular properties that can be neutral
@@ -284,6 +289,44 @@ impl Property {
         }
     }
 
+    /// Funds this property generates each turn for its owner. Owned cities,
+    /// bases, airports, ports, and HQs yield `funds_per_property`. Com towers,
+    /// labs, and neutral properties yield nothing.
+    pub const fn income(&self, funds_per_property: u32) -> u32 {
+        match self {
+            Property::City(Faction::Player(_))
+            | Property::Base(Faction::Player(_))
+            | Property::Airport(Faction::Player(_))
+            | Property::Port(Faction::Player(_))
+            | Property::HQ(_) => funds_per_property,
+            _ => 0,
+        }
+    }
+
+    /// Whether this property repairs and resupplies `unit` at the start of its
+    /// owner's turn. Cities, bases, and HQs service ground units, airports
+    /// service air units, and ports service naval units. Neutral properties
+    /// have no owner to repair for.
+    pub const fn repairs(&self, unit: Unit) -> bool {
+        matches!(
+            (self, unit.domain()),
+            (Property::City(Faction::Player(_)), UnitDomain::Ground)
+                | (Property::Base(Faction::Player(_)), UnitDomain::Ground)
+                | (Property::HQ(_), UnitDomain::Ground)
+                | (Property::Airport(Faction::Player(_)), UnitDomain::Air)
+                | (Property::Port(Faction::Player(_)), UnitDomain::Sea)
+        )
+    }
+
+    /// HP restored each turn to a unit this property [repairs](Self::repairs)
+    pub const fn heal_amount(&self) -> u8 {
+        match self {
+            Property::ComTower(_) | Property::Lab(_) => 0,
+            _ if matches!(self.faction(), Faction::Neutral) => 0,
+            _ => 2,
+        }
+    }
+
     /// Get the name of this property type
     pub const fn name(&self) -> &'static str {
         match self {
@@ -452,6 +495,12 @@ impl Property {
     }
 }
 
+impl std::fmt::Display for Property {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 /// Property types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PropertyKind {
@@ -500,9 +549,37 @@ pub enum GameplayTerrain {
     Teleporter,
 }
 
+impl GameplayTerrain {
+    /// Name of the gameplay category, ignoring ownership and silo status
+    pub const fn name(&self) -> &'static str {
+        match self {
+            GameplayTerrain::Plain => "Plain",
+            GameplayTerrain::Mountain => "Mountain",
+            GameplayTerrain::Wood => "Wood",
+            GameplayTerrain::River => "River",
+            GameplayTerrain::Road => "Road",
+            GameplayTerrain::Bridge => "Bridge",
+            GameplayTerrain::Sea => "Sea",
+            GameplayTerrain::Shoal => "Shoal",
+            GameplayTerrain::Reef => "Reef",
+            GameplayTerrain::Property(_) => "Property",
+            GameplayTerrain::Pipe => "Pipe",
+            GameplayTerrain::PipeSeam => "Pipe Seam",
+            GameplayTerrain::PipeRubble => "Pipe Rubble",
+            GameplayTerrain::MissileSilo(_) => "Missile Silo",
+            GameplayTerrain::Teleporter => "Teleporter",
+        }
+    }
+}
+
 /// Terrain that represents the graphical representation. One can have tall
 /// mountains and stubby mountains, but functionally they act the same.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+///
+/// Ordering follows variant declaration order and then the wrapped value's
+/// own declaration order, with neutral properties ahead of owned ones. It is
+/// stable for sorted output such as snapshots and `BTreeMap` keys but carries
+/// no gameplay meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect))]
 pub enum GraphicalTerrain {
     // Basic terrains
@@ -579,6 +656,23 @@ impl GraphicalTerrain {
         }
     }
 
+    /// A numeric id for this terrain that doesn't depend on the platform or
+    /// compiler, for hashes that must be reproducible.
+    ///
+    /// The high byte is the AWBW terrain id. The low byte tells apart
+    /// graphical variants that share an AWBW id, such as stubby mountains and
+    /// sea and shoal orientations.
+    pub fn stable_id(self) -> u16 {
+        let variant = match self {
+            GraphicalTerrain::StubbyMoutain => 1,
+            GraphicalTerrain::Sea(direction) => direction as u8,
+            GraphicalTerrain::Shoal(direction) => direction as u8,
+            _ => 0,
+        };
+        let awbw_id = u8::from(AwbwTerrainId::from(self.as_terrain()));
+        u16::from_be_bytes([awbw_id, variant])
+    }
+
     /// Defense star bonus applied when a unit is on this terrain type.
     pub const fn defense_stars(self) -> u8 {
         match self {
@@ -593,7 +687,7 @@ impl GraphicalTerrain {
 }
 
 /// Movement terrain represents terrain types from a movement perspective,
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, strum::VariantArray)]
 #[repr(u8)]
 pub enum MovementTerrain {
     Plains,         // Basic open terrain
@@ -608,6 +702,30 @@ pub enum MovementTerrain {
     Teleport,       // Teleporter tiles
 }
 
+impl MovementTerrain {
+    /// Row label for this terrain in the movement cost chart
+    pub const fn name(&self) -> &'static str {
+        match self {
+            MovementTerrain::Plains => "Plains",
+            MovementTerrain::Mountains => "Mountains",
+            MovementTerrain::Woods => "Woods",
+            MovementTerrain::Rivers => "Rivers",
+            MovementTerrain::Infrastructure => "Infrastructure",
+            MovementTerrain::Sea => "Sea",
+            MovementTerrain::Shoals => "Shoals",
+            MovementTerrain::Reefs => "Reefs",
+            MovementTerrain::Pipes => "Pipes",
+            MovementTerrain::Teleport => "Teleport",
+        }
+    }
+}
+
+impl std::fmt::Display for MovementTerrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 impl From<AwbwTerrain> for MovementTerrain {
     /// Convert from detailed Terrain type to simplified MovementTerrain
     fn from(terrain: AwbwTerrain) -> Self {
@@ -670,6 +788,109 @@ impl From<GraphicalTerrain> for MovementTerrain {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_graphical_terrain_ordering() {
+        let orange_star = Faction::Player(PlayerFaction::OrangeStar);
+        let terrains = [
+            GraphicalTerrain::Shoal(ShoalDirection::AEAS),
+            GraphicalTerrain::Property(Property::City(orange_star)),
+            GraphicalTerrain::Sea(SeaDirection::E),
+            GraphicalTerrain::Plain,
+            GraphicalTerrain::Property(Property::City(Faction::Neutral)),
+            GraphicalTerrain::River(RiverType::Vertical),
+            GraphicalTerrain::River(RiverType::Horizontal),
+            GraphicalTerrain::StubbyMoutain,
+        ];
+
+        let mut sorted = terrains.to_vec();
+        sorted.sort();
+        let mut reversed = terrains.iter().rev().copied().collect::<Vec<_>>();
+        reversed.sort();
+        assert_eq!(sorted, reversed);
+
+        assert_eq!(
+            sorted,
+            [
+                GraphicalTerrain::StubbyMoutain,
+                GraphicalTerrain::Plain,
+                GraphicalTerrain::River(RiverType::Horizontal),
+                GraphicalTerrain::River(RiverType::Vertical),
+                GraphicalTerrain::Property(Property::City(Faction::Neutral)),
+                GraphicalTerrain::Property(Property::City(orange_star)),
+                GraphicalTerrain::Sea(SeaDirection::E),
+                GraphicalTerrain::Shoal(ShoalDirection::AEAS),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_property_repairs() {
+        let owned = Faction::Player(PlayerFaction::OrangeStar);
+        let hq = Property::HQ(PlayerFaction::OrangeStar);
+
+        for ground in [Property::City(owned), Property::Base(owned), hq] {
+            assert!(ground.repairs(Unit::Infantry));
+            assert!(ground.repairs(Unit::Tank));
+            assert!(!ground.repairs(Unit::Fighter));
+            assert!(!ground.repairs(Unit::Battleship));
+            assert_eq!(ground.heal_amount(), 2);
+        }
+
+        let airport = Property::Airport(owned);
+        assert!(airport.repairs(Unit::BCopter));
+        assert!(!airport.repairs(Unit::Infantry));
+        assert!(!airport.repairs(Unit::Lander));
+
+        let port = Property::Port(owned);
+        assert!(port.repairs(Unit::Lander));
+        assert!(!port.repairs(Unit::Tank));
+        assert!(!port.repairs(Unit::Bomber));
+
+        for inert in [
+            Property::ComTower(owned),
+            Property::Lab(owned),
+            Property::City(Faction::Neutral),
+        ] {
+            assert!(!inert.repairs(Unit::Infantry));
+            assert_eq!(inert.heal_amount(), 0);
+        }
+    }
+
+    #[test]
+    fn test_property_income() {
+        let owned = Faction::Player(PlayerFaction::OrangeStar);
+
+        assert_eq!(Property::City(owned).income(PROPERTY_INCOME), 1000);
+        assert_eq!(Property::Base(owned).income(PROPERTY_INCOME), 1000);
+        assert_eq!(Property::Airport(owned).income(PROPERTY_INCOME), 1000);
+        assert_eq!(Property::Port(owned).income(2000), 2000);
+        assert_eq!(
+            Property::HQ(PlayerFaction::BlueMoon).income(PROPERTY_INCOME),
+            1000
+        );
+
+        assert_eq!(Property::City(Faction::Neutral).income(PROPERTY_INCOME), 0);
+        assert_eq!(Property::Base(Faction::Neutral).income(PROPERTY_INCOME), 0);
+        assert_eq!(Property::ComTower(owned).income(PROPERTY_INCOME), 0);
+        assert_eq!(Property::Lab(owned).income(PROPERTY_INCOME), 0);
+    }
+
+    #[test]
+    fn test_movement_terrain_names() {
+        use strum::VariantArray;
+
+        let names = MovementTerrain::VARIANTS
+            .iter()
+            .map(|terrain| terrain.to_string())
+            .collect::<Vec<_>>();
+        assert!(names.iter().all(|name| !name.is_empty()));
+        assert_eq!(names.first().map(String::as_str), Some("Plains"));
+        assert_eq!(MovementTerrain::Sea.to_string(), "Sea");
+
+        let unique = names.iter().collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), names.len());
+    }
+
     #[test]
     fn test_movement_terrain() {
         assert_eq!(
@@ -360,6 +360,20 @@ impl Unit {
         }
     }
 
+    /// Cost, mobility, vision, fuel and attack range in one place, for unit
+    /// tooltips.
+    pub const fn summary(self) -> UnitSummary {
+        UnitSummary {
+            cost: self.base_cost(),
+            movement_range: self.movement_range(),
+            movement_type: self.movement_type(),
+            vision: self.base_vision(),
+            max_fuel: self.max_fuel(),
+            attack_range_min: self.attack_range_min(),
+            attack_range_max: self.attack_range_max(),
+        }
+    }
+
     /// Returns true for indirect-fire units (Artillery, Battleship, Rocket, etc.)
     /// that cannot attack after moving.
     pub const fn is_indirect(self) -> bool {
@@ -367,6 +381,24 @@ impl Unit {
     }
 }
 
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A unit type's base stats, as returned by [`Unit::summary`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UnitSummary {
+    pub cost: u32,
+    pub movement_range: u8,
+    pub movement_type: UnitMovement,
+    pub vision: u32,
+    pub max_fuel: u32,
+    pub attack_range_min: u32,
+    pub attack_range_max: u32,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum GraphicalMovement {
     Idle,
@@ -374,3 +406,46 @@ pub enum GraphicalMovement {
     Down,
     Lateral,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Unit;
+    use strum::VariantArray;
+
+    #[test]
+    fn name_uses_human_readable_spelling() {
+        assert_eq!(Unit::Infantry.name(), "Infantry");
+        assert_eq!(Unit::AntiAir.name(), "Anti-Air");
+        assert_eq!(Unit::MdTank.name(), "MD Tank");
+        assert_eq!(Unit::TCopter.name(), "T-Copter");
+    }
+
+    #[test]
+    fn display_matches_name() {
+        for unit in Unit::VARIANTS {
+            assert_eq!(format!("{}", unit), unit.name());
+        }
+        assert_eq!(Unit::AntiAir.to_string(), "Anti-Air");
+    }
+
+    #[test]
+    fn summary_matches_accessors() {
+        for unit in [Unit::Infantry, Unit::Artillery, Unit::Carrier] {
+            let summary = unit.summary();
+            assert_eq!(summary.cost, unit.base_cost());
+            assert_eq!(summary.movement_range, unit.movement_range());
+            assert_eq!(summary.movement_type, unit.movement_type());
+            assert_eq!(summary.vision, unit.base_vision());
+            assert_eq!(summary.max_fuel, unit.max_fuel());
+            assert_eq!(summary.attack_range_min, unit.attack_range_min());
+            assert_eq!(summary.attack_range_max, unit.attack_range_max());
+        }
+
+        let artillery = Unit::Artillery.summary();
+        assert_eq!(artillery.cost, 6000);
+        assert_eq!(
+            (artillery.attack_range_min, artillery.attack_range_max),
+            (2, 3)
+        );
+    }
+}
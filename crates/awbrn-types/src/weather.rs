@@ -1,7 +1,71 @@
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Default)]
+use crate::MovementTerrain;
+
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Default, strum::VariantArray,
+)]
 pub enum Weather {
     #[default]
     Clear,
     Rain,
     Snow,
 }
+
+impl Weather {
+    /// Rain shortens every unit's vision by one tile in fog of war.
+    pub const fn reduces_vision(&self) -> bool {
+        matches!(self, Weather::Rain)
+    }
+
+    /// Snow raises movement costs across most of the map.
+    pub const fn increases_movement_cost(&self) -> bool {
+        matches!(self, Weather::Snow)
+    }
+
+    /// Whether the weather changes the cost of moving across the terrain for
+    /// at least one movement type.
+    ///
+    /// Rain only bogs down wheels and treads in plains and woods, while snow
+    /// slows movement everywhere except pipes and teleporters.
+    pub const fn affects(&self, terrain: MovementTerrain) -> bool {
+        match self {
+            Weather::Clear => false,
+            Weather::Rain => matches!(terrain, MovementTerrain::Plains | MovementTerrain::Woods),
+            Weather::Snow => !matches!(terrain, MovementTerrain::Pipes | MovementTerrain::Teleport),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::VariantArray;
+
+    #[test]
+    fn clear_weather_has_no_effect() {
+        assert!(!Weather::Clear.reduces_vision());
+        assert!(!Weather::Clear.increases_movement_cost());
+        for terrain in MovementTerrain::VARIANTS {
+            assert!(!Weather::Clear.affects(*terrain));
+        }
+    }
+
+    #[test]
+    fn rain_reduces_vision_and_slows_plains_and_woods() {
+        assert!(Weather::Rain.reduces_vision());
+        assert!(!Weather::Rain.increases_movement_cost());
+        assert!(Weather::Rain.affects(MovementTerrain::Plains));
+        assert!(Weather::Rain.affects(MovementTerrain::Woods));
+        assert!(!Weather::Rain.affects(MovementTerrain::Mountains));
+        assert!(!Weather::Rain.affects(MovementTerrain::Sea));
+    }
+
+    #[test]
+    fn snow_increases_movement_cost() {
+        assert!(!Weather::Snow.reduces_vision());
+        assert!(Weather::Snow.increases_movement_cost());
+        assert!(Weather::Snow.affects(MovementTerrain::Plains));
+        assert!(Weather::Snow.affects(MovementTerrain::Mountains));
+        assert!(Weather::Snow.affects(MovementTerrain::Sea));
+        assert!(!Weather::Snow.affects(MovementTerrain::Pipes));
+    }
+}
@@ -1,10 +1,15 @@
+use awbrn_client::features::{AnimationsPaused, HoveredTile};
+use awbrn_client::modes::replay::seek::ReplaySeek;
 use awbrn_client::{
-    AwbrnPlugin, EventSink, MapAssetPathResolver, MapDimensions, NewDay, PendingGameStart,
-    PendingMatchMap, PlayerRosterSnapshot, ReplayLoaded, ReplayToLoad, StaticAssetPathResolver,
-    TileSelected, UnitBuilt, UnitMoved, core::coords::LogicalPx,
+    AwbrnPlugin, EventSink, MapDimensions, NewDay, PendingGameStart, PendingMatchMap,
+    PlayerRosterSnapshot, PrefixMapAssetPathResolver, ReplayLoaded, ReplayToLoad, SelectionChanged,
+    StaticAssetPathResolver, TileHovered, TileSelected, UnitBuilt, UnitMoved,
+    core::coords::LogicalPx, features::camera::canvas_to_world, features::input::TileClicked,
 };
-use awbrn_map::AwbwMapData;
-use awbrn_types::{AwbwGamePlayerId, PlayerFaction};
+use awbrn_game::world::GameMap;
+use awbrn_map::{AwbwMapData, Position};
+use awbrn_types::{AwbwGamePlayerId, Faction, PlayerFaction, Unit, Weather};
+use awbw_replay::turn_models::ActionKind;
 use bevy::{
     app::PluginsState,
     input::{
@@ -14,7 +19,9 @@ use bevy::{
         touch::{TouchInput, TouchPhase},
     },
     prelude::*,
-    window::{CursorLeft, CursorMoved, RawHandleWrapper, WindowResolution, WindowWrapper},
+    window::{
+        CursorLeft, CursorMoved, RawHandleWrapper, WindowFocused, WindowResolution, WindowWrapper,
+    },
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, sync::Arc};
@@ -26,6 +33,9 @@ mod offscreen_window_handle;
 mod web_key_code_generated;
 
 const AWBW_API_ASSET_SOURCE: &str = "awbw_api";
+const DEFAULT_ASSET_ROOT: &str = "../../assets";
+const DEFAULT_MAP_URL_PATH: &str = "api/awbw/map";
+const DEFAULT_MAP_EXTENSION: &str = "json";
 
 #[cfg(target_arch = "wasm32")]
 use bevy::asset::{
@@ -42,6 +52,8 @@ pub enum GameEvent {
     UnitMoved(UnitMoved),
     UnitBuilt(UnitBuilt),
     TileSelected(TileSelected),
+    TileHovered(TileHovered),
+    SelectionChanged(SelectionChanged),
     MapDimensions(MapDimensions),
     ReplayLoaded(ReplayLoaded),
     PlayerRosterUpdated(PlayerRosterSnapshot),
@@ -76,6 +88,10 @@ pub struct CanvasDisplay {
     width: f32,
     height: f32,
     scale_factor: f32,
+    /// sRGB color covering the canvas until a map is in game.
+    #[serde(default)]
+    #[tsify(optional)]
+    loading_color: Option<[u8; 3]>,
 }
 
 #[derive(Resource, Copy, Clone, Debug, Deserialize, Serialize, tsify::Tsify)]
@@ -94,11 +110,283 @@ pub struct MouseButtonEvent {
     button: i16,
 }
 
+/// Map dimensions in tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, tsify::Tsify)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct MapSize {
+    width: u32,
+    height: u32,
+}
+
+/// Terrain details for a single map tile.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, tsify::Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct TileInfo {
+    name: String,
+    defense_stars: u8,
+    /// Gameplay category such as "Plain", "Road" or "Property".
+    gameplay_type: String,
+    /// Name of the owning faction for player-owned properties.
+    owner: Option<String>,
+    capturable: bool,
+}
+
+/// Base stats of a unit type, for unit tooltips.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, tsify::Tsify)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitInfo {
+    name: String,
+    cost: u32,
+    movement_range: u8,
+    /// Movement class such as "Foot", "Treads" or "Air".
+    movement_type: String,
+    vision: u32,
+    max_fuel: u32,
+    attack_range_min: u32,
+    attack_range_max: u32,
+}
+
+impl From<Unit> for UnitInfo {
+    fn from(unit: Unit) -> Self {
+        let summary = unit.summary();
+        UnitInfo {
+            name: unit.name().to_string(),
+            cost: summary.cost,
+            movement_range: summary.movement_range,
+            movement_type: summary.movement_type.name().to_string(),
+            vision: summary.vision,
+            max_fuel: summary.max_fuel,
+            attack_range_min: summary.attack_range_min,
+            attack_range_max: summary.attack_range_max,
+        }
+    }
+}
+
+/// Problems hit while playing back the replay, e.g. actions that couldn't be
+/// rendered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, tsify::Tsify)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaybackDiagnostics {
+    entries: Vec<PlaybackDiagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, tsify::Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaybackDiagnostic {
+    /// Index of the replay action that produced the problem, when known.
+    action_index: Option<u32>,
+    message: String,
+}
+
+/// A tile coordinate on the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, tsify::Tsify)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct TileCoordinate {
+    x: u32,
+    y: u32,
+}
+
+fn hovered_tile(world: &World) -> Option<TileCoordinate> {
+    let position = world.get_resource::<HoveredTile>()?.0?;
+    Some(TileCoordinate {
+        x: position.x as u32,
+        y: position.y as u32,
+    })
+}
+
+fn pick_tile(world: &World, x: f32, y: f32) -> Option<TileCoordinate> {
+    let game_map = world.get_resource::<GameMap>()?;
+    let window = world.try_query::<&Window>()?.single(world).ok()?;
+    let (camera_transform, projection) = world
+        .try_query_filtered::<(&Transform, &Projection), With<Camera2d>>()?
+        .single(world)
+        .ok()?;
+
+    let position = canvas_to_world(camera_transform, projection, window, LogicalPx::new(x, y))
+        .to_map_position(game_map)?;
+    Some(TileCoordinate {
+        x: position.x() as u32,
+        y: position.y() as u32,
+    })
+}
+
+fn select_tile(world: &mut World, x: f32, y: f32) -> Option<TileInfo> {
+    let tile = pick_tile(world, x, y)?;
+    let _ = world.write_message(TileClicked {
+        position: Position::new(tile.x as usize, tile.y as usize),
+    });
+    tile_info(world, tile.x, tile.y)
+}
+
+fn playback_diagnostics(world: &World) -> PlaybackDiagnostics {
+    let entries = world
+        .get_resource::<awbrn_game::replay::PlaybackDiagnostics>()
+        .map(|diagnostics| {
+            diagnostics
+                .0
+                .iter()
+                .map(|diagnostic| PlaybackDiagnostic {
+                    action_index: diagnostic.action_index,
+                    message: diagnostic.message.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    PlaybackDiagnostics { entries }
+}
+
+fn export_map(world: &World) -> Option<String> {
+    let game_map = world.get_resource::<GameMap>()?;
+    Some(game_map.to_awbw_map().to_awbw_text())
+}
+
+fn map_size(world: &World) -> Option<MapSize> {
+    let game_map = world.get_resource::<GameMap>()?;
+    Some(MapSize {
+        width: game_map.width() as u32,
+        height: game_map.height() as u32,
+    })
+}
+
+fn tile_info(world: &World, x: u32, y: u32) -> Option<TileInfo> {
+    let game_map = world.get_resource::<GameMap>()?;
+    let position = Position::new(x as usize, y as usize);
+    if position.x >= game_map.width() || position.y >= game_map.height() {
+        return None;
+    }
+
+    let terrain = game_map.terrain_at(position)?;
+    let awbw_terrain = terrain.as_terrain();
+    let owner = match awbw_terrain.owner() {
+        Some(Faction::Player(faction)) => Some(faction.name().to_string()),
+        _ => None,
+    };
+
+    Some(TileInfo {
+        name: awbw_terrain.name().to_string(),
+        defense_stars: terrain.defense_stars(),
+        gameplay_type: awbw_terrain.gameplay_type().name().to_string(),
+        owner,
+        capturable: awbw_terrain.is_capturable(),
+    })
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, tsify::Tsify)]
+#[tsify(from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum WeatherKind {
+    Clear,
+    Rain,
+    Snow,
+}
+
+impl From<WeatherKind> for Weather {
+    fn from(value: WeatherKind) -> Self {
+        match value {
+            WeatherKind::Clear => Weather::Clear,
+            WeatherKind::Rain => Weather::Rain,
+            WeatherKind::Snow => Weather::Snow,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, tsify::Tsify)]
+#[tsify(from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum ReplayActionKind {
+    AttackSeam,
+    Build,
+    Capture,
+    End,
+    Fire,
+    Join,
+    Load,
+    Move,
+    Power,
+    Repair,
+    Resign,
+    Supply,
+    Unload,
+    Delete,
+    Hide,
+    Unhide,
+    Tag,
+    Unknown,
+}
+
+impl From<ReplayActionKind> for ActionKind {
+    fn from(value: ReplayActionKind) -> Self {
+        match value {
+            ReplayActionKind::AttackSeam => ActionKind::AttackSeam,
+            ReplayActionKind::Build => ActionKind::Build,
+            ReplayActionKind::Capture => ActionKind::Capture,
+            ReplayActionKind::End => ActionKind::End,
+            ReplayActionKind::Fire => ActionKind::Fire,
+            ReplayActionKind::Join => ActionKind::Join,
+            ReplayActionKind::Load => ActionKind::Load,
+            ReplayActionKind::Move => ActionKind::Move,
+            ReplayActionKind::Power => ActionKind::Power,
+            ReplayActionKind::Repair => ActionKind::Repair,
+            ReplayActionKind::Resign => ActionKind::Resign,
+            ReplayActionKind::Supply => ActionKind::Supply,
+            ReplayActionKind::Unload => ActionKind::Unload,
+            ReplayActionKind::Delete => ActionKind::Delete,
+            ReplayActionKind::Hide => ActionKind::Hide,
+            ReplayActionKind::Unhide => ActionKind::Unhide,
+            ReplayActionKind::Tag => ActionKind::Tag,
+            ReplayActionKind::Unknown => ActionKind::Unknown,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, tsify::Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
 pub struct GameAssetConfig {
     static_asset_urls: BTreeMap<String, String>,
+    /// Base URL or path that relative asset paths are loaded from. Defaults to
+    /// the repository's `assets` directory relative to the web app.
+    #[serde(default)]
+    #[tsify(optional)]
+    asset_root: Option<String>,
+    /// Base URL that maps are loaded from as `{mapUrlBase}/{mapId}.{mapExtension}`.
+    /// Defaults to the AWBW map API proxy.
+    #[serde(default)]
+    #[tsify(optional)]
+    map_url_base: Option<String>,
+    /// File extension of map files. Defaults to `json`.
+    #[serde(default)]
+    #[tsify(optional)]
+    map_extension: Option<String>,
+}
+
+impl GameAssetConfig {
+    fn asset_plugin(&self) -> AssetPlugin {
+        AssetPlugin {
+            file_path: self
+                .asset_root
+                .clone()
+                .unwrap_or_else(|| String::from(DEFAULT_ASSET_ROOT)),
+            meta_check: bevy::asset::AssetMetaCheck::Never,
+            ..AssetPlugin::default()
+        }
+    }
+
+    fn map_resolver(&self) -> PrefixMapAssetPathResolver {
+        PrefixMapAssetPathResolver::new(
+            self.map_url_base
+                .clone()
+                .unwrap_or_else(|| format!("{AWBW_API_ASSET_SOURCE}://{DEFAULT_MAP_URL_PATH}")),
+            self.map_extension
+                .clone()
+                .unwrap_or_else(|| String::from(DEFAULT_MAP_EXTENSION)),
+        )
+    }
 }
 
 struct WasmStaticAssetPathResolver {
@@ -120,14 +408,6 @@ impl StaticAssetPathResolver for WasmStaticAssetPathResolver {
     }
 }
 
-struct WasmMapAssetPathResolver;
-
-impl MapAssetPathResolver for WasmMapAssetPathResolver {
-    fn resolve_path(&self, map_id: u32) -> String {
-        format!("{AWBW_API_ASSET_SOURCE}://api/awbw/map/{map_id}.json")
-    }
-}
-
 #[cfg(target_arch = "wasm32")]
 fn register_awbw_asset_source(app: &mut App) {
     app.register_asset_source(
@@ -171,11 +451,7 @@ impl BevyApp {
                     ..Default::default()
                 })
                 .set(ImagePlugin::default_nearest())
-                .set(AssetPlugin {
-                    file_path: String::from("../../assets"),
-                    meta_check: bevy::asset::AssetMetaCheck::Never,
-                    ..AssetPlugin::default()
-                })
+                .set(asset_config.asset_plugin())
                 // The URLs we reference are on the same origin and controlled
                 // by the asset manifest on the JS side.
                 .set(bevy::asset::io::web::WebAssetPlugin {
@@ -184,10 +460,14 @@ impl BevyApp {
         )
         .add_systems(PreStartup, setup_added_window);
 
-        let awbrn_plugin = AwbrnPlugin::new(Arc::new(WasmMapAssetPathResolver))
+        let awbrn_plugin = AwbrnPlugin::new(Arc::new(asset_config.map_resolver()))
             .with_static_asset_resolver(Arc::new(WasmStaticAssetPathResolver::new(
                 asset_config.static_asset_urls,
             )));
+        let awbrn_plugin = match display.loading_color {
+            Some([r, g, b]) => awbrn_plugin.with_loading_color(Color::srgb_u8(r, g, b)),
+            None => awbrn_plugin,
+        };
 
         app.add_plugins(awbrn_plugin);
 
@@ -209,6 +489,8 @@ impl BevyApp {
             wasm_sink!(UnitMoved, UnitMoved);
             wasm_sink!(UnitBuilt, UnitBuilt);
             wasm_sink!(TileSelected, TileSelected);
+            wasm_sink!(TileHovered, TileHovered);
+            wasm_sink!(SelectionChanged, SelectionChanged);
             wasm_sink!(MapDimensions, MapDimensions);
             wasm_sink!(ReplayLoaded, ReplayLoaded);
             wasm_sink!(PlayerRosterUpdated, PlayerRosterSnapshot);
@@ -418,6 +700,28 @@ impl BevyApp {
         });
     }
 
+    /// Tell the game whether the page is visible and focused. Losing focus
+    /// pauses animations and playback; regaining it resumes them unless the
+    /// user paused explicitly.
+    #[wasm_bindgen]
+    pub fn set_focused(&mut self, focused: bool) {
+        let world = self.app.world_mut();
+        let Some(window) = primary_window_entity(world) else {
+            return;
+        };
+        let _ = world.write_message(WindowFocused { window, focused });
+    }
+
+    /// Pause or resume animations and playback at the user's request. The
+    /// pause holds until lifted here, regardless of focus changes.
+    #[wasm_bindgen]
+    pub fn set_paused(&mut self, paused: bool) {
+        self.app
+            .world_mut()
+            .resource_mut::<AnimationsPaused>()
+            .by_user = paused;
+    }
+
     #[wasm_bindgen]
     pub fn handle_canvas_blur(&mut self) {
         let _ = self.app.world_mut().write_message(KeyboardFocusLost);
@@ -450,6 +754,91 @@ impl BevyApp {
         Ok(())
     }
 
+    /// Dimensions of the loaded map in tiles.
+    #[wasm_bindgen]
+    pub fn map_size(&self) -> Option<MapSize> {
+        map_size(self.app.world())
+    }
+
+    /// The loaded map in AWBW text format, for opening a replay's map in
+    /// the editor.
+    #[wasm_bindgen]
+    pub fn export_map(&self) -> Option<String> {
+        export_map(self.app.world())
+    }
+
+    /// Coordinate of the tile under canvas point `(x, y)` in CSS pixels
+    /// (`offsetX` / `offsetY`), or `None` when off the map. Lets JS hit-test
+    /// without routing pointer events through the app.
+    #[wasm_bindgen]
+    pub fn pick_tile(&self, x: f32, y: f32) -> Option<TileCoordinate> {
+        pick_tile(self.app.world(), x, y)
+    }
+
+    /// Select the tile under canvas point `(x, y)` in CSS pixels, as a click
+    /// there would, and return its terrain details. Returns `None` without
+    /// changing the selection when the point is off the map.
+    #[wasm_bindgen]
+    pub fn select_tile(&mut self, x: f32, y: f32) -> Option<TileInfo> {
+        select_tile(self.app.world_mut(), x, y)
+    }
+
+    /// Problems hit while playing back the current replay.
+    #[wasm_bindgen]
+    pub fn playback_diagnostics(&self) -> PlaybackDiagnostics {
+        playback_diagnostics(self.app.world())
+    }
+
+    /// Coordinate of the tile under the pointer, or `None` when off the map.
+    #[wasm_bindgen]
+    pub fn hovered_tile(&self) -> Option<TileCoordinate> {
+        hovered_tile(self.app.world())
+    }
+
+    /// Terrain details for the tile at `(x, y)`, or `None` when off the map.
+    #[wasm_bindgen]
+    pub fn terrain_at(&self, x: u32, y: u32) -> Option<TileInfo> {
+        tile_info(self.app.world(), x, y)
+    }
+
+    /// Base stats for the unit with the given AWBW name, such as "Md.Tank".
+    #[wasm_bindgen]
+    pub fn unit_info(&self, name: &str) -> Option<UnitInfo> {
+        Unit::from_awbw_name(name).map(UnitInfo::from)
+    }
+
+    #[wasm_bindgen]
+    pub fn set_weather(&mut self, weather: WeatherKind) {
+        self.app
+            .world_mut()
+            .write_message(awbrn_client::features::weather::SetWeather(weather.into()));
+    }
+
+    /// Play the replay forward to the next action of `kind`.
+    #[wasm_bindgen]
+    pub fn seek_next(&mut self, kind: ReplayActionKind) {
+        self.app
+            .world_mut()
+            .write_message(ReplaySeek::Next(kind.into()));
+    }
+
+    /// Seek back to the previous action of `kind`, replaying from the start.
+    #[wasm_bindgen]
+    pub fn seek_prev(&mut self, kind: ReplayActionKind) {
+        self.app
+            .world_mut()
+            .write_message(ReplaySeek::Prev(kind.into()));
+    }
+
+    /// Set the color drawn around the map.
+    #[wasm_bindgen]
+    pub fn set_background_color(&mut self, red: u8, green: u8, blue: u8) {
+        self.app
+            .insert_resource(awbrn_client::features::MapBackgroundColor(Color::srgb_u8(
+                red, green, blue,
+            )));
+    }
+
     #[wasm_bindgen]
     pub fn set_player_display_faction(
         &mut self,
@@ -543,3 +932,235 @@ fn setup_added_window(
 
     commands.entity(entity).insert(handle);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use awbrn_client::MapAssetPathResolver;
+    use awbrn_map::{AwbrnMap, AwbwMap};
+    use awbrn_types::{AwbwTerrain, GraphicalTerrain, Property};
+
+    fn world_with_map() -> World {
+        let mut map = AwbrnMap::new(3, 2, GraphicalTerrain::Plain);
+        map.set_terrain(
+            Position::new(2, 1),
+            GraphicalTerrain::Property(Property::City(Faction::Player(PlayerFaction::BlueMoon))),
+        );
+
+        let mut world = World::new();
+        world.init_resource::<GameMap>();
+        world.resource_mut::<GameMap>().set(map);
+        world
+    }
+
+    #[test]
+    fn map_size_reads_game_map() {
+        let world = world_with_map();
+
+        assert_eq!(
+            map_size(&world),
+            Some(MapSize {
+                width: 3,
+                height: 2
+            })
+        );
+    }
+
+    #[test]
+    fn tile_info_describes_known_tiles() {
+        let world = world_with_map();
+
+        assert_eq!(
+            tile_info(&world, 2, 1),
+            Some(TileInfo {
+                name: String::from("Blue Moon City"),
+                defense_stars: 3,
+                gameplay_type: String::from("Property"),
+                owner: Some(String::from("Blue Moon")),
+                capturable: true,
+            })
+        );
+        assert_eq!(
+            tile_info(&world, 0, 0),
+            Some(TileInfo {
+                name: String::from("Plain"),
+                defense_stars: 1,
+                gameplay_type: String::from("Plain"),
+                owner: None,
+                capturable: false,
+            })
+        );
+        assert_eq!(tile_info(&world, 3, 0), None);
+    }
+
+    #[test]
+    fn tile_info_round_trips_through_typescript_shape() {
+        let info = tile_info(&world_with_map(), 2, 1).unwrap();
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Blue Moon City",
+                "defenseStars": 3,
+                "gameplayType": "Property",
+                "owner": "Blue Moon",
+                "capturable": true,
+            })
+        );
+        assert_eq!(serde_json::from_value::<TileInfo>(json).unwrap(), info);
+    }
+
+    #[test]
+    fn export_map_yields_parseable_awbw_text() {
+        let world = world_with_map();
+
+        let exported = export_map(&world).unwrap();
+        let parsed = AwbwMap::parse_txt(&exported).unwrap();
+
+        assert_eq!(parsed.width(), 3);
+        assert_eq!(parsed.height(), 2);
+        assert_eq!(
+            parsed.terrain_at(Position::new(2, 1)),
+            Some(AwbwTerrain::Property(Property::City(Faction::Player(
+                PlayerFaction::BlueMoon
+            ))))
+        );
+        assert_eq!(export_map(&World::new()), None);
+    }
+
+    #[test]
+    fn hovered_tile_reads_pointer_coordinate() {
+        let mut world = world_with_map();
+        assert_eq!(hovered_tile(&world), None);
+
+        world.insert_resource(HoveredTile(Some(Position::new(2, 1))));
+        assert_eq!(hovered_tile(&world), Some(TileCoordinate { x: 2, y: 1 }));
+
+        world.insert_resource(HoveredTile(None));
+        assert_eq!(hovered_tile(&world), None);
+    }
+
+    fn world_with_camera() -> World {
+        let mut world = world_with_map();
+
+        // 200x100 device pixels at 2x is a 100x50 CSS pixel canvas
+        let mut resolution = WindowResolution::new(200, 100);
+        resolution.set_scale_factor_override(Some(2.0));
+        world.spawn(Window {
+            resolution,
+            ..default()
+        });
+        // Zoomed in 2x: each CSS pixel covers half a world unit
+        world.spawn((
+            Camera2d,
+            Transform::default(),
+            Projection::Orthographic(OrthographicProjection {
+                scale: 0.5,
+                ..OrthographicProjection::default_2d()
+            }),
+        ));
+        world
+    }
+
+    #[test]
+    fn pick_tile_converts_canvas_coordinates() {
+        assert_eq!(pick_tile(&world_with_map(), 50.0, 25.0), None);
+
+        let world = world_with_camera();
+
+        assert_eq!(
+            pick_tile(&world, 50.0, 25.0),
+            Some(TileCoordinate { x: 1, y: 0 })
+        );
+        assert_eq!(
+            pick_tile(&world, 80.0, 45.0),
+            Some(TileCoordinate { x: 2, y: 1 })
+        );
+        assert_eq!(pick_tile(&world, 0.0, 0.0), None);
+    }
+
+    #[test]
+    fn select_tile_requests_selection_and_describes_tile() {
+        let mut world = world_with_camera();
+        world.init_resource::<Messages<TileClicked>>();
+
+        assert_eq!(
+            select_tile(&mut world, 80.0, 45.0).map(|info| info.name),
+            Some(String::from("Blue Moon City"))
+        );
+        assert_eq!(select_tile(&mut world, 0.0, 0.0), None);
+
+        let clicks = world.resource::<Messages<TileClicked>>();
+        assert_eq!(
+            clicks.iter_current_update_messages().collect::<Vec<_>>(),
+            vec![&TileClicked {
+                position: Position::new(2, 1)
+            }]
+        );
+    }
+
+    #[test]
+    fn playback_diagnostics_reads_recorded_entries() {
+        let mut world = World::new();
+        assert_eq!(playback_diagnostics(&world).entries, Vec::new());
+
+        world.insert_resource(awbrn_game::replay::PlaybackDiagnostics(vec![
+            awbrn_game::replay::PlaybackDiagnostic {
+                action_index: Some(3),
+                unhandled_action: Some("Unknown"),
+                message: String::from("Unhandled Unknown action"),
+            },
+        ]));
+
+        assert_eq!(
+            serde_json::to_value(playback_diagnostics(&world)).unwrap(),
+            serde_json::json!({
+                "entries": [{"actionIndex": 3, "message": "Unhandled Unknown action"}],
+            })
+        );
+    }
+
+    #[test]
+    fn asset_plugin_defaults_to_repository_assets() {
+        let config: GameAssetConfig = serde_json::from_str(r#"{"staticAssetUrls": {}}"#).unwrap();
+
+        assert_eq!(config.asset_plugin().file_path, "../../assets");
+    }
+
+    #[test]
+    fn asset_plugin_uses_configured_asset_root() {
+        let config: GameAssetConfig = serde_json::from_str(
+            r#"{"staticAssetUrls": {}, "assetRoot": "https://cdn.example.com/awbrn"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.asset_plugin().file_path,
+            "https://cdn.example.com/awbrn"
+        );
+    }
+
+    #[test]
+    fn map_resolver_defaults_to_awbw_api() {
+        let config: GameAssetConfig = serde_json::from_str(r#"{"staticAssetUrls": {}}"#).unwrap();
+
+        assert_eq!(
+            config.map_resolver().resolve_path(162795),
+            "awbw_api://api/awbw/map/162795.json"
+        );
+    }
+
+    #[test]
+    fn map_resolver_uses_configured_base_and_extension() {
+        let config: GameAssetConfig = serde_json::from_str(
+            r#"{"staticAssetUrls": {}, "mapUrlBase": "https://cdn.example.com/maps/", "mapExtension": "json.gz"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.map_resolver().resolve_path(162795),
+            "https://cdn.example.com/maps/162795.json.gz"
+        );
+    }
+}
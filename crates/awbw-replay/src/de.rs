@@ -20,7 +20,19 @@ where
         type Value = Vec<T1>;
 
         fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            formatter.write_str("a map containing key value tuples")
+            formatter.write_str("a map containing key value tuples or a sequence")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let mut values = Vec::new();
+            while let Some(value) = seq.next_element()? {
+                values.push(value);
+            }
+
+            Ok(values)
         }
 
         fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -37,7 +49,7 @@ where
         }
     }
 
-    deserializer.deserialize_map(VecVisitor {
+    deserializer.deserialize_any(VecVisitor {
         marker: PhantomData,
     })
 }
@@ -5,6 +5,16 @@ pub struct DeserializationContext {
     pub entry_kind: EntryKind,
 }
 
+impl DeserializationContext {
+    /// Index of the action being parsed, when parsing one.
+    pub fn action_index(&self) -> Option<usize> {
+        match self.entry_kind {
+            EntryKind::Turn { action_index, .. } => action_index,
+            EntryKind::Game { .. } => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum EntryKind {
     Game {
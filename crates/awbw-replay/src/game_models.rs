@@ -55,6 +55,35 @@ pub struct AwbwGame {
     pub timers_max_turn: u32,
 }
 
+/// Parse an AWBW `YYYY-MM-DD HH:MM:SS` timestamp into seconds since the Unix
+/// epoch.
+///
+/// AWBW doesn't record a time zone, so only differences between timestamps
+/// are meaningful.
+pub fn parse_timestamp(value: &str) -> Option<u64> {
+    let (date, time) = value.split_once(' ')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<u64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let mut time = time.splitn(3, ':').map(str::parse::<u64>);
+    let (hour, minute, second) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+
+    // Days since the epoch for a proleptic Gregorian date (Howard Hinnant's
+    // `days_from_civil`), with the year starting in March.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (era * 146_097 + day_of_era).checked_sub(719_468)?;
+
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second)
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct AwbwPlayer {
     pub id: AwbwGamePlayerId,
@@ -162,6 +191,20 @@ pub enum CoPower {
     SuperPower,
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("1970-01-01 00:00:00"), Some(0));
+        assert_eq!(parse_timestamp("2000-03-01 00:00:00"), Some(951_868_800));
+        assert_eq!(parse_timestamp("2025-02-17 09:51:05"), Some(1_739_785_865));
+        assert_eq!(parse_timestamp("0000-00-00 00:00:00"), None);
+        assert_eq!(parse_timestamp("2025-02-17"), None);
+    }
+}
+
 mod player_faction_id {
     use awbrn_types::PlayerFaction;
     use serde::{Deserialize, Deserializer, Serializer};
@@ -2,8 +2,10 @@ mod de;
 mod errors;
 pub mod game_models;
 mod replay;
+mod summary;
 pub mod turn_models;
 
 pub use de::{Hidden, Masked};
 pub use errors::*;
 pub use replay::*;
+pub use summary::*;
@@ -1,17 +1,299 @@
 use crate::{
     errors::{self, ReplayError, ReplayErrorKind},
-    game_models::AwbwGame,
-    turn_models::Action,
+    game_models::{AwbwGame, parse_timestamp},
+    turn_models::{Action, ActionKind},
 };
+use awbrn_map::Position;
+use awbrn_types::{AwbwGamePlayerId, PlayerFaction, Unit};
 use phpserz::{PhpParser, PhpToken};
 use rawzip::{ZipSliceArchive, ZipVerification, path::ZipFilePath};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::{BufRead, Read};
+use std::time::Duration;
 
 #[derive(Debug, Serialize, PartialEq, Clone)]
 pub struct AwbwReplay {
     pub games: Vec<AwbwGame>,
     pub turns: Vec<Action>,
+
+    /// The untyped JSON of each action, parallel to `turns`. Only populated
+    /// when parsed with [`ReplayParser::with_raw_turns`].
+    #[serde(skip)]
+    raw_turns: Vec<serde_json::Value>,
+
+    /// Unit names a lenient parser replaced with
+    /// [`UNRECOGNIZED_UNIT_PLACEHOLDER`], in action order.
+    #[serde(skip)]
+    unrecognized_units: Vec<UnrecognizedUnit>,
+}
+
+/// A unit name in an action that didn't map to a known [`Unit`], so a lenient
+/// parser read it as [`UNRECOGNIZED_UNIT_PLACEHOLDER`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnrecognizedUnit {
+    /// Index of the action in [`AwbwReplay::turns`].
+    pub action_index: usize,
+    /// The unit name as it appeared in the replay.
+    pub name: String,
+}
+
+impl AwbwReplay {
+    pub fn new(games: Vec<AwbwGame>, turns: Vec<Action>) -> Self {
+        AwbwReplay {
+            games,
+            turns,
+            raw_turns: Vec::new(),
+            unrecognized_units: Vec::new(),
+        }
+    }
+
+    /// Returns the raw action JSON at the given action index, for inspecting
+    /// actions that fail to play back correctly. Always `None` unless the
+    /// replay was parsed with [`ReplayParser::with_raw_turns`].
+    pub fn raw_turn(&self, index: usize) -> Option<&serde_json::Value> {
+        self.raw_turns.get(index)
+    }
+
+    /// Unit names that were played back as [`UNRECOGNIZED_UNIT_PLACEHOLDER`]
+    /// because the parser didn't recognize them.
+    pub fn unrecognized_units(&self) -> &[UnrecognizedUnit] {
+        &self.unrecognized_units
+    }
+
+    /// Index of the first action of `kind` at or after `from`.
+    pub fn next_action_of_kind(&self, kind: ActionKind, from: usize) -> Option<usize> {
+        self.turns
+            .iter()
+            .enumerate()
+            .skip(from)
+            .find(|(_, action)| action.kind() == kind)
+            .map(|(index, _)| index)
+    }
+
+    /// Index of the last action of `kind` before `before`.
+    pub fn prev_action_of_kind(&self, kind: ActionKind, before: usize) -> Option<usize> {
+        self.turns
+            .iter()
+            .take(before)
+            .rposition(|action| action.kind() == kind)
+    }
+
+    /// The day `action_index` is played on, its position within that day,
+    /// and how many actions the day holds, or `None` past the last action.
+    ///
+    /// An action that ends the day counts towards the day it ends.
+    pub fn locate(&self, action_index: usize) -> Option<(u32, usize, usize)> {
+        let days = self.action_days();
+        let day = *days.get(action_index)?;
+        let start = days[..action_index]
+            .iter()
+            .rposition(|&other| other != day)
+            .map_or(0, |index| index + 1);
+        let end = days[action_index..]
+            .iter()
+            .position(|&other| other != day)
+            .map_or(days.len(), |offset| action_index + offset);
+        Some((day, action_index - start, end - start))
+    }
+
+    /// The day each action is played on, parallel to `turns`. Days advance
+    /// after the actions that pass the turn, like playback does.
+    fn action_days(&self) -> Vec<u32> {
+        let mut day = self.games.first().map_or(1, |game| game.day);
+        self.turns
+            .iter()
+            .map(|action| {
+                let played_on = day;
+                day = action.next_turn_day().unwrap_or(day);
+                played_on
+            })
+            .collect()
+    }
+
+    /// The units on the board before any action is played, with the faction
+    /// of the player that owns each one.
+    ///
+    /// Units owned by a player missing from the first snapshot are skipped.
+    pub fn initial_units(&self) -> Vec<(Position, Unit, PlayerFaction)> {
+        let Some(first_game) = self.games.first() else {
+            return Vec::new();
+        };
+
+        first_game
+            .units
+            .iter()
+            .filter_map(|unit| {
+                let player = first_game
+                    .players
+                    .iter()
+                    .find(|player| player.id == unit.players_id)?;
+                let position = Position::new(unit.x as usize, unit.y as usize);
+                Some((position, unit.name, player.faction))
+            })
+            .collect()
+    }
+
+    /// When each recorded turn started and how long the player took.
+    ///
+    /// Turns whose start time is missing or malformed are skipped. The final
+    /// turn only has a duration once the game has ended.
+    pub fn turn_timestamps(&self) -> Vec<TurnTimestamp> {
+        let starts: Vec<_> = self
+            .games
+            .iter()
+            .filter_map(|game| Some((game, parse_timestamp(&game.activity_date)?)))
+            .collect();
+
+        let end = self
+            .games
+            .last()
+            .and_then(|game| game.end_date.as_deref())
+            .and_then(parse_timestamp);
+
+        starts
+            .iter()
+            .enumerate()
+            .map(|(index, &(game, started_at))| {
+                let finished_at = starts.get(index + 1).map(|(_, next)| *next).or(end);
+                TurnTimestamp {
+                    day: game.day,
+                    player_id: AwbwGamePlayerId::new(game.turn),
+                    started_at,
+                    duration: finished_at
+                        .and_then(|finished_at| finished_at.checked_sub(started_at))
+                        .map(Duration::from_secs),
+                }
+            })
+            .collect()
+    }
+
+    /// The turn at which each eliminated player was first recorded as
+    /// eliminated, in order of elimination.
+    ///
+    /// The turn is an index into `games`, the per-turn snapshots. Players
+    /// still in the game at the end of the replay are omitted.
+    pub fn eliminations(&self) -> Vec<(AwbwGamePlayerId, usize)> {
+        let mut eliminations: Vec<(AwbwGamePlayerId, usize)> = Vec::new();
+        for (turn, game) in self.games.iter().enumerate() {
+            for player in game.players.iter().filter(|player| player.eliminated) {
+                if !eliminations.iter().any(|(id, _)| *id == player.id) {
+                    eliminations.push((player.id, turn));
+                }
+            }
+        }
+        eliminations
+    }
+
+    /// Each player's funds at the end of each of their turns.
+    ///
+    /// Funds are taken from the turn's starting snapshot in `games`, which
+    /// already includes that turn's income, less the cost of every unit the
+    /// player built and plus any refund from joining units before the turn
+    /// passed. Entries are keyed by the turn's index into `games`.
+    pub fn funds_timeline(&self) -> HashMap<AwbwGamePlayerId, Vec<(usize, u32)>> {
+        let mut funds: Vec<Option<u32>> = self
+            .games
+            .iter()
+            .map(|game| {
+                let active = AwbwGamePlayerId::new(game.turn);
+                game.players
+                    .iter()
+                    .find(|player| player.id == active)
+                    .map(|player| player.funds)
+            })
+            .collect();
+
+        let mut turn = 0;
+        for action in &self.turns {
+            if let Some(turn_funds) = funds.get_mut(turn).and_then(Option::as_mut) {
+                match action {
+                    Action::Build { new_unit, .. } => {
+                        let cost = new_unit
+                            .values()
+                            .find_map(|unit| unit.get_value())
+                            .map(|unit| unit.units_cost.unwrap_or(unit.units_name.base_cost()));
+                        if let Some(cost) = cost {
+                            *turn_funds = turn_funds.saturating_sub(cost);
+                        }
+                    }
+                    Action::Join { join_action, .. } => {
+                        if let Some(&new_funds) = join_action.new_funds.values().next() {
+                            *turn_funds = new_funds;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if action.next_turn_day().is_some() {
+                turn += 1;
+            }
+        }
+
+        let mut timeline: HashMap<AwbwGamePlayerId, Vec<(usize, u32)>> = HashMap::new();
+        for (turn, (game, funds)) in self.games.iter().zip(funds).enumerate() {
+            if let Some(funds) = funds {
+                timeline
+                    .entry(AwbwGamePlayerId::new(game.turn))
+                    .or_default()
+                    .push((turn, funds));
+            }
+        }
+        timeline
+    }
+
+    /// Every unit built, as the turn it was built on, the base it was built
+    /// at, its type, and the building player.
+    ///
+    /// The turn is an index into `games`, the per-turn snapshots. Builds whose
+    /// unit details are hidden from every viewer are skipped.
+    pub fn production_log(&self) -> Vec<(usize, Position, Unit, AwbwGamePlayerId)> {
+        let mut log = Vec::new();
+        let mut turn = 0;
+        for action in &self.turns {
+            if action.next_turn_day().is_some() {
+                turn += 1;
+            }
+
+            let Action::Build { new_unit, .. } = action else {
+                continue;
+            };
+            let Some(unit) = new_unit.values().find_map(|unit| unit.get_value()) else {
+                continue;
+            };
+            let (Some(x), Some(y)) = (unit.units_x, unit.units_y) else {
+                continue;
+            };
+            log.push((
+                turn,
+                Position::new(x as usize, y as usize),
+                unit.units_name,
+                AwbwGamePlayerId::new(unit.units_players_id),
+            ));
+        }
+        log
+    }
+
+    /// Real time elapsed from the start of the game to its end, or to the
+    /// last recorded turn for games still in progress.
+    pub fn duration(&self) -> Option<Duration> {
+        let first = self.games.first()?;
+        let last = self.games.last()?;
+        let start = parse_timestamp(&first.start_date)?;
+        let end = parse_timestamp(last.end_date.as_deref().unwrap_or(&last.activity_date))?;
+        end.checked_sub(start).map(Duration::from_secs)
+    }
+}
+
+/// Start time of a single turn, from the replay's per-turn game snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnTimestamp {
+    pub day: u32,
+    pub player_id: AwbwGamePlayerId,
+    /// Seconds since the Unix epoch, in AWBW server time.
+    pub started_at: u64,
+    pub duration: Option<Duration>,
 }
 
 #[derive(Debug)]
@@ -216,9 +498,44 @@ impl<'a> ReplayEntry<'a, TurnKind> {
     }
 }
 
+/// The unit type a lenient [`ReplayParser`] substitutes for unit names it
+/// doesn't recognize, such as a unit type added to AWBW after this parser was
+/// written, so the rest of the action can still be played back.
+pub const UNRECOGNIZED_UNIT_PLACEHOLDER: Unit = Unit::Infantry;
+
+/// Replace every unit name in an action's JSON that doesn't map to a known
+/// [`Unit`] with [`UNRECOGNIZED_UNIT_PLACEHOLDER`], collecting the original
+/// names into `replaced`.
+fn replace_unrecognized_unit_names(value: &mut serde_json::Value, replaced: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    serde_json::Value::String(name)
+                        if matches!(key.as_str(), "units_name" | "unitName")
+                            && Unit::from_awbw_name(name).is_none() =>
+                    {
+                        let placeholder = UNRECOGNIZED_UNIT_PLACEHOLDER.name().to_string();
+                        replaced.push(std::mem::replace(name, placeholder));
+                    }
+                    _ => replace_unrecognized_unit_names(value, replaced),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                replace_unrecognized_unit_names(value, replaced);
+            }
+        }
+        _ => {}
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct ReplayParser {
     debug: bool,
+    strict: bool,
+    raw_turns: bool,
 }
 
 impl ReplayParser {
@@ -231,11 +548,167 @@ impl ReplayParser {
         self
     }
 
+    /// When strict, an action with an unrecognized kind or unit name is an
+    /// error. Otherwise (the default) an unrecognized kind is preserved as
+    /// [`Action::Unknown`] so playback can skip past it, and an unrecognized
+    /// unit name is read as [`UNRECOGNIZED_UNIT_PLACEHOLDER`] and reported
+    /// through [`AwbwReplay::unrecognized_units`].
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Keep the untyped JSON of every action, retrievable through
+    /// [`AwbwReplay::raw_turn`]. Off by default as it parses each action twice.
+    pub fn with_raw_turns(mut self, raw_turns: bool) -> Self {
+        self.raw_turns = raw_turns;
+        self
+    }
+
+    fn parse_raw_action(
+        element: &ActionData<'_>,
+        context: errors::DeserializationContext,
+    ) -> Result<serde_json::Value, errors::ReplayError> {
+        serde_json::from_slice(element.data()).map_err(|error| ReplayError {
+            kind: ReplayErrorKind::Json {
+                error,
+                path: None,
+                context: Some(context),
+            },
+        })
+    }
+
+    /// Parse an action, appending its untyped JSON to `raw_turns` when
+    /// retaining raw turns.
+    fn parse_turn(
+        &self,
+        element: &ActionData<'_>,
+        context: errors::DeserializationContext,
+        raw_turns: &mut Vec<serde_json::Value>,
+        unrecognized_units: &mut Vec<UnrecognizedUnit>,
+    ) -> Result<Action, errors::ReplayError> {
+        if self.raw_turns {
+            raw_turns.push(Self::parse_raw_action(element, context.clone())?);
+        }
+        self.parse_action(element, context, unrecognized_units)
+    }
+
+    fn parse_action(
+        &self,
+        element: &ActionData<'_>,
+        context: errors::DeserializationContext,
+        unrecognized_units: &mut Vec<UnrecognizedUnit>,
+    ) -> Result<Action, errors::ReplayError> {
+        let mut deser = element.deserializer();
+        let (result, path) = if self.debug {
+            let mut track = serde_path_to_error::Track::new();
+            let path_deser = serde_path_to_error::Deserializer::new(&mut deser, &mut track);
+            let result = Action::deserialize(path_deser);
+            (result, Some(track.path()))
+        } else {
+            (Action::deserialize(&mut deser), None)
+        };
+
+        result.or_else(|error| {
+            if self.strict {
+                return Err(ReplayError {
+                    kind: ReplayErrorKind::Json {
+                        error,
+                        path,
+                        context: Some(context),
+                    },
+                });
+            }
+
+            let mut raw = Self::parse_raw_action(element, context.clone())?;
+            let known_kind = raw
+                .get("action")
+                .and_then(serde_json::Value::as_str)
+                .map(|kind| ActionKind::from_name(kind).is_some());
+            let mut replaced = Vec::new();
+            if known_kind == Some(true) {
+                replace_unrecognized_unit_names(&mut raw, &mut replaced);
+            }
+            match known_kind {
+                Some(false) => Ok(Action::Unknown(raw)),
+                Some(true) if !replaced.is_empty() => {
+                    let action = Action::deserialize(&raw).map_err(|error| ReplayError {
+                        kind: ReplayErrorKind::Json {
+                            error,
+                            path: None,
+                            context: Some(context.clone()),
+                        },
+                    })?;
+                    let action_index = context.action_index().unwrap_or_default();
+                    unrecognized_units.extend(
+                        replaced
+                            .into_iter()
+                            .map(|name| UnrecognizedUnit { action_index, name }),
+                    );
+                    Ok(action)
+                }
+                _ => Err(ReplayError {
+                    kind: ReplayErrorKind::Json {
+                        error,
+                        path,
+                        context: Some(context),
+                    },
+                }),
+            }
+        })
+    }
+
+    /// Parse a replay from AWBW's zip download. Replays exported as plain
+    /// JSON, an object with the `games` and `turns` arrays in AWBW's own
+    /// encoding, are accepted too.
     pub fn parse(&self, data: &[u8]) -> Result<AwbwReplay, errors::ReplayError> {
-        let file = ReplayFile::open(data)?;
+        match ReplayFile::open(data) {
+            Ok(file) => self.parse_file(file),
+            Err(_) if looks_like_json(data) => self.parse_json(data),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn parse_json(&self, data: &[u8]) -> Result<AwbwReplay, errors::ReplayError> {
+        let replay: JsonReplay<'_> = serde_json::from_slice(data)?;
 
+        let mut turns = Vec::with_capacity(replay.turns.len());
+        let mut raw_turns = Vec::new();
+        let mut unrecognized_units = Vec::new();
+        for (action_index, turn) in replay.turns.into_iter().enumerate() {
+            let element = ActionData {
+                data: turn.get().as_bytes(),
+            };
+            let context = errors::DeserializationContext {
+                file_entry_index: 0,
+                entry_kind: errors::EntryKind::Turn {
+                    turn_index: 0,
+                    player_id: 0,
+                    day: 0,
+                    action_index: Some(action_index),
+                },
+            };
+            turns.push(self.parse_turn(
+                &element,
+                context,
+                &mut raw_turns,
+                &mut unrecognized_units,
+            )?);
+        }
+
+        Ok(AwbwReplay {
+            games: replay.games,
+            turns,
+            raw_turns,
+            unrecognized_units,
+        })
+    }
+
+    fn parse_file(&self, file: ReplayFile<&[u8]>) -> Result<AwbwReplay, errors::ReplayError> {
         let mut games = Vec::new();
         let mut turns = Vec::new();
+        let mut raw_turns = Vec::new();
+        let mut unrecognized_units = Vec::new();
         let mut buf = Vec::new();
 
         for (file_entry_index, file_entry) in file.iter().enumerate() {
@@ -298,43 +771,21 @@ impl ReplayParser {
                         let day = turn.day();
 
                         for element in turn.actions()? {
-                            let mut deser = element.deserializer();
-                            let action = if self.debug {
-                                let mut track = serde_path_to_error::Track::new();
-                                let path_deser =
-                                    serde_path_to_error::Deserializer::new(&mut deser, &mut track);
-                                Action::deserialize(path_deser).map_err(|error| ReplayError {
-                                    kind: ReplayErrorKind::Json {
-                                        error,
-                                        path: Some(track.path()),
-                                        context: Some(errors::DeserializationContext {
-                                            file_entry_index,
-                                            entry_kind: errors::EntryKind::Turn {
-                                                turn_index,
-                                                player_id,
-                                                day,
-                                                action_index: Some(turns.len()),
-                                            },
-                                        }),
-                                    },
-                                })?
-                            } else {
-                                Action::deserialize(&mut deser).map_err(|error| ReplayError {
-                                    kind: ReplayErrorKind::Json {
-                                        error,
-                                        path: None,
-                                        context: Some(errors::DeserializationContext {
-                                            file_entry_index,
-                                            entry_kind: errors::EntryKind::Turn {
-                                                turn_index,
-                                                player_id,
-                                                day,
-                                                action_index: Some(turns.len()),
-                                            },
-                                        }),
-                                    },
-                                })?
+                            let context = errors::DeserializationContext {
+                                file_entry_index,
+                                entry_kind: errors::EntryKind::Turn {
+                                    turn_index,
+                                    player_id,
+                                    day,
+                                    action_index: Some(turns.len()),
+                                },
                             };
+                            let action = self.parse_turn(
+                                &element,
+                                context,
+                                &mut raw_turns,
+                                &mut unrecognized_units,
+                            )?;
                             turns.push(action);
                         }
                         turn_index += 1;
@@ -343,10 +794,29 @@ impl ReplayParser {
             }
         }
 
-        Ok(AwbwReplay { games, turns })
+        Ok(AwbwReplay {
+            games,
+            turns,
+            raw_turns,
+            unrecognized_units,
+        })
     }
 }
 
+/// A replay exported as plain JSON rather than AWBW's zip of PHP data.
+#[derive(Deserialize)]
+struct JsonReplay<'a> {
+    games: Vec<AwbwGame>,
+    #[serde(borrow)]
+    turns: Vec<&'a serde_json::value::RawValue>,
+}
+
+fn looks_like_json(data: &[u8]) -> bool {
+    data.iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|&byte| byte == b'{')
+}
+
 pub struct TurnContent<'a> {
     player_id: u32,
     day: u32,
@@ -422,6 +892,174 @@ impl<'a> ActionData<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::turn_models::{TargetedPlayer, UnitProperty};
+
+    fn test_context() -> errors::DeserializationContext {
+        errors::DeserializationContext {
+            file_entry_index: 0,
+            entry_kind: errors::EntryKind::Turn {
+                turn_index: 0,
+                player_id: 0,
+                day: 1,
+                action_index: Some(0),
+            },
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_data_that_is_neither_zip_nor_json() {
+        assert!(ReplayParser::new().parse(b"not a replay").is_err());
+        assert!(ReplayParser::new().parse(b"  {\"games\": []}").is_err());
+        assert!(looks_like_json(b"\n {\"games\": [], \"turns\": []}"));
+    }
+
+    #[test]
+    fn test_lenient_parser_preserves_unknown_action() {
+        let data = br#"{"action":"Teleport","unit":1}"#;
+        let element = ActionData { data };
+        let raw: serde_json::Value = serde_json::from_slice(data).unwrap();
+
+        let action = ReplayParser::new()
+            .parse_action(&element, test_context(), &mut Vec::new())
+            .unwrap();
+
+        assert_eq!(action, Action::Unknown(raw));
+        assert_eq!(action.kind_name(), "Unknown");
+    }
+
+    #[test]
+    fn test_strict_parser_rejects_unknown_action() {
+        let data = br#"{"action":"Teleport","unit":1}"#;
+        let element = ActionData { data };
+
+        let result = ReplayParser::new().with_strict(true).parse_action(
+            &element,
+            test_context(),
+            &mut Vec::new(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    fn build_action_json(units_name: &str) -> String {
+        format!(
+            r#"{{"action":"Build","newUnit":{{"global":{{"units_id":170205832,"units_games_id":1362397,"units_players_id":1,"units_name":"{units_name}","units_movement_points":3,"units_vision":2,"units_fuel":99,"units_fuel_per_turn":0,"units_sub_dive":"N","units_ammo":0,"units_short_range":0,"units_long_range":0,"units_second_weapon":"N","units_symbol":"G","units_cost":1000,"units_movement_type":"F","units_x":5,"units_y":3,"units_moved":1,"units_capture":0,"units_fired":0,"units_hit_points":10,"units_cargo1_units_id":0,"units_cargo2_units_id":0,"units_carried":"N","countries_code":"os"}}}},"discovered":{{"global":null}}}}"#
+        )
+    }
+
+    fn built_unit(action: &Action) -> &UnitProperty {
+        let Action::Build { new_unit, .. } = action else {
+            panic!("expected a build action, got {action:?}");
+        };
+        new_unit[&TargetedPlayer::Global].get_value().unwrap()
+    }
+
+    #[test]
+    fn test_lenient_parser_keeps_action_with_unknown_unit() {
+        let known = build_action_json("Infantry");
+        let element = ActionData {
+            data: known.as_bytes(),
+        };
+        let known = ReplayParser::new()
+            .with_strict(true)
+            .parse_action(&element, test_context(), &mut Vec::new())
+            .unwrap();
+
+        let unknown = build_action_json("Hovercraft");
+        let element = ActionData {
+            data: unknown.as_bytes(),
+        };
+        let mut unrecognized_units = Vec::new();
+        let action = ReplayParser::new()
+            .parse_action(&element, test_context(), &mut unrecognized_units)
+            .unwrap();
+        assert_eq!(
+            built_unit(&action).units_name,
+            UNRECOGNIZED_UNIT_PLACEHOLDER
+        );
+        assert_eq!(
+            unrecognized_units,
+            vec![UnrecognizedUnit {
+                action_index: 0,
+                name: String::from("Hovercraft"),
+            }]
+        );
+        assert_eq!(built_unit(&action).units_id, built_unit(&known).units_id);
+
+        let result = ReplayParser::new().with_strict(true).parse_action(
+            &element,
+            test_context(),
+            &mut Vec::new(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_lenient_parse_reports_unrecognized_units() {
+        let replay = format!(
+            r#"{{"games":[],"turns":[{},{}]}}"#,
+            build_action_json("Infantry"),
+            build_action_json("Hovercraft")
+        );
+
+        let parsed = ReplayParser::new().parse(replay.as_bytes()).unwrap();
+
+        assert_eq!(
+            parsed.unrecognized_units(),
+            [UnrecognizedUnit {
+                action_index: 1,
+                name: String::from("Hovercraft"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lenient_parser_rejects_malformed_known_action() {
+        let data = br#"{"action":"End","updatedInfo":"bogus"}"#;
+        let element = ActionData { data };
+
+        let result = ReplayParser::new().parse_action(&element, test_context(), &mut Vec::new());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_raw_turns_reject_invalid_json() {
+        let element = ActionData {
+            data: b"{\"action\":",
+        };
+        let mut raw_turns = Vec::new();
+
+        let result = ReplayParser::new().with_raw_turns(true).parse_turn(
+            &element,
+            test_context(),
+            &mut raw_turns,
+            &mut Vec::new(),
+        );
+
+        assert!(result.is_err());
+        assert!(raw_turns.is_empty());
+    }
+
+    #[test]
+    fn test_raw_turns_are_opt_in() {
+        let element = ActionData {
+            data: br#"{"action":"Teleport","unit":1}"#,
+        };
+        let mut raw_turns = Vec::new();
+
+        ReplayParser::new()
+            .parse_turn(&element, test_context(), &mut raw_turns, &mut Vec::new())
+            .unwrap();
+        assert!(raw_turns.is_empty());
+
+        ReplayParser::new()
+            .with_raw_turns(true)
+            .parse_turn(&element, test_context(), &mut raw_turns, &mut Vec::new())
+            .unwrap();
+        assert_eq!(raw_turns.len(), 1);
+        assert_eq!(raw_turns[0]["action"], "Teleport");
+    }
 
     #[test]
     fn test_turn_header() {
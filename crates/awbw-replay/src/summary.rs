@@ -0,0 +1,153 @@
+//! Flattened replay summaries for front ends.
+//!
+//! The game models mirror AWBW's wire format. These types pick out what a
+//! replay browser needs and serialize as plain camelCase JSON so they can
+//! cross the JS boundary as is.
+
+use serde::{Deserialize, Serialize};
+
+use crate::AwbwReplay;
+use crate::game_models::{AwbwGame, AwbwPlayer};
+
+/// Overview of a replay: the game, its players, rules, and result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayHeader {
+    pub game_id: u32,
+    pub name: String,
+    pub map_id: u32,
+    pub start_date: String,
+    pub end_date: Option<String>,
+    /// The last day recorded in the replay.
+    pub days: u32,
+    /// Players in turn order.
+    pub players: Vec<PlayerInfo>,
+    pub outcome: GameOutcome,
+    pub features: ReplayFeatures,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerInfo {
+    pub player_id: u32,
+    pub user_id: u32,
+    pub order: u32,
+    /// Team letter in team games.
+    pub team: Option<String>,
+    pub faction_code: String,
+    pub faction_name: String,
+    pub co_name: Option<String>,
+    pub tag_co_name: Option<String>,
+    pub eliminated: bool,
+}
+
+/// How the game ended, as of the last snapshot in the replay.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum GameOutcome {
+    InProgress,
+    /// The game ended with these players still standing.
+    #[serde(rename_all = "camelCase")]
+    Finished {
+        winner_ids: Vec<u32>,
+    },
+}
+
+/// Game rules that change how a replay plays out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(target_family = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(target_family = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayFeatures {
+    pub fog: bool,
+    pub team: bool,
+    pub use_powers: bool,
+    pub official: bool,
+    pub starting_funds: u32,
+    pub funds_per_property: u32,
+    /// Properties needed to win, or 0 when capture wins are off.
+    pub capture_win: u32,
+}
+
+impl PlayerInfo {
+    fn new(player: &AwbwPlayer, team_game: bool) -> Self {
+        Self {
+            player_id: player.id.as_u32(),
+            user_id: player.users_id.as_u32(),
+            order: player.order,
+            team: team_game.then(|| player.team.clone()),
+            faction_code: player.faction.country_code().to_string(),
+            faction_name: player.faction.name().to_string(),
+            co_name: player.co().map(|co| co.name().to_string()),
+            tag_co_name: player
+                .tags_co_id
+                .and_then(awbrn_types::Co::from_awbw_id)
+                .map(|co| co.name().to_string()),
+            eliminated: player.eliminated,
+        }
+    }
+}
+
+impl GameOutcome {
+    fn from_game(game: &AwbwGame) -> Self {
+        if game.end_date.is_none() {
+            return GameOutcome::InProgress;
+        }
+
+        let winner_ids = game
+            .players
+            .iter()
+            .filter(|player| !player.eliminated)
+            .map(|player| player.id.as_u32())
+            .collect();
+        GameOutcome::Finished { winner_ids }
+    }
+}
+
+impl ReplayFeatures {
+    fn from_game(game: &AwbwGame) -> Self {
+        Self {
+            fog: game.fog,
+            team: game.team,
+            use_powers: game.use_powers,
+            official: game.official,
+            starting_funds: game.starting_funds,
+            funds_per_property: game.funds,
+            capture_win: game.capture_win,
+        }
+    }
+}
+
+impl AwbwReplay {
+    /// Summarize the replay from its first and last game snapshots, or `None`
+    /// for a replay without snapshots.
+    pub fn header(&self) -> Option<ReplayHeader> {
+        let first = self.games.first()?;
+        let last = self.games.last()?;
+
+        let mut players: Vec<_> = last
+            .players
+            .iter()
+            .map(|player| PlayerInfo::new(player, last.team))
+            .collect();
+        players.sort_by_key(|player| player.order);
+
+        Some(ReplayHeader {
+            game_id: first.id.as_u32(),
+            name: first.name.clone(),
+            map_id: first.maps_id.as_u32(),
+            start_date: first.start_date.clone(),
+            end_date: last.end_date.clone(),
+            days: last.day,
+            players,
+            outcome: GameOutcome::from_game(last),
+            features: ReplayFeatures::from_game(first),
+        })
+    }
+}
@@ -98,28 +98,38 @@ pub enum Action {
         #[serde(rename = "updatedInfo")]
         updated_info: UpdatedInfo,
     },
+
+    /// An action whose kind isn't recognized, preserved as raw JSON. Only
+    /// produced by a lenient [`ReplayParser`](crate::ReplayParser).
+    #[serde(skip_deserializing)]
+    Unknown(serde_json::Value),
 }
 
 impl Action {
     pub fn kind_name(&self) -> &'static str {
+        self.kind().name()
+    }
+
+    pub fn kind(&self) -> ActionKind {
         match self {
-            Action::AttackSeam { .. } => "AttackSeam",
-            Action::Build { .. } => "Build",
-            Action::Capt { .. } => "Capt",
-            Action::End { .. } => "End",
-            Action::Fire { .. } => "Fire",
-            Action::Join { .. } => "Join",
-            Action::Load { .. } => "Load",
-            Action::Move(_) => "Move",
-            Action::Power(_) => "Power",
-            Action::Repair { .. } => "Repair",
-            Action::Resign { .. } => "Resign",
-            Action::Supply { .. } => "Supply",
-            Action::Unload { .. } => "Unload",
-            Action::Delete { .. } => "Delete",
-            Action::Hide { .. } => "Hide",
-            Action::Unhide { .. } => "Unhide",
-            Action::Tag { .. } => "Tag",
+            Action::AttackSeam { .. } => ActionKind::AttackSeam,
+            Action::Build { .. } => ActionKind::Build,
+            Action::Capt { .. } => ActionKind::Capture,
+            Action::End { .. } => ActionKind::End,
+            Action::Fire { .. } => ActionKind::Fire,
+            Action::Join { .. } => ActionKind::Join,
+            Action::Load { .. } => ActionKind::Load,
+            Action::Move(_) => ActionKind::Move,
+            Action::Power(_) => ActionKind::Power,
+            Action::Repair { .. } => ActionKind::Repair,
+            Action::Resign { .. } => ActionKind::Resign,
+            Action::Supply { .. } => ActionKind::Supply,
+            Action::Unload { .. } => ActionKind::Unload,
+            Action::Delete { .. } => ActionKind::Delete,
+            Action::Hide { .. } => ActionKind::Hide,
+            Action::Unhide { .. } => ActionKind::Unhide,
+            Action::Tag { .. } => ActionKind::Tag,
+            Action::Unknown(_) => ActionKind::Unknown,
         }
     }
 
@@ -138,6 +148,135 @@ impl Action {
             _ => None,
         }
     }
+
+    /// The day play continues on when this action passes the turn, or `None`
+    /// for actions played within a turn.
+    ///
+    /// Ending a turn, tagging in a partner CO, and resigning on your own turn
+    /// all pass the turn.
+    pub fn next_turn_day(&self) -> Option<u32> {
+        match self {
+            Action::End { updated_info } | Action::Tag { updated_info } => Some(updated_info.day),
+            Action::Resign {
+                next_turn_action: Some(next_turn_action),
+                ..
+            } => Some(next_turn_action.day),
+            _ => None,
+        }
+    }
+
+    /// The unit performing this action, when the replay reveals it.
+    ///
+    /// Actions with an embedded move report the moving unit, so a unit's
+    /// move and its follow-up (fire, capture, load, ...) share an id and can
+    /// be grouped as one logical action.
+    pub fn primary_unit_id(&self) -> Option<AwbwUnitId> {
+        if let Some(move_action) = self.move_action() {
+            return visible_unit_id(&move_action.unit);
+        }
+
+        match self {
+            Action::AttackSeam {
+                attack_seam_action, ..
+            } => attack_seam_action
+                .unit
+                .values()
+                .find_map(|combat| combat.combat_info.get_value())
+                .map(|unit| unit.units_id),
+            Action::Build { new_unit, .. } => visible_unit_id(new_unit),
+            Action::Fire { fire_action, .. } => fire_action
+                .combat_info_vision
+                .values()
+                .find_map(|vision| vision.combat_info.attacker.get_value())
+                .map(|attacker| attacker.units_id),
+            Action::Join { join_action, .. } => visible_unit_id(&join_action.unit),
+            Action::Load { load_action, .. } => load_action
+                .loaded
+                .values()
+                .find_map(|hidden| hidden.get_value().copied()),
+            Action::Repair { repair_action, .. } => repair_action
+                .unit
+                .values()
+                .find_map(|hidden| hidden.get_value().copied())
+                .map(AwbwUnitId::new),
+            Action::Supply { supply_action, .. } => supply_action
+                .unit
+                .values()
+                .find_map(|hidden| hidden.get_value().copied())
+                .map(AwbwUnitId::new),
+            Action::Unload { unit, .. } => visible_unit_id(unit),
+            Action::Delete { delete_action } => delete_action
+                .unit_id
+                .as_ref()?
+                .values()
+                .find_map(|hidden| hidden.get_value().copied()),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of an [`Action`], without its payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::VariantArray)]
+pub enum ActionKind {
+    AttackSeam,
+    Build,
+    Capture,
+    End,
+    Fire,
+    Join,
+    Load,
+    Move,
+    Power,
+    Repair,
+    Resign,
+    Supply,
+    Unload,
+    Delete,
+    Hide,
+    Unhide,
+    Tag,
+    Unknown,
+}
+
+impl ActionKind {
+    /// The `action` tag AWBW uses for this kind.
+    pub const fn name(self) -> &'static str {
+        match self {
+            ActionKind::AttackSeam => "AttackSeam",
+            ActionKind::Build => "Build",
+            ActionKind::Capture => "Capt",
+            ActionKind::End => "End",
+            ActionKind::Fire => "Fire",
+            ActionKind::Join => "Join",
+            ActionKind::Load => "Load",
+            ActionKind::Move => "Move",
+            ActionKind::Power => "Power",
+            ActionKind::Repair => "Repair",
+            ActionKind::Resign => "Resign",
+            ActionKind::Supply => "Supply",
+            ActionKind::Unload => "Unload",
+            ActionKind::Delete => "Delete",
+            ActionKind::Hide => "Hide",
+            ActionKind::Unhide => "Unhide",
+            ActionKind::Tag => "Tag",
+            ActionKind::Unknown => "Unknown",
+        }
+    }
+
+    /// The kind whose typed [`Action`] variant deserializes from the `action`
+    /// tag `name`, or `None` for tags this parser doesn't know.
+    pub fn from_name(name: &str) -> Option<ActionKind> {
+        <ActionKind as strum::VariantArray>::VARIANTS
+            .iter()
+            .copied()
+            .find(|kind| *kind != ActionKind::Unknown && kind.name() == name)
+    }
+}
+
+fn visible_unit_id(unit: &UnitMap) -> Option<AwbwUnitId> {
+    unit.values()
+        .find_map(|hidden| hidden.get_value())
+        .map(|unit| unit.units_id)
 }
 
 pub type UnitMap = indexmap::IndexMap<TargetedPlayer, Hidden<UnitProperty>>;
@@ -792,6 +931,28 @@ mod awbw_country_code {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use strum::VariantArray;
+
+    #[test]
+    fn test_action_kind_names_match_serde_tags() {
+        let is_serde_tag = |name: &str| {
+            let error = serde_json::from_value::<Action>(serde_json::json!({ "action": name }))
+                .unwrap_err()
+                .to_string();
+            !error.contains("unknown variant")
+        };
+
+        for kind in ActionKind::VARIANTS.iter().copied() {
+            let known = kind != ActionKind::Unknown;
+            assert_eq!(is_serde_tag(kind.name()), known, "{kind:?}");
+            assert_eq!(
+                ActionKind::from_name(kind.name()),
+                known.then_some(kind),
+                "{kind:?}"
+            );
+        }
+        assert_eq!(ActionKind::from_name("Teleport"), None);
+    }
 
     #[test]
     fn test_global_stat_boost() {
@@ -1,3 +1,4 @@
+use awbw_replay::turn_models::Action;
 use awbw_replay::{ReplayEntriesKind, ReplayFile};
 use highway::HighwayHash;
 use insta::{assert_json_snapshot, glob};
@@ -82,6 +83,8 @@ fn test_replay_snapshots() {
             ),
         };
 
+        assert!(replay.raw_turn(0).is_none());
+
         let hasher = highway::HighwayHasher::new(highway::Key::default());
 
         // HighwayHash is fast, but we still want to buffer writes as much as
@@ -101,3 +104,509 @@ fn test_replay_snapshots() {
         assert_json_snapshot!(snapshot);
     });
 }
+
+#[test]
+fn test_raw_turn_matches_typed_action() {
+    let data = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../assets/replays/1362397.zip"
+    ))
+    .unwrap();
+    let replay = awbw_replay::ReplayParser::new()
+        .with_raw_turns(true)
+        .parse(&data)
+        .unwrap();
+    for (index, action) in replay.turns.iter().enumerate() {
+        let raw = replay.raw_turn(index).unwrap();
+        assert_eq!(raw["action"], action.kind_name(), "action index {index}");
+    }
+
+    let raw = replay.raw_turn(0).unwrap();
+
+    assert_eq!(raw["action"], "Build");
+    assert_eq!(raw["newUnit"]["global"]["units_id"], 170205832);
+    assert_eq!(raw["newUnit"]["global"]["units_name"], "Infantry");
+    assert_eq!(replay.turns[0].kind_name(), "Build");
+    assert!(replay.raw_turn(replay.turns.len()).is_none());
+}
+
+#[test]
+fn test_fire_with_move_reports_attacker_as_primary_unit() {
+    let data = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../assets/replays/1362397.zip"
+    ))
+    .unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    let (move_action, fire_action) = replay
+        .turns
+        .iter()
+        .find_map(|action| match action {
+            Action::Fire {
+                move_action: Some(move_action),
+                fire_action,
+            } => Some((move_action, fire_action)),
+            _ => None,
+        })
+        .expect("replay contains a fire action with an embedded move");
+
+    let attacker = fire_action
+        .combat_info_vision
+        .values()
+        .find_map(|vision| vision.combat_info.attacker.get_value())
+        .unwrap();
+    let action = Action::Fire {
+        move_action: Some(move_action.clone()),
+        fire_action: fire_action.clone(),
+    };
+    assert_eq!(action.primary_unit_id(), Some(attacker.units_id));
+    assert_eq!(
+        Action::Move(move_action.clone()).primary_unit_id(),
+        Some(attacker.units_id)
+    );
+}
+
+#[test]
+fn test_replay_turn_timestamps() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1362397.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    let timestamps = replay.turn_timestamps();
+    assert_eq!(timestamps.len(), replay.games.len());
+
+    let first = timestamps[0];
+    assert_eq!(first.day, 1);
+    assert_eq!(
+        first.started_at,
+        awbw_replay::game_models::parse_timestamp("2025-02-17 09:51:05").unwrap()
+    );
+    assert_eq!(
+        first.duration,
+        Some(std::time::Duration::from_secs(20 * 60 + 10))
+    );
+    assert_eq!(
+        timestamps[1].duration,
+        Some(std::time::Duration::from_secs(37 * 60 + 48))
+    );
+
+    // The game never ended, so the final turn has no known duration.
+    assert_eq!(timestamps.last().unwrap().duration, None);
+
+    let total: std::time::Duration = timestamps.iter().filter_map(|turn| turn.duration).sum();
+    assert_eq!(replay.duration(), Some(total));
+}
+
+#[test]
+fn test_replay_eliminations() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1403019.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    assert_eq!(replay.games[0].players.len(), 5);
+    let eliminations = replay.eliminations();
+    assert_eq!(
+        eliminations,
+        vec![
+            (awbrn_types::AwbwGamePlayerId::new(3276855), 101),
+            (awbrn_types::AwbwGamePlayerId::new(3277095), 124),
+        ]
+    );
+
+    // Each player is recorded once, at the first snapshot marking them out.
+    for &(player_id, turn) in &eliminations {
+        let eliminated = |game: &awbw_replay::game_models::AwbwGame| {
+            game.players
+                .iter()
+                .any(|player| player.id == player_id && player.eliminated)
+        };
+        assert!(eliminated(&replay.games[turn]));
+        assert!(!eliminated(&replay.games[turn - 1]));
+    }
+}
+
+#[test]
+fn test_replay_funds_timeline() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1403019.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    let timeline = replay.funds_timeline();
+    assert_eq!(timeline.len(), 5);
+
+    // Day 1: 7000 starting funds less four infantry. Day 2: 7000 income less
+    // four more infantry and a T-Copter.
+    let player = awbrn_types::AwbwGamePlayerId::new(3276855);
+    assert_eq!(
+        timeline[&player][..2],
+        [(0, 7000 - 4 * 1000), (5, 3000 + 7000 - 9000)]
+    );
+
+    // Funds left at the end of a turn carry into the next snapshot.
+    for (turn, funds) in &timeline[&player][..2] {
+        let next = replay.games[turn + 1]
+            .players
+            .iter()
+            .find(|p| p.id == player)
+            .unwrap();
+        assert_eq!(next.funds, *funds);
+    }
+}
+
+#[test]
+fn test_replay_action_kind_lookup() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1403019.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    use awbw_replay::turn_models::{Action, ActionKind};
+    let first_capture = replay
+        .turns
+        .iter()
+        .position(|action| matches!(action, Action::Capt { .. }))
+        .unwrap();
+    assert_eq!(
+        replay.next_action_of_kind(ActionKind::Capture, 0),
+        Some(first_capture)
+    );
+    assert_eq!(
+        replay.next_action_of_kind(ActionKind::Capture, first_capture),
+        Some(first_capture)
+    );
+    assert_eq!(
+        replay.prev_action_of_kind(ActionKind::Capture, first_capture),
+        None
+    );
+    assert_eq!(
+        replay.prev_action_of_kind(ActionKind::Capture, first_capture + 1),
+        Some(first_capture)
+    );
+    assert_eq!(
+        replay.next_action_of_kind(ActionKind::Build, replay.turns.len()),
+        None
+    );
+}
+
+#[test]
+fn test_replay_initial_units() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1362397.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    // The map starts with four predeployed Black Hole infantry
+    let infantry = |x, y| {
+        (
+            awbrn_map::Position::new(x, y),
+            awbrn_types::Unit::Infantry,
+            awbrn_types::PlayerFaction::BlackHole,
+        )
+    };
+    assert_eq!(
+        replay.initial_units(),
+        vec![
+            infantry(12, 9),
+            infantry(12, 11),
+            infantry(14, 9),
+            infantry(14, 11)
+        ]
+    );
+}
+
+#[test]
+fn test_replay_production_log() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1403019.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    let build_count = replay
+        .turns
+        .iter()
+        .filter(|action| matches!(action, Action::Build { .. }))
+        .count();
+    let log = replay.production_log();
+    assert_eq!(log.len(), build_count);
+
+    use awbrn_map::Position;
+    use awbrn_types::{AwbwGamePlayerId, Unit};
+    let player = AwbwGamePlayerId::new(3276855);
+    assert_eq!(
+        log[..4],
+        [
+            (0, Position::new(1, 31), Unit::Infantry, player),
+            (0, Position::new(15, 34), Unit::Infantry, player),
+            (0, Position::new(21, 7), Unit::Infantry, player),
+            (0, Position::new(27, 12), Unit::Infantry, player),
+        ]
+    );
+    assert_eq!(
+        log[4],
+        (
+            1,
+            Position::new(7, 15),
+            Unit::Infantry,
+            AwbwGamePlayerId::new(3277011)
+        )
+    );
+
+    // The player's second turn builds four more infantry and a T-Copter.
+    let second_turn: Vec<_> = log.iter().filter(|(turn, ..)| *turn == 5).collect();
+    assert_eq!(second_turn.len(), 5);
+    assert!(second_turn.iter().all(|(.., id)| *id == player));
+    assert_eq!(
+        second_turn
+            .iter()
+            .filter(|(_, _, unit, _)| *unit == Unit::TCopter)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_replay_header_serializes_camel_case() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1403019.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    let header = replay.header().unwrap();
+    let json = serde_json::to_value(&header).unwrap();
+    assert_eq!(json["gameId"], 1403019);
+    assert_eq!(json["mapId"], 168602);
+    assert_eq!(json["endDate"], serde_json::Value::Null);
+    assert_eq!(json["outcome"]["kind"], "inProgress");
+    assert_eq!(json["features"]["usePowers"], true);
+    assert_eq!(json["features"]["fundsPerProperty"], 1000);
+
+    let first_player = &json["players"][0];
+    assert_eq!(first_player["playerId"], 3276855);
+    assert_eq!(first_player["factionCode"], "wn");
+    assert_eq!(first_player["coName"], "Jess");
+    assert_eq!(first_player["tagCoName"], serde_json::Value::Null);
+
+    fn assert_camel_case(value: &serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    assert!(!key.contains('_'), "{key} is not camelCase");
+                    assert_camel_case(value);
+                }
+            }
+            serde_json::Value::Array(values) => values.iter().for_each(assert_camel_case),
+            _ => {}
+        }
+    }
+    assert_camel_case(&json);
+}
+
+/// A PHP value re-encoded as JSON the way PHP's `json_encode` would: strings
+/// arrive as bytes, and arrays keyed `0..n` become JSON lists.
+struct PhpJson(serde_json::Value);
+
+impl<'de> Deserialize<'de> for PhpJson {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PhpJsonVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for PhpJsonVisitor {
+            type Value = PhpJson;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a PHP value")
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<PhpJson, E> {
+                Ok(PhpJson(v.into()))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<PhpJson, E> {
+                Ok(PhpJson(v.into()))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<PhpJson, E> {
+                Ok(PhpJson(v.into()))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<PhpJson, E> {
+                Ok(PhpJson(v.into()))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<PhpJson, E> {
+                Ok(PhpJson(v.into()))
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<PhpJson, E> {
+                Ok(PhpJson(String::from_utf8_lossy(v).into()))
+            }
+
+            fn visit_unit<E>(self) -> Result<PhpJson, E> {
+                Ok(PhpJson(serde_json::Value::Null))
+            }
+
+            fn visit_none<E>(self) -> Result<PhpJson, E> {
+                Ok(PhpJson(serde_json::Value::Null))
+            }
+
+            fn visit_some<D: serde::Deserializer<'de>>(self, d: D) -> Result<PhpJson, D::Error> {
+                PhpJson::deserialize(d)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<PhpJson, A::Error> {
+                let mut values = Vec::new();
+                while let Some(PhpJson(value)) = seq.next_element()? {
+                    values.push(value);
+                }
+                Ok(PhpJson(values.into()))
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<PhpJson, A::Error> {
+                let mut entries = Vec::new();
+                while let Some((PhpJson(key), PhpJson(value))) = map.next_entry()? {
+                    entries.push((key, value));
+                }
+
+                let is_list = entries
+                    .iter()
+                    .enumerate()
+                    .all(|(index, (key, _))| key.as_u64() == Some(index as u64));
+                if is_list {
+                    let values: Vec<_> = entries.into_iter().map(|(_, value)| value).collect();
+                    return Ok(PhpJson(values.into()));
+                }
+
+                let object = entries
+                    .into_iter()
+                    .map(|(key, value)| match key {
+                        serde_json::Value::String(key) => (key, value),
+                        key => (key.to_string(), value),
+                    })
+                    .collect::<serde_json::Map<_, _>>();
+                Ok(PhpJson(object.into()))
+            }
+        }
+
+        deserializer.deserialize_any(PhpJsonVisitor)
+    }
+}
+
+#[test]
+fn test_raw_json_replay_matches_compressed() {
+    let data = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../assets/replays/1362397.zip"
+    ))
+    .unwrap();
+    let parser = awbw_replay::ReplayParser::new().with_raw_turns(true);
+    let compressed = parser.parse(&data).unwrap();
+    let raw_turns: Vec<_> = (0..compressed.turns.len())
+        .map(|index| compressed.raw_turn(index).unwrap())
+        .collect();
+
+    let file = ReplayFile::open(&data).unwrap();
+    let mut games = Vec::new();
+    let mut buf = Vec::new();
+    for file_entry in file.iter() {
+        let reader = BufReader::new(file_entry.get_reader().unwrap());
+        if let ReplayEntriesKind::Game(mut entries) = ReplayEntriesKind::classify(reader).unwrap() {
+            while let Some(entry) = entries.next_entry(&mut buf).unwrap() {
+                let PhpJson(game) = PhpJson::deserialize(&mut entry.deserializer()).unwrap();
+                games.push(game);
+            }
+        }
+    }
+
+    let raw_json = serde_json::to_vec(&serde_json::json!({
+        "games": games,
+        "turns": raw_turns,
+    }))
+    .unwrap();
+    let from_json = parser.parse(&raw_json).unwrap();
+
+    assert_eq!(from_json, compressed);
+}
+
+#[test]
+fn test_replay_locate_actions_within_days() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1362397.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+
+    // Every player's turn makes up a day, so day one closes with the first
+    // End that passes the turn into day two
+    let ends_day = |day| {
+        replay
+            .turns
+            .iter()
+            .position(
+                |action| matches!(action, Action::End { updated_info } if updated_info.day > day),
+            )
+            .unwrap()
+    };
+    let day_one_end = ends_day(1);
+    let day_two_end = ends_day(2);
+    let day_one_len = day_one_end + 1;
+    let day_two_len = day_two_end - day_one_end;
+
+    assert_eq!(replay.locate(0), Some((1, 0, day_one_len)));
+    assert_eq!(
+        replay.locate(day_one_end),
+        Some((1, day_one_end, day_one_len))
+    );
+    assert_eq!(replay.locate(day_one_end + 1), Some((2, 0, day_two_len)));
+    assert_eq!(
+        replay.locate(day_two_end),
+        Some((2, day_two_len - 1, day_two_len))
+    );
+    assert_eq!(replay.locate(day_two_end + 1).unwrap().0, 3);
+
+    let last = replay.turns.len() - 1;
+    let (_, index, len) = replay.locate(last).unwrap();
+    assert_eq!(index, len - 1);
+    assert_eq!(replay.locate(replay.turns.len()), None);
+}
+
+#[test]
+fn test_replay_turn_indexes_count_tag_turns() {
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/replays/1563018.zip");
+    let data = std::fs::read(path).unwrap();
+    let replay = awbw_replay::ReplayParser::new().parse(&data).unwrap();
+    assert!(
+        replay
+            .turns
+            .iter()
+            .any(|action| matches!(action, Action::Tag { .. }))
+    );
+
+    // Tagging in a partner CO passes the turn, so builds stay attributed to
+    // the snapshot of the turn they were made in
+    for (turn, _, _, player_id) in replay.production_log() {
+        assert_eq!(
+            awbrn_types::AwbwGamePlayerId::new(replay.games[turn].turn),
+            player_id
+        );
+    }
+
+    // Funds left at the end of a turn carry into the next snapshot.
+    for (player_id, timeline) in replay.funds_timeline() {
+        for (turn, funds) in timeline {
+            let Some(next) = replay.games.get(turn + 1) else {
+                continue;
+            };
+            let player = next.players.iter().find(|p| p.id == player_id).unwrap();
+            assert_eq!(player.funds, funds, "turn {turn}");
+        }
+    }
+}
@@ -20,6 +20,67 @@ fn infantry(pathfinder: &mut awbrn_map::PathFinder<impl awbrn_map::MovementMap>)
     reachable.into_positions().count()
 }
 
+fn weather_toggle_map() -> awbrn_map::AwbrnMap {
+    let mut map = awbrn_map::AwbrnMap::new(100, 100, awbrn_types::GraphicalTerrain::Plain);
+    for (i, terrain) in [
+        awbrn_types::GraphicalTerrain::Mountain,
+        awbrn_types::GraphicalTerrain::Wood,
+        awbrn_types::GraphicalTerrain::Sea(awbrn_types::SeaDirection::N_E_S_W),
+        awbrn_types::GraphicalTerrain::Property(awbrn_types::Property::City(
+            awbrn_types::Faction::Neutral,
+        )),
+    ]
+    .into_iter()
+    .cycle()
+    .take(2_500)
+    .enumerate()
+    {
+        map.set_terrain(Position::new((i * 4) % 100, (i * 4) / 100), terrain);
+    }
+    map
+}
+
+/// Toggles the weather through every variant on an app holding the terrain
+/// visual systems, as happens in the client when the weather changes.
+fn weather_toggle(app: &mut bevy::app::App) {
+    for weather in [
+        awbrn_types::Weather::Snow,
+        awbrn_types::Weather::Rain,
+        awbrn_types::Weather::Clear,
+    ] {
+        awbrn_client::test_support::set_weather(app, weather);
+    }
+}
+
+fn weather_toggle_app() -> bevy::app::App {
+    awbrn_client::test_support::terrain_visuals_app(weather_toggle_map())
+}
+
+/// Per-position terrain lookups over the whole map, as done when building
+/// map visuals at load.
+fn terrain_lookup(map: &awbrn_map::AwbrnMap) -> usize {
+    let mut checksum = 0;
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            if let Some(terrain) = map.terrain_at(Position::new(x, y)) {
+                checksum += awbrn_content::spritesheet_index(awbrn_types::Weather::Clear, terrain)
+                    .index() as usize;
+            }
+        }
+    }
+    checksum
+}
+
+/// The same walk as [`terrain_lookup`] through the row-major iterator, as a
+/// baseline for the cost of position lookups.
+fn terrain_iter(map: &awbrn_map::AwbrnMap) -> usize {
+    map.iter()
+        .map(|(_, terrain)| {
+            awbrn_content::spritesheet_index(awbrn_types::Weather::Clear, terrain).index() as usize
+        })
+        .sum()
+}
+
 pub mod criterion_benches {
     use super::*;
     use criterion::{BenchmarkId, Criterion};
@@ -40,7 +101,33 @@ pub mod criterion_benches {
         group.finish();
     }
 
-    criterion::criterion_group!(map_benches, pathfinding);
+    fn terrain_weather(c: &mut Criterion) {
+        let mut app = weather_toggle_app();
+        let mut group = c.benchmark_group("terrain_weather");
+        group.bench_function(BenchmarkId::from_parameter("toggle-100x100"), |b| {
+            b.iter(|| weather_toggle(black_box(&mut app)));
+        });
+        group.finish();
+    }
+
+    fn terrain_lookup_group(c: &mut Criterion) {
+        let map = weather_toggle_map();
+        let mut group = c.benchmark_group("terrain_lookup");
+        group.bench_function(BenchmarkId::from_parameter("terrain-at-100x100"), |b| {
+            b.iter(|| black_box(terrain_lookup(&map)));
+        });
+        group.bench_function(BenchmarkId::from_parameter("iter-100x100"), |b| {
+            b.iter(|| black_box(terrain_iter(&map)));
+        });
+        group.finish();
+    }
+
+    criterion::criterion_group!(
+        map_benches,
+        pathfinding,
+        terrain_weather,
+        terrain_lookup_group
+    );
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -65,5 +152,32 @@ pub mod gungraun_benches {
         reachable.into_positions().count()
     }
 
-    library_benchmark_group!(name = map_benches, benchmarks = [pathfinding,]);
+    #[library_benchmark(setup = weather_toggle_app)]
+    #[bench::toggle_100x100()]
+    fn terrain_weather(mut app: bevy::app::App) -> bevy::app::App {
+        weather_toggle(&mut app);
+        app
+    }
+
+    #[library_benchmark(setup = weather_toggle_map)]
+    #[bench::terrain_at_100x100()]
+    fn terrain_lookup_at(map: awbrn_map::AwbrnMap) -> usize {
+        terrain_lookup(&map)
+    }
+
+    #[library_benchmark(setup = weather_toggle_map)]
+    #[bench::iter_100x100()]
+    fn terrain_lookup_iter(map: awbrn_map::AwbrnMap) -> usize {
+        terrain_iter(&map)
+    }
+
+    library_benchmark_group!(
+        name = map_benches,
+        benchmarks = [
+            pathfinding,
+            terrain_weather,
+            terrain_lookup_at,
+            terrain_lookup_iter
+        ]
+    );
 }
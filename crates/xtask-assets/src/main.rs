@@ -4,8 +4,8 @@ use image::{ImageReader, RgbaImage};
 use indexmap::IndexMap;
 use oxipng::{InFile, Options, OutFile};
 use rectangle_pack::{
-    GroupedRectsToPlace, PackedLocation, RectToInsert, TargetBin, contains_smallest_box,
-    pack_rects, volume_heuristic,
+    GroupedRectsToPlace, RectToInsert, TargetBin, contains_smallest_box, pack_rects,
+    volume_heuristic,
 };
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -19,6 +19,11 @@ use walkdir::WalkDir;
 const TILESHEET_COLUMNS: u32 = 64;
 const UNITSHEET_COLUMNS: u32 = 64;
 const UNIT_SPRITESHEET_BLEED: u32 = 1;
+/// Frames a single unit may span across its four animations. Each unit's
+/// frames are packed into a fixed `[u16; 16]` buffer in awbrn-content's
+/// `UnitAnimationData` and its sprite cells laid out back to back per
+/// faction, so growing this means growing both.
+const MAX_UNIT_FRAMES: usize = 16;
 const CO_PORTRAIT_COLUMNS: u32 = 8;
 const CO_PORTRAIT_WIDTH: u32 = 32;
 const CO_PORTRAIT_HEIGHT: u32 = 32;
@@ -38,6 +43,20 @@ struct SpritesheetBuild {
     offset_y: u32,
 }
 
+impl SpritesheetBuild {
+    /// Top-left pixel of the cell holding the sprite at `index`. This is the
+    /// same grid Bevy's `TextureAtlasLayout::from_grid` derives from the
+    /// emitted cell size, padding and offset.
+    fn cell_origin(&self, index: u32) -> (u32, u32) {
+        let col = index % self.columns;
+        let row = index / self.columns;
+        (
+            self.offset_x + col * (self.cell_width + self.padding_x),
+            self.offset_y + row * (self.cell_height + self.padding_y),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum WeatherKind {
     Clear,
@@ -213,6 +232,12 @@ struct TileMetadata {
     frame_timings: Vec<u32>,
 }
 
+impl TileMetadata {
+    fn is_animated(&self) -> bool {
+        self.frames > 1
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct SpriteIndex {
     start_index: u16,
@@ -568,7 +593,7 @@ fn run_tiles() -> Result<()> {
                 }),
             },
             frames: 1,
-            frame_timings: vec![300],
+            frame_timings: Vec::new(),
         });
     }
 
@@ -586,10 +611,11 @@ fn run_tiles() -> Result<()> {
                 .map_err(|_| anyhow!("Animation frames exceed 255 for {name}"))?
         };
 
-        let frame_timings = if entry.frames.is_empty() {
-            vec![300] // Default for non-animated
-        } else {
+        // Static buildings carry no timings so they never get animation data
+        let frame_timings = if frames > 1 {
             entry.frames.clone()
+        } else {
+            Vec::new()
         };
 
         tiles.push(TileMetadata {
@@ -629,7 +655,7 @@ fn run_tiles() -> Result<()> {
             rain: None,
         },
         frames: 1,
-        frame_timings: vec![300],
+        frame_timings: Vec::new(),
     });
 
     let mut terrain_map = HashMap::new();
@@ -715,19 +741,12 @@ fn run_tiles() -> Result<()> {
     all_frames.extend(snow_frames);
     all_frames.extend(rain_frames);
 
-    let _tilesheet = build_spritesheet(&all_frames, &tilesheet_path, TILESHEET_COLUMNS, 0)?;
+    let tilesheet = build_spritesheet(&all_frames, &tilesheet_path, TILESHEET_COLUMNS, 0)?;
     optimize_png(&tilesheet_path)?;
 
-    let tilesheet_rows = (all_frames.len() as u32).div_ceil(TILESHEET_COLUMNS);
-
     fs::create_dir_all(&generated_dir).context("Creating generated output directory")?;
     let spritesheet_rs = generated_dir.join("spritesheet_index.rs");
-    let spritesheet_contents = render_spritesheet_index(
-        &ordered_tiles,
-        &sprite_indices,
-        TILESHEET_COLUMNS,
-        tilesheet_rows,
-    );
+    let spritesheet_contents = render_spritesheet_index(&ordered_tiles, &sprite_indices, tilesheet);
     fs::write(&spritesheet_rs, spritesheet_contents).context("Writing spritesheet_index.rs")?;
 
     // Generate terrain animation data
@@ -783,7 +802,18 @@ fn run_ui() -> Result<()> {
     let effect_sprites = collect_ui_effect_sprites(&effects_root)?;
     sprites.extend(effect_sprites);
     sprites.sort_by(|a, b| a.name.cmp(&b.name));
-    let (atlas_width, atlas_height, placements) = pack_ui_sprites(&sprites)?;
+    let (atlas_width, packed_height, mut placements) = pack_ui_sprites(&sprites)?;
+
+    // Explosion frames go in rows below the packed sprites, and after them in
+    // the atlas data, so adding them doesn't move any existing sprite.
+    let explosion_sprites = collect_explosion_sprites(&effects_root.join("Explosion"))?;
+    let atlas_height = place_ui_sprite_rows(
+        &explosion_sprites,
+        atlas_width,
+        packed_height,
+        &mut placements,
+    )?;
+    sprites.extend(explosion_sprites);
 
     build_ui_atlas(
         &sprites,
@@ -936,6 +966,47 @@ fn collect_ui_effect_sprites(effects_root: &Path) -> Result<Vec<UiSprite>> {
     Ok(sprites)
 }
 
+/// Explosion frames from the source art, ordered for playback so that
+/// `Explosion-10.png` follows `Explosion-9.png`.
+fn collect_explosion_sprites(explosion_root: &Path) -> Result<Vec<UiSprite>> {
+    let mut sprites = Vec::new();
+    for entry in fs::read_dir(explosion_root)
+        .with_context(|| format!("Reading {}", explosion_root.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("png") {
+            continue;
+        }
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        let rgba = load_rgba_image(&path)?;
+        let (width, height) = rgba.dimensions();
+        sprites.push(UiSprite {
+            name: format!("Effects/Explosion/{file_name}"),
+            image: rgba,
+            width,
+            height,
+        });
+    }
+
+    if sprites.is_empty() {
+        return Err(anyhow!(
+            "No explosion frames found in {}",
+            explosion_root.display()
+        ));
+    }
+
+    sprites.sort_by(|a, b| {
+        a.name
+            .len()
+            .cmp(&b.name.len())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    Ok(sprites)
+}
+
 fn collect_co_portraits(
     co_map: &BTreeMap<String, CombatOfficerEntry>,
     co_textures_root: &Path,
@@ -1004,7 +1075,10 @@ fn collect_co_portraits(
 
 const UI_ATLAS_PADDING: u32 = 1;
 
-fn pack_ui_sprites(sprites: &[UiSprite]) -> Result<(u32, u32, HashMap<String, PackedLocation>)> {
+/// Top-left corner of each sprite's padded cell in the UI atlas.
+type UiPlacements = HashMap<String, (u32, u32)>;
+
+fn pack_ui_sprites(sprites: &[UiSprite]) -> Result<(u32, u32, UiPlacements)> {
     let p = UI_ATLAS_PADDING * 2;
     let total_area: u32 = sprites
         .iter()
@@ -1046,7 +1120,7 @@ fn pack_ui_sprites(sprites: &[UiSprite]) -> Result<(u32, u32, HashMap<String, Pa
                 let placements = result
                     .packed_locations()
                     .iter()
-                    .map(|(name, (_bin_id, location))| (name.clone(), *location))
+                    .map(|(name, (_bin_id, location))| (name.clone(), (location.x(), location.y())))
                     .collect();
                 return Ok((side, side, placements));
             }
@@ -1057,9 +1131,41 @@ fn pack_ui_sprites(sprites: &[UiSprite]) -> Result<(u32, u32, HashMap<String, Pa
     }
 }
 
+/// Lay `sprites` out left to right in rows starting at `top`, wrapping at
+/// `width`. Returns the atlas height needed to fit them.
+fn place_ui_sprite_rows(
+    sprites: &[UiSprite],
+    width: u32,
+    top: u32,
+    placements: &mut UiPlacements,
+) -> Result<u32> {
+    let p = UI_ATLAS_PADDING * 2;
+    let (mut x, mut y, mut row_height) = (0, top, 0);
+    for sprite in sprites {
+        let (cell_width, cell_height) = (sprite.width + p, sprite.height + p);
+        if cell_width > width {
+            return Err(anyhow!(
+                "{} is wider than the {width}px UI atlas",
+                sprite.name
+            ));
+        }
+        if x + cell_width > width {
+            x = 0;
+            y += row_height;
+            row_height = 0;
+        }
+
+        placements.insert(sprite.name.clone(), (x, y));
+        x += cell_width;
+        row_height = row_height.max(cell_height);
+    }
+
+    Ok(y + row_height)
+}
+
 fn build_ui_atlas(
     sprites: &[UiSprite],
-    placements: &HashMap<String, PackedLocation>,
+    placements: &UiPlacements,
     output_path: &Path,
     width: u32,
     height: u32,
@@ -1067,14 +1173,14 @@ fn build_ui_atlas(
     let mut atlas = RgbaImage::new(width, height);
 
     for sprite in sprites {
-        let placement = placements
+        let &(x, y) = placements
             .get(&sprite.name)
             .with_context(|| format!("Missing placement for {}", sprite.name))?;
         image::imageops::overlay(
             &mut atlas,
             &sprite.image,
-            (placement.x() + UI_ATLAS_PADDING).into(),
-            (placement.y() + UI_ATLAS_PADDING).into(),
+            (x + UI_ATLAS_PADDING).into(),
+            (y + UI_ATLAS_PADDING).into(),
         );
     }
 
@@ -1089,9 +1195,11 @@ fn build_ui_atlas(
     Ok(())
 }
 
+/// Write the atlas data with sprites in the order given, which new sprites
+/// are appended to so the index of every existing sprite stays the same.
 fn write_ui_atlas_data(
     sprites: &[UiSprite],
-    placements: &HashMap<String, PackedLocation>,
+    placements: &UiPlacements,
     width: u32,
     height: u32,
     output_path: &Path,
@@ -1099,21 +1207,19 @@ fn write_ui_atlas_data(
     let mut entries = Vec::new();
 
     for sprite in sprites {
-        let placement = placements
+        let &(x, y) = placements
             .get(&sprite.name)
             .with_context(|| format!("Missing placement for {}", sprite.name))?;
 
         entries.push(UiAtlasSprite {
             name: sprite.name.clone(),
-            x: placement.x() + UI_ATLAS_PADDING,
-            y: placement.y() + UI_ATLAS_PADDING,
+            x: x + UI_ATLAS_PADDING,
+            y: y + UI_ATLAS_PADDING,
             width: sprite.width,
             height: sprite.height,
         });
     }
 
-    entries.sort_by(|a, b| a.name.cmp(&b.name));
-
     let data = UiAtlasData {
         size: UiAtlasSize { width, height },
         sprites: entries,
@@ -1303,10 +1409,7 @@ fn build_unit_definitions(units: Vec<(String, UnitEntry)>) -> Result<Vec<UnitDef
             return Err(anyhow!("Duplicate unit definition for {name}"));
         }
 
-        let total_frames = unit_total_frames(&entry);
-        if total_frames > 16 {
-            return Err(anyhow!("Unit {name} has {total_frames} frames (max 16)"));
-        }
+        check_unit_frame_budget(&name, &entry)?;
 
         definitions.push(UnitDefinition {
             unit,
@@ -1367,11 +1470,29 @@ fn resolve_unit_frame_path(
     base_dir.join(format!("{}-{}.png", animation.texture, frame))
 }
 
-fn unit_total_frames(entry: &UnitEntry) -> usize {
-    entry.idle.frames.len()
-        + entry.move_up.frames.len()
-        + entry.move_down.frames.len()
-        + entry.move_side.frames.len()
+/// Errors when a unit's animations exceed [`MAX_UNIT_FRAMES`], naming the
+/// animation that pushed it over.
+fn check_unit_frame_budget(name: &str, entry: &UnitEntry) -> Result<()> {
+    let animations = [
+        ("IdleAnimation", &entry.idle),
+        ("MoveUpAnimation", &entry.move_up),
+        ("MoveDownAnimation", &entry.move_down),
+        ("MoveSideAnimation", &entry.move_side),
+    ];
+
+    let mut total_frames = 0;
+    for (animation_name, animation) in animations {
+        let before = total_frames;
+        total_frames += animation.frames.len();
+        if total_frames > MAX_UNIT_FRAMES {
+            return Err(anyhow!(
+                "Unit {name} exceeds the {MAX_UNIT_FRAMES} frame budget: {animation_name} ({} frames) takes it from {before} to {total_frames} frames",
+                animation.frames.len()
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 fn unit_definition_total_frames(entry: &UnitDefinition) -> usize {
@@ -1891,16 +2012,23 @@ fn build_spritesheet(
     let cols = columns.max(1);
     let rows = (images.len() as u32).div_ceil(cols).max(1);
     let gutter = bleed * 2;
+    let build = SpritesheetBuild {
+        columns: cols,
+        rows,
+        cell_width: max_width,
+        cell_height: max_height,
+        padding_x: gutter,
+        padding_y: gutter,
+        offset_x: bleed,
+        offset_y: bleed,
+    };
     let sheet_width = cols * (max_width + gutter);
     let sheet_height = rows * (max_height + gutter);
 
     let mut sheet = RgbaImage::new(sheet_width, sheet_height);
 
     for (index, (_path, image)) in images.into_iter().enumerate() {
-        let col = (index as u32) % cols;
-        let row = (index as u32) / cols;
-        let base_x = col * (max_width + gutter) + bleed;
-        let base_y = row * (max_height + gutter) + bleed;
+        let (base_x, base_y) = build.cell_origin(index as u32);
         let x_offset = max_width.saturating_sub(image.width());
         let y_offset = max_height.saturating_sub(image.height());
         let x = base_x + x_offset;
@@ -1919,16 +2047,7 @@ fn build_spritesheet(
         .save(output_path)
         .with_context(|| format!("Saving spritesheet to {}", output_path.display()))?;
 
-    Ok(SpritesheetBuild {
-        columns: cols,
-        rows,
-        cell_width: max_width,
-        cell_height: max_height,
-        padding_x: gutter,
-        padding_y: gutter,
-        offset_x: bleed,
-        offset_y: bleed,
-    })
+    Ok(build)
 }
 
 fn collect_logo_paths(countries: &[CountryEntry], logos_root: &Path) -> Result<Vec<PathBuf>> {
@@ -2267,17 +2386,52 @@ fn render_faction_lookup(metadata: &[FactionMetadataEntry]) -> String {
 fn render_spritesheet_index(
     tiles: &[TileMetadata],
     sprite_indices: &HashMap<(TerrainKey, WeatherKind), SpriteIndex>,
-    tilesheet_columns: u32,
-    tilesheet_rows: u32,
+    tilesheet: SpritesheetBuild,
 ) -> String {
     let mut output = String::new();
     output.push_str("// This file is @generated by xtask-assets.\n\n");
     output.push_str(&format!(
-        "pub const TILESHEET_COLUMNS: u32 = {tilesheet_columns};\n",
+        "pub const TILESHEET_COLUMNS: u32 = {};\n",
+        tilesheet.columns
+    ));
+    output.push_str(&format!(
+        "pub const TILESHEET_ROWS: u32 = {};\n",
+        tilesheet.rows
+    ));
+    output.push_str(&format!(
+        "pub const TILESHEET_SPRITE_WIDTH: u32 = {};\n",
+        tilesheet.cell_width
+    ));
+    output.push_str(&format!(
+        "pub const TILESHEET_SPRITE_HEIGHT: u32 = {};\n\n",
+        tilesheet.cell_height
+    ));
+    output.push_str(&format!(
+        "pub const TILESHEET_PADDING_X: u32 = {};\n",
+        tilesheet.padding_x
     ));
     output.push_str(&format!(
-        "pub const TILESHEET_ROWS: u32 = {tilesheet_rows};\n\n"
+        "pub const TILESHEET_PADDING_Y: u32 = {};\n",
+        tilesheet.padding_y
+    ));
+    output.push_str(&format!(
+        "pub const TILESHEET_OFFSET_X: u32 = {};\n",
+        tilesheet.offset_x
+    ));
+    output.push_str(&format!(
+        "pub const TILESHEET_OFFSET_Y: u32 = {};\n\n",
+        tilesheet.offset_y
     ));
+    output.push_str("#[rustfmt::skip]\n");
+    output.push_str(&format!(
+        "pub const SPRITESHEET_TERRAINS: [GraphicalTerrain; {}] = [\n",
+        tiles.len()
+    ));
+    for tile in tiles {
+        output.push_str(&format!("    {},\n", tile.terrain.rust_pattern()));
+    }
+    output.push_str("];\n\n");
+
     output.push_str("#[rustfmt::skip]\n");
     output.push_str(
         "pub const fn spritesheet_index(weather: Weather, terrain: GraphicalTerrain) -> SpritesheetIndex {\n",
@@ -2328,10 +2482,17 @@ fn render_terrain_animation_data(tiles: &[TileMetadata]) -> String {
     let mut const_names = Vec::new();
 
     for tile in tiles {
-        if tile.frames <= 1 {
+        if !tile.is_animated() {
             continue; // Skip static (non-animated) tiles
         }
 
+        assert_eq!(
+            tile.frame_timings.len(),
+            usize::from(tile.frames),
+            "{:?} needs one timing per animation frame",
+            tile.terrain
+        );
+
         let const_name = format!("FRAMES_{}", const_counter);
         const_counter += 1;
 
@@ -2369,3 +2530,65 @@ fn render_terrain_animation_data(tiles: &[TileMetadata]) -> String {
     output.push_str("    }\n}\n");
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_entry(frame_counts: [usize; 4]) -> UnitEntry {
+        let animation = |frames: usize| UnitAnimationEntry {
+            texture: String::from("inf"),
+            frames: vec![100; frames],
+        };
+        UnitEntry {
+            _name: String::from("Infantry"),
+            idle: animation(frame_counts[0]),
+            move_up: animation(frame_counts[1]),
+            move_down: animation(frame_counts[2]),
+            move_side: animation(frame_counts[3]),
+        }
+    }
+
+    #[test]
+    fn unit_frame_budget_allows_exactly_the_cap() {
+        assert!(check_unit_frame_budget("Infantry", &unit_entry([4, 4, 4, 4])).is_ok());
+    }
+
+    #[test]
+    fn unit_frame_budget_names_the_overflowing_animation() {
+        let error = check_unit_frame_budget("Infantry", &unit_entry([4, 4, 9, 1])).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("Infantry"), "{message}");
+        assert!(message.contains("MoveDownAnimation"), "{message}");
+        assert!(message.contains("from 8 to 17"), "{message}");
+    }
+
+    #[test]
+    fn cell_origins_step_over_bleed_gutters() {
+        let sheet = SpritesheetBuild {
+            columns: 64,
+            rows: 2,
+            cell_width: 23,
+            cell_height: 24,
+            padding_x: 2,
+            padding_y: 2,
+            offset_x: 1,
+            offset_y: 1,
+        };
+
+        assert_eq!(sheet.cell_origin(0), (1, 1));
+        assert_eq!(sheet.cell_origin(1), (26, 1));
+        assert_eq!(sheet.cell_origin(65), (26, 27));
+
+        // The last cell plus its trailing bleed ends exactly at the sheet edge.
+        let (x, y) = sheet.cell_origin(sheet.columns * sheet.rows - 1);
+        assert_eq!(
+            x + sheet.cell_width + sheet.offset_x,
+            sheet.columns * (sheet.cell_width + sheet.padding_x)
+        );
+        assert_eq!(
+            y + sheet.cell_height + sheet.offset_y,
+            sheet.rows * (sheet.cell_height + sheet.padding_y)
+        );
+    }
+}